@@ -6,7 +6,7 @@ use figment::{
     providers::{Env, Format, Toml},
 };
 use serde::{Deserialize, Serialize};
-use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{pubkey::Pubkey, signature::read_keypair_file, signer::Signer};
 
 use crate::Cli;
 
@@ -22,8 +22,14 @@ pub struct Config {
     /// Default payer keypair path
     pub payer_keypair: Option<PathBuf>,
 
-    /// Destination program ID
+    /// Default program targeted by sessions
     pub destination_program: Option<String>,
+
+    /// Name of the root to operate on
+    pub root_name: Option<String>,
+
+    /// Creator of the root to operate on; defaults to payer
+    pub root_creator: Option<String>,
 }
 
 fn default_rpc_url() -> String {
@@ -37,6 +43,8 @@ impl Default for Config {
             program_id: None,
             payer_keypair: None,
             destination_program: None,
+            root_name: None,
+            root_creator: None,
         }
     }
 }
@@ -60,6 +68,16 @@ pub fn merge_cli_config(config: &mut Config, cli: &Cli) {
     if let Some(program_id) = &cli.program_id {
         config.program_id = Some(program_id.clone());
     }
+    if let Some(root_name) = &cli.root_name {
+        config.root_name = Some(root_name.clone());
+    }
+    if let Some(root_creator) = &cli.root_creator {
+        config.root_creator = Some(root_creator.clone());
+    }
+    // root creator falls back to the payer the command actually signs with
+    if let Some(payer) = cli.command.payer() {
+        config.payer_keypair = Some(payer.clone());
+    }
 }
 
 pub fn get_program_id(config: &Config) -> Result<Pubkey> {
@@ -69,3 +87,65 @@ pub fn get_program_id(config: &Config) -> Result<Pubkey> {
         .ok_or_else(|| anyhow!("Program ID not configured"))
         .and_then(|s| Pubkey::from_str(s).context("Invalid program ID"))
 }
+
+pub fn get_root_name(config: &Config) -> Result<String> {
+    config
+        .root_name
+        .clone()
+        .ok_or_else(|| anyhow!("Root name not configured"))
+}
+
+pub fn get_root_creator(config: &Config) -> Result<Pubkey> {
+    if let Some(creator) = &config.root_creator {
+        return Pubkey::from_str(creator).context("Invalid root creator");
+    }
+    let payer_keypair_path = config
+        .payer_keypair
+        .as_ref()
+        .ok_or_else(|| anyhow!("Root creator not configured"))?;
+    read_keypair_file(payer_keypair_path)
+        .map(|kp| kp.pubkey())
+        .map_err(|e| anyhow!("Could not read keyfile: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use clap::Parser;
+    use solana_sdk::signature::{Keypair, write_keypair_file};
+
+    #[test]
+    fn test_root_creator_defaults_to_payer_override() {
+        let configured = Keypair::new();
+        let payer = Keypair::new();
+        let dir = std::env::temp_dir().join(format!("mosaic-cli-{}", payer.pubkey()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let configured_path = dir.join("configured.json");
+        let payer_path = dir.join("payer.json");
+        write_keypair_file(&configured, &configured_path).unwrap();
+        write_keypair_file(&payer, &payer_path).unwrap();
+
+        let cli = Cli::try_parse_from([
+            "mosaic-cli",
+            "close-buffer",
+            "--session-id",
+            "1",
+            "--buffer",
+            &Pubkey::new_unique().to_string(),
+            "--recipient",
+            &Pubkey::new_unique().to_string(),
+            "--payer",
+            payer_path.to_str().unwrap(),
+        ])
+        .unwrap();
+        let mut config = Config {
+            payer_keypair: Some(configured_path),
+            ..Config::default()
+        };
+        merge_cli_config(&mut config, &cli);
+
+        assert_eq!(get_root_creator(&config).unwrap(), payer.pubkey());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,29 +1,149 @@
 use std::{path::PathBuf, str::FromStr};
 
 use anyhow::{Context, Result, anyhow};
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
 use borsh::BorshDeserialize;
 use solana_client::{rpc_client::RpcClient, rpc_config::CommitmentConfig};
 use solana_sdk::{
-    message::{AccountMeta, Instruction},
+    hash::{Hash, hash},
+    message::AccountMeta,
     pubkey::Pubkey,
-    signature::{Keypair, read_keypair_file},
+    signature::{Keypair, Signature, read_keypair_file},
     signer::Signer,
     transaction::Transaction,
 };
-use solana_sdk_ids::system_program;
+use solana_sdk_ids::bpf_loader_upgradeable;
+use solana_transaction_status_client_types::UiTransactionEncoding;
 use tracing::{debug, info};
 
+use mosaic_client::{
+    instructions::{
+        InitializeRootArgs, InitializeSigningSessionArgs, cancel, close_session, execute,
+        initialize_root, initialize_signing_session, reject, sign, unsign,
+    },
+    pda::{find_root_pda, find_signing_session_pda, find_vault_pda},
+    programs::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+    state::signing_session::{stale_votes, vote_bit},
+};
+
 use crate::{
-    config::{Config, get_program_id},
+    config::{Config, get_program_id, get_root_creator, get_root_name},
     types::{
-        CloseSessionIxData, CreateSessionIxData, ExecuteIxData, InitializeRootIxData,
-        InstructionAccount, InstructionAccountJson, ProgramIx, Root, SignIxData, SigningSession,
-        SigningSessionPhase,
+        ConfigChange, EVENT_TAG, EVENT_VERSION, Event, Expiry, InitializeRootRequest,
+        InstructionAccount, InstructionAccountJson, LoaderChange, Operator, Permission, Proposal,
+        Root, SessionInstruction, SigningSession, SigningSessionPhase, TokenTransfer,
+        TokenTransferRequest,
     },
 };
 
-const ROOT_PDA: &[u8] = b"root";
-const SIGNING_SESSION_PDA: &[u8] = b"signing_session";
+const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    Pubkey::from_str_const("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+// offset of decimals in mint layout: mint authority option (36) + supply (8)
+const MINT_DECIMALS_OFFSET: usize = 44;
+
+// buffer state header: enum tag (4) + authority option (1 + 32)
+const LOADER_BUFFER_HEADER_LEN: usize = 37;
+
+fn find_associated_token_address(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// returns buffer authority and hash of the program data it holds
+fn fetch_buffer_hash(rpc_client: &RpcClient, buffer: &Pubkey) -> Result<(Option<Pubkey>, Hash)> {
+    let account = rpc_client
+        .get_account(buffer)
+        .context("Failed to fetch buffer account")?;
+    if account.owner != bpf_loader_upgradeable::id()
+        || account.data.len() < LOADER_BUFFER_HEADER_LEN
+        || account.data[..4] != 1u32.to_le_bytes()
+    {
+        return Err(anyhow!("{} is not an upgradeable loader buffer", buffer));
+    }
+    let authority = (account.data[4] == 1)
+        .then(|| Pubkey::try_from(&account.data[5..LOADER_BUFFER_HEADER_LEN]))
+        .transpose()?;
+    Ok((authority, hash(&account.data[LOADER_BUFFER_HEADER_LEN..])))
+}
+
+fn get_root_pda(config: &Config, program_id: &Pubkey) -> Result<Pubkey> {
+    let creator = get_root_creator(config)?;
+    let name = get_root_name(config)?;
+    Ok(find_root_pda(&creator, &name, program_id).0)
+}
+
+/// fetches and deserializes root account
+fn fetch_root(rpc_client: &RpcClient, root_pda: &Pubkey) -> Result<Root> {
+    let account = rpc_client
+        .get_account(root_pda)
+        .context("Failed to fetch root account")?;
+    Root::deserialize(&account.data).context("Failed to deserialize root account data")
+}
+
+/// returns keys of current root operators whose position is set in vote bitmap, skipping votes
+/// cast by previous holders of a slot taken over after session `operators_epoch`
+fn voters(root: &Root, votes: u64, operators_epoch: u32) -> Vec<Pubkey> {
+    let votes = votes & !stale_votes(&root.operator_epochs, operators_epoch);
+    root.operators
+        .iter()
+        .enumerate()
+        .filter(|(position, operator)| votes & vote_bit(*position) != 0 && !operator.is_removed())
+        .map(|(_, operator)| operator.key)
+        .collect()
+}
+
+/// returns weight of votes cast by current root operators, skipping votes of previous slot holders
+fn vote_weight(root: &Root, votes: u64, operators_epoch: u32) -> u32 {
+    root.weight_of(votes & !stale_votes(&root.operator_epochs, operators_epoch))
+}
+
+/// parses operator given as `pubkey[:weight[:permissions]]`; defaults to weight one and `ive`
+fn parse_operator(operator: &str) -> Result<Operator> {
+    let mut parts = operator.split(':');
+    let key = parts.next().unwrap_or_default();
+    let weight = parts
+        .next()
+        .map_or(Ok(1), str::parse)
+        .context("Invalid operator weight")?;
+    let permissions = parts
+        .next()
+        .map_or(Ok(Permission::ALL), parse_permissions)?;
+    Ok(Operator {
+        key: Pubkey::from_str(key).context("Invalid operator pubkey")?,
+        weight,
+        permissions,
+    })
+}
+
+/// parses permissions given as letters: `i`nitiate, `v`ote and `e`xecute
+pub fn parse_permissions(permissions: &str) -> Result<u8> {
+    permissions.chars().try_fold(0, |bits, letter| {
+        Ok(bits
+            | match letter {
+                'i' => Permission::Initiate as u8,
+                'v' => Permission::Vote as u8,
+                'e' => Permission::Execute as u8,
+                _ => return Err(anyhow!("Unknown permission '{}'", letter)),
+            })
+    })
+}
+
+/// formats permission bits as letters understood by `parse_permissions`
+fn format_permissions(permissions: u8) -> String {
+    [
+        (Permission::Initiate as u8, 'i'),
+        (Permission::Vote as u8, 'v'),
+        (Permission::Execute as u8, 'e'),
+    ]
+    .iter()
+    .filter(|(bit, _)| permissions & bit != 0)
+    .map(|(_, letter)| *letter)
+    .collect()
+}
 
 fn load_keypair(path: &PathBuf) -> Result<Keypair> {
     match read_keypair_file(path) {
@@ -34,27 +154,31 @@ fn load_keypair(path: &PathBuf) -> Result<Keypair> {
 
 pub async fn handle_initialize_root(
     config: &Config,
-    operators: Vec<String>,
-    threshold: u8,
-    destination_program: String,
+    request: InitializeRootRequest,
     payer_path: Option<PathBuf>,
 ) -> Result<()> {
     info!("Initializing root account...");
 
     let program_id = get_program_id(config)?;
     let rpc_client = RpcClient::new_with_commitment(&config.rpc_url, CommitmentConfig::confirmed());
-    let operator_pubkeys: Result<Vec<Pubkey>> = operators
+    let threshold = request.threshold;
+    let operators = request
+        .operators
         .iter()
-        .map(|s| Pubkey::from_str(s).context("Invalid operator pubkey"))
-        .collect();
-    let operator_pubkeys = operator_pubkeys?;
+        .map(|s| parse_operator(s))
+        .collect::<Result<Vec<_>>>()?;
+    let total_weight: u32 = operators.iter().map(|op| u32::from(op.weight)).sum();
 
-    debug!("Operators: {:?}", operator_pubkeys);
-    debug!("Threshold: {}/{}", threshold, operator_pubkeys.len());
+    debug!("Operators: {:?}", operators);
+    debug!("Threshold: {}/{}", threshold, total_weight);
 
-    let destination_program_pubkey =
-        Pubkey::from_str(&destination_program).context("Invalid destination program ID")?;
-    info!("Destination program: {}", destination_program_pubkey);
+    let allowed_program_pubkeys = request
+        .allowed_programs
+        .iter()
+        .map(|s| Pubkey::from_str(s).context("Invalid allowed program ID"))
+        .collect::<Result<Vec<_>>>()?;
+    info!("Allowed programs: {:?}", allowed_program_pubkeys);
+    info!("Execute policy: {:?}", request.execute_policy);
 
     let payer_keypair_path = payer_path
         .or_else(|| config.payer_keypair.clone())
@@ -62,27 +186,21 @@ pub async fn handle_initialize_root(
     let payer = load_keypair(&payer_keypair_path)?;
     info!("Payer: {}", payer.pubkey());
 
-    let (root_pda, root_bump) = Pubkey::find_program_address(&[ROOT_PDA], &program_id);
-    debug!("Root PDA: {} (bump: {})", root_pda, root_bump);
-
-    let ix_data = InitializeRootIxData {
-        operators: operator_pubkeys,
-        threshold,
-        bump: root_bump,
-        destination_program: destination_program_pubkey,
-    };
-    let mut data = vec![ProgramIx::InitializeOperators as u8];
-    data.extend_from_slice(&borsh::to_vec(&ix_data)?);
-
-    let instruction = Instruction::new_with_bytes(
-        program_id,
-        &data,
-        vec![
-            AccountMeta::new(payer.pubkey(), true),
-            AccountMeta::new(root_pda, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-        ],
+    let name = get_root_name(config)?;
+    let (instruction, root_pda) = initialize_root(
+        &program_id,
+        &payer.pubkey(),
+        InitializeRootArgs {
+            name,
+            operators,
+            threshold,
+            allowed_programs: allowed_program_pubkeys,
+            default_ttl: request.default_ttl,
+            timelock: request.timelock,
+            execute_policy: request.execute_policy,
+        },
     );
+    debug!("Root PDA: {}", root_pda);
 
     let recent_blockhash = rpc_client.get_latest_blockhash()?;
     let transaction = Transaction::new_signed_with_payer(
@@ -103,22 +221,51 @@ pub async fn handle_initialize_root(
 pub async fn handle_create_session(
     config: &Config,
     session_id: u16,
-    instruction_data: String,
-    accounts: String,
+    target_program: Option<String>,
+    instructions: Vec<(String, String)>,
+    vault_index: u8,
+    expires_at: Option<Expiry>,
     payer_path: Option<PathBuf>,
 ) -> Result<()> {
     info!("Creating signing session {}...", session_id);
 
     let program_id = get_program_id(config)?;
-    let rpc_client = RpcClient::new_with_commitment(&config.rpc_url, CommitmentConfig::confirmed());
-    let instruction_data = hex::decode(instruction_data.trim_start_matches("0x"))
+    let target_program = get_target_program(config, &program_id, target_program)?;
+    info!("Target program: {}", target_program);
+
+    let instructions = instructions
+        .iter()
+        .map(|(instruction_data, accounts)| {
+            session_instruction(target_program, instruction_data, accounts)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    debug!("Instructions: {}", instructions.len());
+
+    send_create_session(
+        config,
+        session_id,
+        Proposal::Cpi,
+        instructions,
+        vault_index,
+        expires_at,
+        payer_path,
+    )
+}
+
+fn session_instruction(
+    program_id: Pubkey,
+    instruction_data: &str,
+    accounts: &str,
+) -> Result<SessionInstruction> {
+    let data = hex::decode(instruction_data.trim_start_matches("0x"))
         .context("Invalid hex string for instruction data")?;
-    debug!("Instruction data: {} bytes", instruction_data.len());
+    debug!("Instruction data: {} bytes", data.len());
 
     let accounts: Vec<InstructionAccountJson> =
-        serde_json::from_str(&accounts).context("Invalid JSON for accounts")?;
+        serde_json::from_str(accounts).context("Invalid JSON for accounts")?;
 
-    let instruction_accounts: Vec<Vec<u8>> = accounts
+    let accounts: Vec<Vec<u8>> = accounts
         .iter()
         .map(|acc| {
             let pubkey = Pubkey::from_str(&acc.pubkey)?;
@@ -131,47 +278,236 @@ pub async fn handle_create_session(
         })
         .collect::<Result<Vec<_>>>()?;
 
-    debug!("Instruction accounts: {}", instruction_accounts.len());
+    debug!("Instruction accounts: {}", accounts.len());
 
-    let payer_keypair_path = payer_path
-        .or_else(|| config.payer_keypair.clone())
-        .ok_or_else(|| anyhow!("Payer keypair not specified"))?;
-    let payer = load_keypair(&payer_keypair_path)?;
+    Ok(SessionInstruction {
+        program_id,
+        data,
+        accounts,
+    })
+}
 
-    let (root_pda, _) = Pubkey::find_program_address(&[ROOT_PDA], &program_id);
+/// picks program targeted by session; falls back to config, then to the only allowed program
+fn get_target_program(
+    config: &Config,
+    program_id: &Pubkey,
+    target_program: Option<String>,
+) -> Result<Pubkey> {
+    if let Some(target) = target_program.or_else(|| config.destination_program.clone()) {
+        return Pubkey::from_str(&target).context("Invalid target program ID");
+    }
 
-    let (signing_pda, signing_bump) = Pubkey::find_program_address(
-        &[
-            &root_pda.to_bytes(),
-            &session_id.to_be_bytes(),
-            SIGNING_SESSION_PDA,
-        ],
-        &program_id,
+    let rpc_client = RpcClient::new_with_commitment(&config.rpc_url, CommitmentConfig::confirmed());
+    let root_pda = get_root_pda(config, program_id)?;
+    let account = rpc_client.get_account(&root_pda)?;
+    let root = Root::deserialize(&account.data)?;
+    match root.allowed_programs.as_slice() {
+        [only] => Ok(*only),
+        _ => Err(anyhow!(
+            "Root allows {} programs, specify the target program",
+            root.allowed_programs.len()
+        )),
+    }
+}
+
+pub async fn handle_propose_config(
+    config: &Config,
+    session_id: u16,
+    change: ConfigChange,
+    expires_at: Option<Expiry>,
+    payer_path: Option<PathBuf>,
+) -> Result<()> {
+    info!("Proposing config change in session {}...", session_id);
+    debug!("Config change: {:?}", change);
+
+    send_create_session(
+        config,
+        session_id,
+        Proposal::Config(change),
+        vec![],
+        0,
+        expires_at,
+        payer_path,
+    )
+}
+
+pub async fn handle_transfer(
+    config: &Config,
+    session_id: u16,
+    recipient: String,
+    amount: u64,
+    vault_index: u8,
+    expires_at: Option<Expiry>,
+    payer_path: Option<PathBuf>,
+) -> Result<()> {
+    info!("Proposing transfer in session {}...", session_id);
+
+    let recipient = Pubkey::from_str(&recipient).context("Invalid recipient pubkey")?;
+    info!(
+        "Transfer: {} lamports from vault {} to {}",
+        amount, vault_index, recipient
     );
-    debug!(
-        "Signing session PDA: {} (bump: {})",
-        signing_pda, signing_bump
+
+    send_create_session(
+        config,
+        session_id,
+        Proposal::Transfer {
+            recipient,
+            amount,
+            vault_index,
+        },
+        vec![],
+        vault_index,
+        expires_at,
+        payer_path,
+    )
+}
+
+pub async fn handle_token_transfer(
+    config: &Config,
+    session_id: u16,
+    request: TokenTransferRequest,
+    expires_at: Option<Expiry>,
+    payer_path: Option<PathBuf>,
+) -> Result<()> {
+    info!("Proposing token transfer in session {}...", session_id);
+
+    let program_id = get_program_id(config)?;
+    let rpc_client = RpcClient::new_with_commitment(&config.rpc_url, CommitmentConfig::confirmed());
+
+    let mint_account = rpc_client
+        .get_account(&request.mint)
+        .context("Failed to fetch mint account")?;
+    let token_program = mint_account.owner;
+    if token_program != TOKEN_PROGRAM_ID && token_program != TOKEN_2022_PROGRAM_ID {
+        return Err(anyhow!(
+            "Mint is owned by {}, neither token nor token-2022 program",
+            token_program
+        ));
+    }
+    let decimals = *mint_account
+        .data
+        .get(MINT_DECIMALS_OFFSET)
+        .ok_or_else(|| anyhow!("Invalid mint account data"))?;
+
+    // tokens leave associated token account of the signing vault or root pda
+    let root_pda = get_root_pda(config, &program_id)?;
+    let authority = match request.vault_index {
+        Some(vault_index) => find_vault_pda(&root_pda, vault_index, &program_id).0,
+        None => root_pda,
+    };
+    let source = find_associated_token_address(&authority, &request.mint, &token_program);
+    let destination =
+        find_associated_token_address(&request.recipient, &request.mint, &token_program);
+
+    info!("Token program: {}", token_program);
+    info!(
+        "Transfer: {} (decimals {}) of {} from {} to {}",
+        request.amount, decimals, request.mint, source, destination
     );
 
-    let create_ix_data = CreateSessionIxData {
+    send_create_session(
+        config,
         session_id,
-        instruction_data,
-        instruction_accounts,
-        bump: signing_bump,
+        Proposal::TokenTransfer(TokenTransfer {
+            token_program,
+            mint: request.mint,
+            source,
+            destination,
+            amount: request.amount,
+            decimals,
+            vault_index: request.vault_index,
+        }),
+        vec![],
+        request.vault_index.unwrap_or_default(),
+        expires_at,
+        payer_path,
+    )
+}
+
+pub async fn handle_loader_change(
+    config: &Config,
+    session_id: u16,
+    change: LoaderChange,
+    vault_index: Option<u8>,
+    expires_at: Option<Expiry>,
+    payer_path: Option<PathBuf>,
+) -> Result<()> {
+    info!("Proposing loader change in session {}...", session_id);
+
+    let program_id = get_program_id(config)?;
+    let rpc_client = RpcClient::new_with_commitment(&config.rpc_url, CommitmentConfig::confirmed());
+
+    // loader checks authority against root pda or signing vault
+    let root_pda = get_root_pda(config, &program_id)?;
+    let authority = match vault_index {
+        Some(vault_index) => find_vault_pda(&root_pda, vault_index, &program_id).0,
+        None => root_pda,
     };
-    let mut data = vec![ProgramIx::CreateSession as u8];
-    data.extend_from_slice(&borsh::to_vec(&create_ix_data)?);
+    info!("Authority: {}", authority);
+
+    // operators verify buffer contents before signing
+    if let LoaderChange::Upgrade { buffer, .. } | LoaderChange::CloseBuffer { buffer, .. } = &change
+    {
+        let (buffer_authority, buffer_hash) = fetch_buffer_hash(&rpc_client, buffer)?;
+        info!("Buffer: {}", buffer);
+        info!("Buffer program data hash: {}", buffer_hash);
+        if buffer_authority != Some(authority) {
+            return Err(anyhow!(
+                "Buffer authority {:?} is not the proposal authority {}",
+                buffer_authority,
+                authority
+            ));
+        }
+    }
+    info!("Change: {:?}", change);
 
-    let instruction = Instruction::new_with_bytes(
-        program_id,
-        &data,
-        vec![
-            AccountMeta::new(payer.pubkey(), true),
-            AccountMeta::new(root_pda, false),
-            AccountMeta::new(signing_pda, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-        ],
+    send_create_session(
+        config,
+        session_id,
+        Proposal::Loader {
+            change,
+            vault_index,
+        },
+        vec![],
+        vault_index.unwrap_or_default(),
+        expires_at,
+        payer_path,
+    )
+}
+
+fn send_create_session(
+    config: &Config,
+    session_id: u16,
+    proposal: Proposal,
+    instructions: Vec<SessionInstruction>,
+    vault_index: u8,
+    expires_at: Option<Expiry>,
+    payer_path: Option<PathBuf>,
+) -> Result<()> {
+    let program_id = get_program_id(config)?;
+    let rpc_client = RpcClient::new_with_commitment(&config.rpc_url, CommitmentConfig::confirmed());
+
+    let payer_keypair_path = payer_path
+        .or_else(|| config.payer_keypair.clone())
+        .ok_or_else(|| anyhow!("Payer keypair not specified"))?;
+    let payer = load_keypair(&payer_keypair_path)?;
+
+    let root_pda = get_root_pda(config, &program_id)?;
+
+    let (instruction, signing_pda) = initialize_signing_session(
+        &program_id,
+        &payer.pubkey(),
+        &root_pda,
+        InitializeSigningSessionArgs {
+            session_id,
+            proposal,
+            instructions,
+            vault_index,
+            expires_at,
+        },
     );
+    debug!("Signing session PDA: {}", signing_pda);
 
     let recent_blockhash = rpc_client.get_latest_blockhash()?;
     let transaction = Transaction::new_signed_with_payer(
@@ -199,31 +535,55 @@ pub async fn handle_sign(config: &Config, session_id: u16, signer_path: PathBuf)
     let signer = load_keypair(&signer_path)?;
     info!("Signer: {}", signer.pubkey());
 
-    let (root_pda, _) = Pubkey::find_program_address(&[ROOT_PDA], &program_id);
+    let root_pda = get_root_pda(config, &program_id)?;
 
-    let (signing_pda, signing_bump) = Pubkey::find_program_address(
-        &[
-            &root_pda.to_bytes(),
-            &session_id.to_be_bytes(),
-            SIGNING_SESSION_PDA,
-        ],
-        &program_id,
+    let (signing_pda, _) = find_signing_session_pda(&root_pda, session_id, &program_id);
+    let instruction = sign(&program_id, &signer.pubkey(), &root_pda, session_id);
+
+    let recent_blockhash = rpc_client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&signer.pubkey()),
+        &[&signer],
+        recent_blockhash,
     );
 
-    let sign_ix_data = SignIxData { bump: signing_bump };
-    let mut data = vec![ProgramIx::Sign as u8];
-    data.extend_from_slice(&borsh::to_vec(&sign_ix_data)?);
+    let signature = rpc_client.send_and_confirm_transaction(&transaction)?;
 
-    let instruction = Instruction::new_with_bytes(
-        program_id,
-        &data,
-        vec![
-            AccountMeta::new(signer.pubkey(), true),
-            AccountMeta::new_readonly(root_pda, false),
-            AccountMeta::new(signing_pda, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-        ],
+    let account = rpc_client.get_account(&signing_pda)?;
+    let session = SigningSession::deserialize(&account.data)?;
+    let root = fetch_root(&rpc_client, &root_pda)?;
+
+    info!("\n✅ Session signed successfully!");
+    info!("Transaction signature: {}", signature);
+    info!(
+        "Current approval weight: {}/{}",
+        vote_weight(&root, session.approvals, session.operators_epoch),
+        root.threshold
     );
+    info!("Approvers:");
+    for approver in voters(&root, session.approvals, session.operators_epoch) {
+        info!("  - {}", approver);
+    }
+    info!("Phase: {:?}", session.phase);
+
+    Ok(())
+}
+
+pub async fn handle_unsign(config: &Config, session_id: u16, signer_path: PathBuf) -> Result<()> {
+    info!("Revoking approval for session {}...", session_id);
+
+    let program_id = get_program_id(config)?;
+    let rpc_client = RpcClient::new_with_commitment(&config.rpc_url, CommitmentConfig::confirmed());
+
+    let signer = load_keypair(&signer_path)?;
+    info!("Signer: {}", signer.pubkey());
+
+    let root_pda = get_root_pda(config, &program_id)?;
+
+    let (signing_pda, _) = find_signing_session_pda(&root_pda, session_id, &program_id);
+
+    let instruction = unsign(&program_id, &signer.pubkey(), &root_pda, session_id);
 
     let recent_blockhash = rpc_client.get_latest_blockhash()?;
     let transaction = Transaction::new_signed_with_payer(
@@ -236,17 +596,18 @@ pub async fn handle_sign(config: &Config, session_id: u16, signer_path: PathBuf)
     let signature = rpc_client.send_and_confirm_transaction(&transaction)?;
 
     let account = rpc_client.get_account(&signing_pda)?;
-    let session = SigningSession::try_from_slice(&account.data)?;
+    let session = SigningSession::deserialize(&account.data)?;
+    let root = fetch_root(&rpc_client, &root_pda)?;
 
-    info!("\n✅ Session signed successfully!");
+    info!("\n✅ Approval revoked successfully!");
     info!("Transaction signature: {}", signature);
     info!(
-        "Current approvals: {}/{}",
-        session.approvals.len(),
-        session.approvals.len()
+        "Current approval weight: {}/{}",
+        vote_weight(&root, session.approvals, session.operators_epoch),
+        root.threshold
     );
     info!("Approvers:");
-    for approver in &session.approvals {
+    for approver in voters(&root, session.approvals, session.operators_epoch) {
         info!("  - {}", approver);
     }
     info!("Phase: {:?}", session.phase);
@@ -254,10 +615,79 @@ pub async fn handle_sign(config: &Config, session_id: u16, signer_path: PathBuf)
     Ok(())
 }
 
+pub async fn handle_reject(config: &Config, session_id: u16, signer_path: PathBuf) -> Result<()> {
+    info!("Rejecting session {}...", session_id);
+
+    let program_id = get_program_id(config)?;
+    let rpc_client = RpcClient::new_with_commitment(&config.rpc_url, CommitmentConfig::confirmed());
+
+    let signer = load_keypair(&signer_path)?;
+    info!("Signer: {}", signer.pubkey());
+
+    let root_pda = get_root_pda(config, &program_id)?;
+
+    let (signing_pda, _) = find_signing_session_pda(&root_pda, session_id, &program_id);
+
+    let instruction = reject(&program_id, &signer.pubkey(), &root_pda, session_id);
+
+    let recent_blockhash = rpc_client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&signer.pubkey()),
+        &[&signer],
+        recent_blockhash,
+    );
+
+    let signature = rpc_client.send_and_confirm_transaction(&transaction)?;
+
+    let account = rpc_client.get_account(&signing_pda)?;
+    let session = SigningSession::deserialize(&account.data)?;
+    let root = fetch_root(&rpc_client, &root_pda)?;
+
+    info!("\n✅ Session rejected successfully!");
+    info!("Transaction signature: {}", signature);
+    info!("Rejecters:");
+    for rejecter in voters(&root, session.rejections, session.operators_epoch) {
+        info!("  - {}", rejecter);
+    }
+    info!("Phase: {:?}", session.phase);
+
+    Ok(())
+}
+
+pub async fn handle_cancel(config: &Config, session_id: u16, proposer_path: PathBuf) -> Result<()> {
+    info!("Cancelling session {}...", session_id);
+
+    let program_id = get_program_id(config)?;
+    let rpc_client = RpcClient::new_with_commitment(&config.rpc_url, CommitmentConfig::confirmed());
+
+    let proposer = load_keypair(&proposer_path)?;
+    info!("Proposer: {}", proposer.pubkey());
+
+    let root_pda = get_root_pda(config, &program_id)?;
+
+    let instruction = cancel(&program_id, &proposer.pubkey(), &root_pda, session_id);
+
+    let recent_blockhash = rpc_client.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&proposer.pubkey()),
+        &[&proposer],
+        recent_blockhash,
+    );
+
+    let signature = rpc_client.send_and_confirm_transaction(&transaction)?;
+
+    info!("\n✅ Session cancelled successfully!");
+    info!("Transaction signature: {}", signature);
+
+    Ok(())
+}
+
 pub async fn handle_execute(
     config: &Config,
     session_id: u16,
-    storage_account: String,
+    storage_account: Option<String>,
     executor_path: PathBuf,
 ) -> Result<()> {
     info!("Executing session {}...", session_id);
@@ -268,31 +698,16 @@ pub async fn handle_execute(
     let executor = load_keypair(&executor_path)?;
     info!("Executor: {}", executor.pubkey());
 
-    let storage_pubkey =
-        Pubkey::from_str(&storage_account).context("Invalid storage account pubkey")?;
-
-    let destination_program = if let Some(dest) = &config.destination_program {
-        Pubkey::from_str(dest).context("Invalid destination program ID")?
-    } else {
-        let (root_pda, _) = Pubkey::find_program_address(&[ROOT_PDA], &program_id);
-        let account = rpc_client.get_account(&root_pda)?;
-        let root = Root::try_from_slice(&account.data)?;
-        root.destination_program
-    };
+    let storage_pubkey = storage_account
+        .map(|storage| Pubkey::from_str(&storage).context("Invalid storage account pubkey"))
+        .transpose()?;
 
-    let (root_pda, _) = Pubkey::find_program_address(&[ROOT_PDA], &program_id);
+    let root_pda = get_root_pda(config, &program_id)?;
 
-    let (signing_pda, _) = Pubkey::find_program_address(
-        &[
-            &root_pda.to_bytes(),
-            &session_id.to_be_bytes(),
-            SIGNING_SESSION_PDA,
-        ],
-        &program_id,
-    );
+    let (signing_pda, _) = find_signing_session_pda(&root_pda, session_id, &program_id);
 
     let account = rpc_client.get_account(&signing_pda)?;
-    let session = SigningSession::try_from_slice(&account.data)?;
+    let session = SigningSession::deserialize(&account.data)?;
 
     if session.phase != SigningSessionPhase::Approved {
         return Err(anyhow!(
@@ -301,22 +716,18 @@ pub async fn handle_execute(
         ));
     }
 
-    let execute_ix_data = ExecuteIxData {};
-    let mut data = vec![ProgramIx::Execute as u8];
-    data.extend_from_slice(&borsh::to_vec(&execute_ix_data)?);
-
-    let instruction = Instruction::new_with_bytes(
-        program_id,
-        &data,
-        vec![
-            AccountMeta::new(executor.pubkey(), true),
-            AccountMeta::new_readonly(root_pda, false),
-            AccountMeta::new(signing_pda, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-            AccountMeta::new_readonly(destination_program, false),
-            AccountMeta::new(storage_pubkey, false),
-        ],
-    );
+    let mut instruction = execute(&program_id, &executor.pubkey(), &root_pda, &session)
+        .context("Failed to build execute instruction")?;
+    if let Some(storage_pubkey) = storage_pubkey
+        && instruction
+            .accounts
+            .iter()
+            .all(|acc| acc.pubkey != storage_pubkey)
+    {
+        instruction
+            .accounts
+            .push(AccountMeta::new(storage_pubkey, false));
+    }
 
     let recent_blockhash = rpc_client.get_latest_blockhash()?;
     let transaction = Transaction::new_signed_with_payer(
@@ -341,23 +752,48 @@ pub async fn handle_view_root(config: &Config) -> Result<()> {
     let program_id = get_program_id(config)?;
     let rpc_client = RpcClient::new_with_commitment(&config.rpc_url, CommitmentConfig::confirmed());
 
-    let (root_pda, _) = Pubkey::find_program_address(&[ROOT_PDA], &program_id);
+    let root_pda = get_root_pda(config, &program_id)?;
     info!("Root PDA: {}", root_pda);
 
     let account = rpc_client
         .get_account(&root_pda)
         .context("Failed to fetch root account. Has it been initialized?")?;
     let root =
-        Root::try_from_slice(&account.data).context("Failed to deserialize root account data")?;
+        Root::deserialize(&account.data).context("Failed to deserialize root account data")?;
 
     info!("\n=== Root Account State ===");
-    info!("Operators ({}):", root.operators.len());
-    for (i, operator) in root.operators.iter().enumerate() {
-        info!("  {}. {}", i + 1, operator);
+    info!("Name: {}", root.name);
+    info!("Creator: {}", root.creator);
+    let operators: Vec<_> = root
+        .operators
+        .iter()
+        .filter(|operator| !operator.is_removed())
+        .collect();
+    info!("Operators ({}):", operators.len());
+    for (i, operator) in operators.iter().enumerate() {
+        info!(
+            "  {}. {} (weight {}, permissions {})",
+            i + 1,
+            operator.key,
+            operator.weight,
+            format_permissions(operator.permissions)
+        );
     }
-    info!("Threshold: {}/{}", root.threshold, root.operators.len());
+    let total_weight: u32 = root
+        .operators
+        .iter()
+        .filter(|op| op.permissions & Permission::Vote as u8 != 0)
+        .map(|op| u32::from(op.weight))
+        .sum();
+    info!("Threshold: {}/{} weight", root.threshold, total_weight);
     info!("Last Session ID: {}", root.last_id);
-    info!("Destination Program: {}", root.destination_program);
+    info!("Allowed Programs ({}):", root.allowed_programs.len());
+    for (i, program) in root.allowed_programs.iter().enumerate() {
+        info!("  {}. {}", i + 1, program);
+    }
+    info!("Default Session TTL: {:?}", root.default_ttl);
+    info!("Timelock: {} seconds", root.timelock);
+    info!("Execute Policy: {:?}", root.execute_policy);
     info!("Bump: {}", root.bump);
     info!("Account Owner: {}", account.owner);
     info!("Balance: {} lamports", account.lamports);
@@ -365,44 +801,163 @@ pub async fn handle_view_root(config: &Config) -> Result<()> {
     Ok(())
 }
 
+pub async fn handle_view_vault(config: &Config, vault_index: u8) -> Result<()> {
+    info!("Fetching vault {}...\n", vault_index);
+
+    let program_id = get_program_id(config)?;
+    let rpc_client = RpcClient::new_with_commitment(&config.rpc_url, CommitmentConfig::confirmed());
+
+    let root_pda = get_root_pda(config, &program_id)?;
+    let (vault_pda, vault_bump) = find_vault_pda(&root_pda, vault_index, &program_id);
+
+    info!("\n=== Vault ===");
+    info!("Root PDA: {}", root_pda);
+    info!("Vault PDA: {}", vault_pda);
+    info!("Index: {}", vault_index);
+    info!("Bump: {}", vault_bump);
+    info!("Balance: {} lamports", rpc_client.get_balance(&vault_pda)?);
+
+    Ok(())
+}
+
+/// decodes events logged by given program; data of other programs in the call stack is skipped
+pub fn decode_events(logs: &[String], program_id: &Pubkey) -> Result<Vec<Event>> {
+    let program_id = program_id.to_string();
+    let mut invoked = vec![];
+    let mut events = vec![];
+    for line in logs {
+        let Some(rest) = line.strip_prefix("Program ") else {
+            continue;
+        };
+        if let Some(fields) = rest.strip_prefix("data: ") {
+            if invoked.last() != Some(&program_id.as_str()) {
+                continue;
+            }
+            let fields = fields
+                .split(' ')
+                .map(|field| BASE64.decode(field))
+                .collect::<Result<Vec<_>, _>>()
+                .context("Invalid base64 in program data")?;
+            let [tag, version, event] = fields.as_slice() else {
+                continue;
+            };
+            if tag != EVENT_TAG {
+                continue;
+            }
+            if version != &[EVENT_VERSION] {
+                return Err(anyhow!("Unsupported event version {:?}", version));
+            }
+            events.push(Event::try_from_slice(event).context("Failed to deserialize event")?);
+        } else if let Some((program, _)) = rest.split_once(" invoke [") {
+            invoked.push(program);
+        } else if rest.ends_with(" success") || rest.contains(" failed: ") {
+            invoked.pop();
+        }
+    }
+    Ok(events)
+}
+
+pub async fn handle_events(config: &Config, signature: String) -> Result<()> {
+    info!("Fetching events of transaction {}...\n", signature);
+
+    let program_id = get_program_id(config)?;
+    let rpc_client = RpcClient::new_with_commitment(&config.rpc_url, CommitmentConfig::confirmed());
+
+    let signature = Signature::from_str(&signature).context("Invalid transaction signature")?;
+    let transaction = rpc_client
+        .get_transaction(&signature, UiTransactionEncoding::Json)
+        .context("Failed to fetch transaction")?;
+    let logs: Option<Vec<String>> = transaction
+        .transaction
+        .meta
+        .ok_or_else(|| anyhow!("Transaction has no status meta"))?
+        .log_messages
+        .into();
+
+    let events = decode_events(&logs.unwrap_or_default(), &program_id)?;
+    info!("Events ({}):", events.len());
+    for (i, event) in events.iter().enumerate() {
+        info!("  {}. {:?}", i + 1, event);
+    }
+
+    Ok(())
+}
+
 pub async fn handle_view_session(config: &Config, session_id: u16) -> Result<()> {
     info!("Fetching signing session {}...\n", session_id);
 
     let program_id = get_program_id(config)?;
     let rpc_client = RpcClient::new_with_commitment(&config.rpc_url, CommitmentConfig::confirmed());
 
-    let (root_pda, _) = Pubkey::find_program_address(&[ROOT_PDA], &program_id);
+    let root_pda = get_root_pda(config, &program_id)?;
 
-    let (signing_pda, _) = Pubkey::find_program_address(
-        &[
-            &root_pda.to_bytes(),
-            &session_id.to_be_bytes(),
-            SIGNING_SESSION_PDA,
-        ],
-        &program_id,
-    );
+    let (signing_pda, _) = find_signing_session_pda(&root_pda, session_id, &program_id);
     info!("Signing Session PDA: {}", signing_pda);
 
     let account = rpc_client
         .get_account(&signing_pda)
         .context("Failed to fetch signing session. Does it exist?")?;
 
-    let session = SigningSession::try_from_slice(&account.data)
+    let session = SigningSession::deserialize(&account.data)
         .context("Failed to deserialize signing session data")?;
 
     info!("\n=== Signing Session State ===");
     info!("Session ID: {}", session.session_id);
     info!("Root PDA: {}", session.root_pda);
+    info!("Proposer: {}", session.proposer);
+    info!("Rent Payer: {}", session.rent_payer);
     info!("Phase: {:?}", session.phase);
-    info!("Approvals ({}):", session.approvals.len());
-    for (i, approver) in session.approvals.iter().enumerate() {
+    info!("Proposal: {:?}", session.proposal);
+    info!("Expires At: {:?}", session.expires_at);
+    let root = fetch_root(&rpc_client, &root_pda)?;
+    if let Some(approved_at) = session.approved_at {
+        info!("Approved At: {} (unix timestamp)", approved_at);
+    }
+    if let Some(earliest) = session.header().earliest_execution_time(root.timelock) {
+        info!("Earliest Execution: {} (unix timestamp)", earliest);
+    }
+    info!(
+        "Approvals ({}/{} weight):",
+        vote_weight(&root, session.approvals, session.operators_epoch),
+        root.threshold
+    );
+    for (i, approver) in voters(&root, session.approvals, session.operators_epoch)
+        .iter()
+        .enumerate()
+    {
         info!("  {}. {}", i + 1, approver);
     }
-    info!("Instruction Data: {} bytes", session.instruction_data.len());
-    info!("  Hex: {}", hex::encode(&session.instruction_data));
     info!(
-        "Instruction Accounts: {}",
-        session.instruction_accounts.len()
+        "Rejections ({} weight):",
+        vote_weight(&root, session.rejections, session.operators_epoch)
+    );
+    for (i, rejecter) in voters(&root, session.rejections, session.operators_epoch)
+        .iter()
+        .enumerate()
+    {
+        info!("  {}. {}", i + 1, rejecter);
+    }
+    info!("Instructions ({}):", session.instructions.len());
+    for (i, instruction) in session.instructions.iter().enumerate() {
+        info!("  {}. Program: {}", i + 1, instruction.program_id);
+        info!("     Data: {} bytes", instruction.data.len());
+        info!("     Hex: {}", hex::encode(&instruction.data));
+        info!("     Accounts: {}", instruction.accounts.len());
+    }
+    if let Proposal::Loader {
+        change: LoaderChange::Upgrade { buffer, .. },
+        ..
+    } = &session.proposal
+    {
+        match fetch_buffer_hash(&rpc_client, buffer) {
+            Ok((_, buffer_hash)) => info!("Buffer program data hash: {}", buffer_hash),
+            Err(err) => info!("Buffer program data hash: unavailable ({})", err),
+        }
+    }
+    info!(
+        "Vault: {} (index {})",
+        find_vault_pda(&root_pda, session.vault_index, &program_id).0,
+        session.vault_index
     );
     info!("Bump: {}", session.bump);
     info!("Account Owner: {}", account.owner);
@@ -417,11 +972,15 @@ pub async fn handle_list_sessions(config: &Config) -> Result<()> {
     let program_id = get_program_id(config)?;
     let rpc_client = RpcClient::new_with_commitment(&config.rpc_url, CommitmentConfig::confirmed());
 
+    let root_pda = get_root_pda(config, &program_id)?;
+    let root = fetch_root(&rpc_client, &root_pda)?;
     let accounts = rpc_client.get_program_accounts(&program_id)?;
 
     let mut sessions = Vec::new();
     for (pubkey, account) in accounts {
-        if let Ok(session) = SigningSession::try_from_slice(&account.data) {
+        if let Ok(session) = SigningSession::deserialize(&account.data)
+            && session.root_pda == root_pda
+        {
             sessions.push((pubkey, session));
         }
     }
@@ -446,7 +1005,11 @@ pub async fn handle_list_sessions(config: &Config) -> Result<()> {
             session.session_id,
             pubkey,
             session.phase,
-            session.approvals.len()
+            format!(
+                "{}/{}",
+                vote_weight(&root, session.approvals, session.operators_epoch),
+                root.threshold
+            )
         );
     }
 
@@ -466,38 +1029,18 @@ pub async fn handle_close_session(
     let closer = load_keypair(&closer_path)?;
     info!("Closer: {}", closer.pubkey());
 
-    let (root_pda, _) = Pubkey::find_program_address(&[ROOT_PDA], &program_id);
+    let root_pda = get_root_pda(config, &program_id)?;
 
-    let (signing_pda, signing_bump) = Pubkey::find_program_address(
-        &[
-            &root_pda.to_bytes(),
-            &session_id.to_be_bytes(),
-            SIGNING_SESSION_PDA,
-        ],
-        &program_id,
-    );
+    let (signing_pda, _) = find_signing_session_pda(&root_pda, session_id, &program_id);
 
     let account = rpc_client
         .get_account(&signing_pda)
         .context("Failed to fetch signing session. Does it exist?")?;
-    let session = SigningSession::try_from_slice(&account.data)?;
+    let session = SigningSession::deserialize(&account.data)?;
 
     info!("Current phase: {:?}", session.phase);
 
-    let close_ix_data = CloseSessionIxData { bump: signing_bump };
-    let mut data = vec![ProgramIx::CloseSession as u8];
-    data.extend_from_slice(&borsh::to_vec(&close_ix_data)?);
-
-    let instruction = Instruction::new_with_bytes(
-        program_id,
-        &data,
-        vec![
-            AccountMeta::new(closer.pubkey(), true),
-            AccountMeta::new_readonly(root_pda, false),
-            AccountMeta::new(signing_pda, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-        ],
-    );
+    let instruction = close_session(&program_id, &closer.pubkey(), &root_pda, &session);
 
     let recent_blockhash = rpc_client.get_latest_blockhash()?;
     let transaction = Transaction::new_signed_with_payer(
@@ -512,7 +1055,92 @@ pub async fn handle_close_session(
     info!("\n✅ Session closed successfully!");
     info!("Transaction signature: {}", signature);
     info!("Session ID: {}", session_id);
-    info!("Reclaimed rent sent to: {}", closer.pubkey());
+    info!("Reclaimed rent sent to: {}", session.rent_payer);
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data_line(event: &Event) -> String {
+        let fields = [
+            EVENT_TAG.to_vec(),
+            vec![EVENT_VERSION],
+            borsh::to_vec(event).unwrap(),
+        ];
+        let fields: Vec<String> = fields.iter().map(|field| BASE64.encode(field)).collect();
+        format!("Program data: {}", fields.join(" "))
+    }
+
+    fn cancelled(session_id: u16) -> Event {
+        Event::SessionCancelled {
+            root: Pubkey::new_unique(),
+            session_id,
+            proposer: Pubkey::new_unique(),
+        }
+    }
+
+    #[test]
+    fn test_decode_events_skips_nested_program_data() {
+        let program_id = Pubkey::new_unique();
+        let other_program = Pubkey::new_unique();
+        let (first, second) = (cancelled(1), cancelled(2));
+        let logs = vec![
+            format!("Program {program_id} invoke [1]"),
+            data_line(&first),
+            format!("Program {other_program} invoke [2]"),
+            // callee logging data that looks like a mosaic event
+            data_line(&cancelled(3)),
+            format!("Program {other_program} success"),
+            "Program log: back in mosaic".to_string(),
+            data_line(&second),
+            format!("Program {program_id} consumed 1000 of 200000 compute units"),
+            format!("Program {program_id} success"),
+        ];
+
+        assert_eq!(
+            decode_events(&logs, &program_id).unwrap(),
+            vec![first, second]
+        );
+    }
+
+    #[test]
+    fn test_decode_events_skips_foreign_program_data() {
+        let program_id = Pubkey::new_unique();
+        let other_program = Pubkey::new_unique();
+        let event = cancelled(1);
+        let logs = vec![
+            format!("Program {other_program} invoke [1]"),
+            data_line(&cancelled(2)),
+            format!("Program {other_program} failed: custom program error: 0x1"),
+            format!("Program {program_id} invoke [1]"),
+            data_line(&event),
+            format!("Program {program_id} success"),
+            format!("Program {other_program} invoke [1]"),
+            data_line(&cancelled(3)),
+            format!("Program {other_program} success"),
+        ];
+
+        assert_eq!(decode_events(&logs, &program_id).unwrap(), vec![event]);
+    }
+
+    #[test]
+    fn test_decode_events_rejects_unknown_version() {
+        let program_id = Pubkey::new_unique();
+        let event = borsh::to_vec(&cancelled(1)).unwrap();
+        let logs = vec![
+            format!("Program {program_id} invoke [1]"),
+            format!(
+                "Program data: {} {} {}",
+                BASE64.encode(EVENT_TAG),
+                BASE64.encode([EVENT_VERSION + 1]),
+                BASE64.encode(event)
+            ),
+            format!("Program {program_id} success"),
+        ];
+
+        assert!(decode_events(&logs, &program_id).is_err());
+    }
+}
@@ -1,12 +1,21 @@
-use anyhow::Result;
-use clap::{Parser, Subcommand};
-use std::path::PathBuf;
+use anyhow::{Context, Result, anyhow};
+use clap::{Args, Parser, Subcommand};
+use solana_sdk::pubkey::Pubkey;
+use std::{path::PathBuf, str::FromStr};
+
+use mosaic_client::pda::find_program_data_address;
 
 use crate::{
     config::{load_config, merge_cli_config},
     handlers::{
-        handle_close_session, handle_create_session, handle_execute, handle_initialize_root,
-        handle_list_sessions, handle_sign, handle_view_root, handle_view_session,
+        handle_cancel, handle_close_session, handle_create_session, handle_events, handle_execute,
+        handle_initialize_root, handle_list_sessions, handle_loader_change, handle_propose_config,
+        handle_reject, handle_sign, handle_token_transfer, handle_transfer, handle_unsign,
+        handle_view_root, handle_view_session, handle_view_vault, parse_permissions,
+    },
+    types::{
+        ConfigChange, ExecutePolicy, Expiry, InitializeRootRequest, LoaderChange, Operator,
+        TokenTransferRequest,
     },
 };
 
@@ -29,19 +38,39 @@ struct Cli {
 
     #[arg(long, global = true)]
     program_id: Option<String>,
+
+    /// name of the root to operate on
+    #[arg(long, global = true)]
+    root_name: Option<String>,
+
+    /// creator of the root to operate on; defaults to payer
+    #[arg(long, global = true)]
+    root_creator: Option<String>,
 }
 
 #[derive(Debug, Subcommand)]
 enum Commands {
     InitializeRoot {
+        /// operators as `pubkey[:weight[:permissions]]`; defaults to weight one and `ive`
         #[arg(short, long, value_delimiter = ',')]
         operators: Vec<String>,
 
+        /// required summed weight of approvals
         #[arg(short, long)]
-        threshold: u8,
+        threshold: u16,
 
-        #[arg(short, long)]
-        destination_program: String,
+        #[arg(short, long, value_delimiter = ',')]
+        allowed_programs: Vec<String>,
+
+        #[command(flatten)]
+        default_ttl: TtlArgs,
+
+        /// seconds between session approval and its earliest execution
+        #[arg(long, default_value_t = 0)]
+        timelock: u32,
+
+        #[command(flatten)]
+        execute_policy: ExecutePolicyArgs,
 
         #[arg(short, long)]
         payer: Option<PathBuf>,
@@ -51,13 +80,155 @@ enum Commands {
         #[arg(short, long)]
         session_id: u16,
 
-        // (hex string)
+        // allowed program the session instructions cpi into
+        #[arg(long)]
+        target_program: Option<String>,
+
+        // (hex string), repeat once per instruction
+        #[arg(short, long, required = true)]
+        instruction_data: Vec<String>,
+
+        // Accounts represented as json, paired with each instruction data in order
+        #[arg(short, long, required = true)]
+        accounts: Vec<String>,
+
+        // root vault signing session instructions alongside root pda
+        #[arg(long, default_value_t = 0)]
+        vault_index: u8,
+
+        #[command(flatten)]
+        expires_at: ExpiryArgs,
+
+        #[arg(short, long)]
+        payer: Option<PathBuf>,
+    },
+
+    ProposeConfig {
+        #[arg(short, long)]
+        session_id: u16,
+
+        #[command(subcommand)]
+        change: ConfigChangeCommand,
+
+        #[command(flatten)]
+        expires_at: ExpiryArgs,
+
+        #[arg(short, long)]
+        payer: Option<PathBuf>,
+    },
+
+    Transfer {
+        #[arg(short, long)]
+        session_id: u16,
+
+        #[arg(short, long)]
+        recipient: String,
+
+        // lamports
+        #[arg(short, long)]
+        amount: u64,
+
+        #[arg(long, default_value_t = 0)]
+        vault_index: u8,
+
+        #[command(flatten)]
+        expires_at: ExpiryArgs,
+
+        #[arg(short, long)]
+        payer: Option<PathBuf>,
+    },
+
+    TokenTransfer {
+        #[arg(short, long)]
+        session_id: u16,
+
         #[arg(short, long)]
-        instruction_data: String,
+        mint: String,
 
-        // Accounts represented as json
+        // wallet receiving tokens into its associated token account
         #[arg(short, long)]
-        accounts: String,
+        recipient: String,
+
+        // base units of the mint
+        #[arg(short, long)]
+        amount: u64,
+
+        // vault owning the source token account; root pda when omitted
+        #[arg(long)]
+        vault_index: Option<u8>,
+
+        #[command(flatten)]
+        expires_at: ExpiryArgs,
+
+        #[arg(short, long)]
+        payer: Option<PathBuf>,
+    },
+
+    UpgradeProgram {
+        #[arg(short, long)]
+        session_id: u16,
+
+        #[arg(long)]
+        program: String,
+
+        // buffer holding the new program data; its hash is printed for review
+        #[arg(short, long)]
+        buffer: String,
+
+        // account receiving buffer lamports after the upgrade
+        #[arg(long)]
+        spill: String,
+
+        // vault holding upgrade authority; root pda when omitted
+        #[arg(long)]
+        vault_index: Option<u8>,
+
+        #[command(flatten)]
+        expires_at: ExpiryArgs,
+
+        #[arg(short, long)]
+        payer: Option<PathBuf>,
+    },
+
+    SetUpgradeAuthority {
+        #[arg(short, long)]
+        session_id: u16,
+
+        // buffer or program data account
+        #[arg(short, long)]
+        account: String,
+
+        // makes the account immutable when omitted
+        #[arg(short, long)]
+        new_authority: Option<String>,
+
+        // vault holding current authority; root pda when omitted
+        #[arg(long)]
+        vault_index: Option<u8>,
+
+        #[command(flatten)]
+        expires_at: ExpiryArgs,
+
+        #[arg(short, long)]
+        payer: Option<PathBuf>,
+    },
+
+    CloseBuffer {
+        #[arg(short, long)]
+        session_id: u16,
+
+        #[arg(short, long)]
+        buffer: String,
+
+        #[arg(short, long)]
+        recipient: String,
+
+        // vault holding buffer authority; root pda when omitted
+        #[arg(long)]
+        vault_index: Option<u8>,
+
+        #[command(flatten)]
+        expires_at: ExpiryArgs,
 
         #[arg(short, long)]
         payer: Option<PathBuf>,
@@ -71,13 +242,37 @@ enum Commands {
         signer: PathBuf,
     },
 
+    Unsign {
+        #[arg(short, long)]
+        session_id: u16,
+
+        #[arg(short, long)]
+        signer: PathBuf,
+    },
+
+    Reject {
+        #[arg(short, long)]
+        session_id: u16,
+
+        #[arg(short, long)]
+        signer: PathBuf,
+    },
+
+    Cancel {
+        #[arg(short, long)]
+        session_id: u16,
+
+        #[arg(short, long)]
+        proposer: PathBuf,
+    },
+
     Execute {
         #[arg(short, long)]
         session_id: u16,
 
         /// public key
         #[arg(long)]
-        storage_account: String,
+        storage_account: Option<String>,
 
         #[arg(short, long)]
         executor: PathBuf,
@@ -85,6 +280,17 @@ enum Commands {
 
     ViewRoot,
 
+    ViewVault {
+        #[arg(long, default_value_t = 0)]
+        vault_index: u8,
+    },
+
+    /// decodes mosaic events logged by a transaction
+    Events {
+        #[arg(short, long)]
+        signature: String,
+    },
+
     ViewSession {
         #[arg(short, long)]
         session_id: u16,
@@ -101,9 +307,207 @@ enum Commands {
     },
 }
 
+#[derive(Debug, Subcommand)]
+enum ConfigChangeCommand {
+    AddOperator {
+        operator: String,
+
+        #[arg(short, long, default_value_t = 1)]
+        weight: u8,
+
+        /// letters of granted permissions: `i`nitiate, `v`ote, `e`xecute
+        #[arg(short, long, default_value = "ive")]
+        permissions: String,
+    },
+
+    RemoveOperator {
+        operator: String,
+    },
+
+    ReplaceOperator {
+        old: String,
+        new: String,
+    },
+
+    ChangeThreshold {
+        threshold: u16,
+    },
+
+    /// clears default ttl when neither is given
+    ChangeDefaultTtl {
+        #[command(flatten)]
+        ttl: TtlArgs,
+    },
+
+    ChangeTimelock {
+        seconds: u32,
+    },
+
+    AddAllowedProgram {
+        program: String,
+    },
+
+    RemoveAllowedProgram {
+        program: String,
+    },
+
+    ChangeOperatorWeight {
+        operator: String,
+        weight: u8,
+    },
+
+    /// permissions as letters: `i`nitiate, `v`ote, `e`xecute
+    ChangeOperatorPermissions {
+        operator: String,
+        permissions: String,
+    },
+
+    ChangeExecutePolicy {
+        #[command(flatten)]
+        execute_policy: ExecutePolicyArgs,
+    },
+}
+
+// session expiry as absolute slot or unix timestamp
+#[derive(Debug, Args)]
+struct ExpiryArgs {
+    #[arg(long, conflicts_with = "expires_at_timestamp")]
+    expires_at_slot: Option<u64>,
+
+    #[arg(long)]
+    expires_at_timestamp: Option<i64>,
+}
+
+impl Commands {
+    /// returns `--payer` override of commands paying for their transaction
+    fn payer(&self) -> Option<&PathBuf> {
+        match self {
+            Commands::InitializeRoot { payer, .. }
+            | Commands::CreateSession { payer, .. }
+            | Commands::ProposeConfig { payer, .. }
+            | Commands::Transfer { payer, .. }
+            | Commands::TokenTransfer { payer, .. }
+            | Commands::UpgradeProgram { payer, .. }
+            | Commands::SetUpgradeAuthority { payer, .. }
+            | Commands::CloseBuffer { payer, .. } => payer.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl ExpiryArgs {
+    fn into_expiry(self) -> Option<Expiry> {
+        expiry(self.expires_at_slot, self.expires_at_timestamp)
+    }
+}
+
+// session lifetime in slots or seconds
+#[derive(Debug, Args)]
+struct TtlArgs {
+    #[arg(long, conflicts_with = "ttl_seconds")]
+    ttl_slots: Option<u64>,
+
+    #[arg(long)]
+    ttl_seconds: Option<i64>,
+}
+
+impl TtlArgs {
+    fn into_expiry(self) -> Option<Expiry> {
+        expiry(self.ttl_slots, self.ttl_seconds)
+    }
+}
+
+// who may execute approved sessions; operators with execute permission by default
+#[derive(Debug, Args)]
+struct ExecutePolicyArgs {
+    /// allow any signer to execute
+    #[arg(long, conflicts_with = "executors")]
+    execute_by_anyone: bool,
+
+    /// restrict execution to listed keys
+    #[arg(long, value_delimiter = ',')]
+    executors: Vec<String>,
+}
+
+impl ExecutePolicyArgs {
+    fn into_execute_policy(self) -> Result<ExecutePolicy> {
+        if self.execute_by_anyone {
+            return Ok(ExecutePolicy::Anyone);
+        }
+        if self.executors.is_empty() {
+            return Ok(ExecutePolicy::Operators);
+        }
+        let executors = self
+            .executors
+            .iter()
+            .map(|executor| Pubkey::from_str(executor).context("Invalid executor pubkey"))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ExecutePolicy::Executors(executors))
+    }
+}
+
+fn expiry(slot: Option<u64>, timestamp: Option<i64>) -> Option<Expiry> {
+    slot.map(Expiry::Slot)
+        .or(timestamp.map(Expiry::UnixTimestamp))
+}
+
+impl ConfigChangeCommand {
+    fn into_config_change(self) -> Result<ConfigChange> {
+        let parse = |key: &str| Pubkey::from_str(key).context("Invalid pubkey");
+        Ok(match self {
+            ConfigChangeCommand::AddOperator {
+                operator,
+                weight,
+                permissions,
+            } => ConfigChange::AddOperator(Operator {
+                key: parse(&operator)?,
+                weight,
+                permissions: parse_permissions(&permissions)?,
+            }),
+            ConfigChangeCommand::RemoveOperator { operator } => {
+                ConfigChange::RemoveOperator(parse(&operator)?)
+            }
+            ConfigChangeCommand::ReplaceOperator { old, new } => ConfigChange::ReplaceOperator {
+                old: parse(&old)?,
+                new: parse(&new)?,
+            },
+            ConfigChangeCommand::ChangeThreshold { threshold } => {
+                ConfigChange::ChangeThreshold(threshold)
+            }
+            ConfigChangeCommand::ChangeDefaultTtl { ttl } => {
+                ConfigChange::ChangeDefaultTtl(ttl.into_expiry())
+            }
+            ConfigChangeCommand::ChangeTimelock { seconds } => {
+                ConfigChange::ChangeTimelock(seconds)
+            }
+            ConfigChangeCommand::AddAllowedProgram { program } => {
+                ConfigChange::AddAllowedProgram(parse(&program)?)
+            }
+            ConfigChangeCommand::RemoveAllowedProgram { program } => {
+                ConfigChange::RemoveAllowedProgram(parse(&program)?)
+            }
+            ConfigChangeCommand::ChangeOperatorWeight { operator, weight } => {
+                ConfigChange::ChangeOperatorWeight {
+                    operator: parse(&operator)?,
+                    weight,
+                }
+            }
+            ConfigChangeCommand::ChangeOperatorPermissions {
+                operator,
+                permissions,
+            } => ConfigChange::ChangeOperatorPermissions {
+                operator: parse(&operator)?,
+                permissions: parse_permissions(&permissions)?,
+            },
+            ConfigChangeCommand::ChangeExecutePolicy { execute_policy } => {
+                ConfigChange::ChangeExecutePolicy(execute_policy.into_execute_policy()?)
+            }
+        })
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
-
     tracing_subscriber::fmt::init();
 
     let cli = Cli::parse();
@@ -115,25 +519,200 @@ async fn main() -> Result<()> {
         Commands::InitializeRoot {
             operators,
             threshold,
-            destination_program,
+            allowed_programs,
+            default_ttl,
+            timelock,
+            execute_policy,
             payer,
         } => {
-            handle_initialize_root(&config, operators, threshold, destination_program, payer)
-                .await?
+            let request = InitializeRootRequest {
+                operators,
+                threshold,
+                allowed_programs,
+                default_ttl: default_ttl.into_expiry(),
+                timelock,
+                execute_policy: execute_policy.into_execute_policy()?,
+            };
+            handle_initialize_root(&config, request, payer).await?
         }
         Commands::CreateSession {
             session_id,
+            target_program,
             instruction_data,
             accounts,
+            vault_index,
+            expires_at,
+            payer,
+        } => {
+            if instruction_data.len() != accounts.len() {
+                return Err(anyhow!(
+                    "Each instruction needs both instruction data and accounts ({} data, {} accounts)",
+                    instruction_data.len(),
+                    accounts.len()
+                ));
+            }
+            handle_create_session(
+                &config,
+                session_id,
+                target_program,
+                instruction_data.into_iter().zip(accounts).collect(),
+                vault_index,
+                expires_at.into_expiry(),
+                payer,
+            )
+            .await?
+        }
+        Commands::ProposeConfig {
+            session_id,
+            change,
+            expires_at,
             payer,
-        } => handle_create_session(&config, session_id, instruction_data, accounts, payer).await?,
+        } => {
+            handle_propose_config(
+                &config,
+                session_id,
+                change.into_config_change()?,
+                expires_at.into_expiry(),
+                payer,
+            )
+            .await?
+        }
+        Commands::Transfer {
+            session_id,
+            recipient,
+            amount,
+            vault_index,
+            expires_at,
+            payer,
+        } => {
+            handle_transfer(
+                &config,
+                session_id,
+                recipient,
+                amount,
+                vault_index,
+                expires_at.into_expiry(),
+                payer,
+            )
+            .await?
+        }
+        Commands::TokenTransfer {
+            session_id,
+            mint,
+            recipient,
+            amount,
+            vault_index,
+            expires_at,
+            payer,
+        } => {
+            let request = TokenTransferRequest {
+                mint: Pubkey::from_str(&mint).context("Invalid mint pubkey")?,
+                recipient: Pubkey::from_str(&recipient).context("Invalid recipient pubkey")?,
+                amount,
+                vault_index,
+            };
+            handle_token_transfer(
+                &config,
+                session_id,
+                request,
+                expires_at.into_expiry(),
+                payer,
+            )
+            .await?
+        }
+        Commands::UpgradeProgram {
+            session_id,
+            program,
+            buffer,
+            spill,
+            vault_index,
+            expires_at,
+            payer,
+        } => {
+            let program = Pubkey::from_str(&program).context("Invalid program pubkey")?;
+            let change = LoaderChange::Upgrade {
+                program,
+                program_data: find_program_data_address(&program),
+                buffer: Pubkey::from_str(&buffer).context("Invalid buffer pubkey")?,
+                spill: Pubkey::from_str(&spill).context("Invalid spill pubkey")?,
+            };
+            handle_loader_change(
+                &config,
+                session_id,
+                change,
+                vault_index,
+                expires_at.into_expiry(),
+                payer,
+            )
+            .await?
+        }
+        Commands::SetUpgradeAuthority {
+            session_id,
+            account,
+            new_authority,
+            vault_index,
+            expires_at,
+            payer,
+        } => {
+            let change = LoaderChange::SetAuthority {
+                account: Pubkey::from_str(&account).context("Invalid account pubkey")?,
+                new_authority: new_authority
+                    .map(|authority| {
+                        Pubkey::from_str(&authority).context("Invalid new authority pubkey")
+                    })
+                    .transpose()?,
+            };
+            handle_loader_change(
+                &config,
+                session_id,
+                change,
+                vault_index,
+                expires_at.into_expiry(),
+                payer,
+            )
+            .await?
+        }
+        Commands::CloseBuffer {
+            session_id,
+            buffer,
+            recipient,
+            vault_index,
+            expires_at,
+            payer,
+        } => {
+            let change = LoaderChange::CloseBuffer {
+                buffer: Pubkey::from_str(&buffer).context("Invalid buffer pubkey")?,
+                recipient: Pubkey::from_str(&recipient).context("Invalid recipient pubkey")?,
+            };
+            handle_loader_change(
+                &config,
+                session_id,
+                change,
+                vault_index,
+                expires_at.into_expiry(),
+                payer,
+            )
+            .await?
+        }
         Commands::Sign { session_id, signer } => handle_sign(&config, session_id, signer).await?,
+        Commands::Unsign { session_id, signer } => {
+            handle_unsign(&config, session_id, signer).await?
+        }
+        Commands::Reject { session_id, signer } => {
+            handle_reject(&config, session_id, signer).await?
+        }
+        Commands::Cancel {
+            session_id,
+            proposer,
+        } => handle_cancel(&config, session_id, proposer).await?,
         Commands::Execute {
             session_id,
             storage_account,
             executor,
         } => handle_execute(&config, session_id, storage_account, executor).await?,
         Commands::ViewRoot => handle_view_root(&config).await?,
+        Commands::ViewVault { vault_index } => handle_view_vault(&config, vault_index).await?,
+        Commands::Events { signature } => handle_events(&config, signature).await?,
         Commands::ViewSession { session_id } => handle_view_session(&config, session_id).await?,
         Commands::ListSessions => handle_list_sessions(&config).await?,
         Commands::CloseSession { session_id, closer } => {
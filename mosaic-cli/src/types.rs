@@ -1,23 +1,17 @@
-use borsh::{BorshDeserialize, BorshSerialize};
 use serde::Deserialize;
 use solana_sdk::pubkey::Pubkey;
 
-#[derive(BorshSerialize, BorshDeserialize)]
-pub struct InitializeRootIxData {
-    pub operators: Vec<Pubkey>,
-    pub threshold: u8,
-    pub bump: u8,
-    pub destination_program: Pubkey,
-}
-
-#[repr(u8)]
-pub enum ProgramIx {
-    InitializeOperators = 0,
-    CreateSession = 1,
-    Sign = 2,
-    Execute = 3,
-    CloseSession = 4
-}
+pub use mosaic_client::{
+    events::{EVENT_TAG, EVENT_VERSION, Event},
+    state::{
+        expiry::Expiry,
+        proposal::{ConfigChange, LoaderChange, Proposal, TokenTransfer},
+        root::{ExecutePolicy, Operator, Permission, Root},
+        signing_session::{
+            InstructionAccount, SessionInstruction, SigningSession, SigningSessionPhase,
+        },
+    },
+};
 
 #[derive(Deserialize)]
 pub struct InstructionAccountJson {
@@ -26,58 +20,20 @@ pub struct InstructionAccountJson {
     pub writable: bool,
 }
 
-#[derive(BorshSerialize)]
-pub struct InstructionAccount {
-    pub pubkey: [u8; 32],
-    pub signer: bool,
-    pub writable: bool,
-}
-
-#[derive(BorshSerialize, BorshDeserialize)]
-pub struct CreateSessionIxData {
-    pub session_id: u16,
-    pub instruction_data: Vec<u8>,
-    pub instruction_accounts: Vec<Vec<u8>>,
-    pub bump: u8,
-}
-
-#[derive(BorshSerialize, BorshDeserialize)]
-pub struct SignIxData {
-    pub bump: u8,
+/// token transfer as requested on the command line; token accounts get derived from it
+pub struct TokenTransferRequest {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub vault_index: Option<u8>,
 }
 
-#[derive(BorshSerialize, BorshDeserialize, Debug)]
-pub struct SigningSession {
-    pub session_id: u16,
-    pub root_pda: Pubkey,
-    pub phase: SigningSessionPhase,
-    pub approvals: Vec<Pubkey>,
-    pub instruction_data: Vec<u8>,
-    pub instruction_accounts: Vec<Vec<u8>>,
-    pub bump: u8,
-}
-
-
-#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq)]
-pub enum SigningSessionPhase {
-    Active,
-    Approved,
-    Executed,
-}
-
-#[derive(BorshSerialize, BorshDeserialize, Debug)]
-pub struct Root {
-    pub operators: Vec<Pubkey>,
-    pub last_id: u16,
-    pub destination_program: Pubkey,
-    pub threshold: u8,
-    pub bump: u8,
-}
-
-#[derive(BorshSerialize, BorshDeserialize)]
-pub struct ExecuteIxData {}
-
-#[derive(BorshSerialize, BorshDeserialize)]
-pub struct CloseSessionIxData {
-    pub bump: u8,
+/// root settings as requested on the command line
+pub struct InitializeRootRequest {
+    pub operators: Vec<String>,
+    pub threshold: u16,
+    pub allowed_programs: Vec<String>,
+    pub default_ttl: Option<Expiry>,
+    pub timelock: u32,
+    pub execute_policy: ExecutePolicy,
 }
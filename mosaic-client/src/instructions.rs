@@ -0,0 +1,344 @@
+use borsh::BorshSerialize;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+use solana_sdk_ids::{bpf_loader_upgradeable, system_program};
+
+pub use mosaic::instructions::{
+    Instruction as MosaicInstruction, cancel::CancelIxData, close_session::CloseSessionIxData,
+    execute::ExecuteIxData, init_root::InitializeRootIxData,
+    init_signing_session::InitializeSigningSessionIxData, reject::RejectIxData, sign::SignIxData,
+    unsign::UnsignIxData,
+};
+
+use crate::{
+    pda::{find_root_pda, find_signing_session_pda, find_vault_pda},
+    state::{
+        expiry::Expiry,
+        proposal::{LoaderChange, Proposal},
+        root::{ExecutePolicy, Operator},
+        signing_session::{InstructionAccount, SessionInstruction, SigningSession},
+    },
+};
+
+/// root settings; bump gets derived from payer and name
+pub struct InitializeRootArgs {
+    pub name: String,
+    pub operators: Vec<Operator>,
+    pub threshold: u16,
+    pub allowed_programs: Vec<Pubkey>,
+    pub default_ttl: Option<Expiry>,
+    pub timelock: u32,
+    pub execute_policy: ExecutePolicy,
+}
+
+/// signing session settings; bump gets derived from root and session id
+pub struct InitializeSigningSessionArgs {
+    pub session_id: u16,
+    pub proposal: Proposal,
+    pub instructions: Vec<SessionInstruction>,
+    pub vault_index: u8,
+    pub expires_at: Option<Expiry>,
+}
+
+/// prepends opcode to borsh serialized instruction data
+fn instruction_data(opcode: MosaicInstruction, ix_data: &impl BorshSerialize) -> Vec<u8> {
+    let mut data = vec![opcode as u8];
+    ix_data
+        .serialize(&mut data)
+        .expect("serializing into vec never fails");
+    data
+}
+
+/// builds `InitializeOperators` creating root of given payer; returns root pda alongside
+pub fn initialize_root(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    args: InitializeRootArgs,
+) -> (Instruction, Pubkey) {
+    let (root_pda, bump) = find_root_pda(payer, &args.name, program_id);
+    let ix_data = InitializeRootIxData {
+        name: args.name,
+        operators: args.operators,
+        threshold: args.threshold,
+        allowed_programs: args.allowed_programs,
+        default_ttl: args.default_ttl,
+        timelock: args.timelock,
+        execute_policy: args.execute_policy,
+        bump,
+    };
+    let instruction = Instruction::new_with_bytes(
+        *program_id,
+        &instruction_data(MosaicInstruction::InitializeOperators, &ix_data),
+        vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    (instruction, root_pda)
+}
+
+/// builds `InitializeSigningSession` proposed by payer; returns signing session pda alongside
+pub fn initialize_signing_session(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    root_pda: &Pubkey,
+    args: InitializeSigningSessionArgs,
+) -> (Instruction, Pubkey) {
+    let (signing_pda, bump) = find_signing_session_pda(root_pda, args.session_id, program_id);
+    let ix_data = InitializeSigningSessionIxData {
+        proposal: args.proposal,
+        instructions: args.instructions,
+        vault_index: args.vault_index,
+        expires_at: args.expires_at,
+        bump,
+    };
+    let instruction = Instruction::new_with_bytes(
+        *program_id,
+        &instruction_data(MosaicInstruction::InitializeSigningSession, &ix_data),
+        vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(*root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    );
+    (instruction, signing_pda)
+}
+
+/// builds `Sign` approving session of given id by signer
+pub fn sign(
+    program_id: &Pubkey,
+    signer: &Pubkey,
+    root_pda: &Pubkey,
+    session_id: u16,
+) -> Instruction {
+    let (signing_pda, bump) = find_signing_session_pda(root_pda, session_id, program_id);
+    Instruction::new_with_bytes(
+        *program_id,
+        &instruction_data(MosaicInstruction::Sign, &SignIxData { bump }),
+        vec![
+            AccountMeta::new(*signer, true),
+            AccountMeta::new_readonly(*root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    )
+}
+
+/// builds `Unsign` revoking approval of signer from session of given id
+pub fn unsign(
+    program_id: &Pubkey,
+    signer: &Pubkey,
+    root_pda: &Pubkey,
+    session_id: u16,
+) -> Instruction {
+    let (signing_pda, bump) = find_signing_session_pda(root_pda, session_id, program_id);
+    Instruction::new_with_bytes(
+        *program_id,
+        &instruction_data(MosaicInstruction::Unsign, &UnsignIxData { bump }),
+        vec![
+            AccountMeta::new(*signer, true),
+            AccountMeta::new_readonly(*root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    )
+}
+
+/// builds `Reject` rejecting session of given id by signer
+pub fn reject(
+    program_id: &Pubkey,
+    signer: &Pubkey,
+    root_pda: &Pubkey,
+    session_id: u16,
+) -> Instruction {
+    let (signing_pda, bump) = find_signing_session_pda(root_pda, session_id, program_id);
+    Instruction::new_with_bytes(
+        *program_id,
+        &instruction_data(MosaicInstruction::Reject, &RejectIxData { bump }),
+        vec![
+            AccountMeta::new(*signer, true),
+            AccountMeta::new_readonly(*root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    )
+}
+
+/// builds `Cancel` of session of given id by its proposer
+pub fn cancel(
+    program_id: &Pubkey,
+    proposer: &Pubkey,
+    root_pda: &Pubkey,
+    session_id: u16,
+) -> Instruction {
+    let (signing_pda, bump) = find_signing_session_pda(root_pda, session_id, program_id);
+    Instruction::new_with_bytes(
+        *program_id,
+        &instruction_data(MosaicInstruction::Cancel, &CancelIxData { bump }),
+        vec![
+            AccountMeta::new(*proposer, true),
+            AccountMeta::new_readonly(*root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    )
+}
+
+/// builds `CloseSession` of given session; its lamports get refunded to the recorded rent payer
+pub fn close_session(
+    program_id: &Pubkey,
+    closer: &Pubkey,
+    root_pda: &Pubkey,
+    session: &SigningSession,
+) -> Instruction {
+    let (signing_pda, bump) = find_signing_session_pda(root_pda, session.session_id, program_id);
+    Instruction::new_with_bytes(
+        *program_id,
+        &instruction_data(
+            MosaicInstruction::CloseSession,
+            &CloseSessionIxData { bump },
+        ),
+        vec![
+            AccountMeta::new_readonly(*closer, true),
+            AccountMeta::new_readonly(*root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new(session.rent_payer, false),
+        ],
+    )
+}
+
+/// builds `Execute` of given session; accounts its proposal and instructions touch are appended
+/// as remaining accounts
+pub fn execute(
+    program_id: &Pubkey,
+    executor: &Pubkey,
+    root_pda: &Pubkey,
+    session: &SigningSession,
+) -> borsh::io::Result<Instruction> {
+    let (signing_pda, _) = find_signing_session_pda(root_pda, session.session_id, program_id);
+
+    // config proposals rewrite root account
+    let root_meta = match session.proposal {
+        Proposal::Cpi
+        | Proposal::Transfer { .. }
+        | Proposal::TokenTransfer(_)
+        | Proposal::Loader { .. } => AccountMeta::new_readonly(*root_pda, false),
+        Proposal::Config(_) => AccountMeta::new(*root_pda, false),
+    };
+
+    // config and transfer proposals cpi nowhere; mosaic itself fills the program slot
+    let target_program = session
+        .instructions
+        .first()
+        .map_or(*program_id, |instruction| instruction.program_id);
+
+    let mut accounts = vec![
+        AccountMeta::new(*executor, true),
+        root_meta,
+        AccountMeta::new(signing_pda, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new_readonly(target_program, false),
+    ];
+    accounts.extend(proposal_accounts(program_id, root_pda, &session.proposal));
+    for instruction in &session.instructions {
+        merge_account_meta(
+            &mut accounts,
+            AccountMeta::new_readonly(instruction.program_id, false),
+        );
+        for account in &instruction.accounts {
+            let meta = InstructionAccount::deserialize(account)
+                .map_err(|_| borsh::io::Error::other("invalid session instruction account"))?;
+            let pubkey = Pubkey::new_from_array(meta.pubkey);
+            if pubkey == *root_pda {
+                continue;
+            }
+            merge_account_meta(
+                &mut accounts,
+                AccountMeta {
+                    pubkey,
+                    is_signer: false,
+                    is_writable: meta.writable,
+                },
+            );
+        }
+    }
+
+    Ok(Instruction::new_with_bytes(
+        *program_id,
+        &instruction_data(MosaicInstruction::Execute, &ExecuteIxData {}),
+        accounts,
+    ))
+}
+
+/// appends meta unless its key is already listed, otherwise widens listed meta to its access
+fn merge_account_meta(accounts: &mut Vec<AccountMeta>, meta: AccountMeta) {
+    match accounts.iter_mut().find(|acc| acc.pubkey == meta.pubkey) {
+        Some(listed) => {
+            listed.is_writable |= meta.is_writable;
+            listed.is_signer |= meta.is_signer;
+        }
+        None => accounts.push(meta),
+    }
+}
+
+/// returns remaining accounts the proposal expects ahead of session instruction accounts
+fn proposal_accounts(
+    program_id: &Pubkey,
+    root_pda: &Pubkey,
+    proposal: &Proposal,
+) -> Vec<AccountMeta> {
+    match proposal {
+        Proposal::Cpi | Proposal::Config(_) => vec![],
+        // vault followed by recipient
+        Proposal::Transfer {
+            recipient,
+            vault_index,
+            ..
+        } => vec![
+            AccountMeta::new(find_vault_pda(root_pda, *vault_index, program_id).0, false),
+            AccountMeta::new(*recipient, false),
+        ],
+        // token accounts, mint and token program, then signing vault
+        Proposal::TokenTransfer(transfer) => {
+            let mut accounts = vec![
+                AccountMeta::new(transfer.source, false),
+                AccountMeta::new_readonly(transfer.mint, false),
+                AccountMeta::new(transfer.destination, false),
+                AccountMeta::new_readonly(transfer.token_program, false),
+            ];
+            if let Some(vault_index) = transfer.vault_index {
+                let (vault_pda, _) = find_vault_pda(root_pda, vault_index, program_id);
+                accounts.push(AccountMeta::new(vault_pda, false));
+            }
+            accounts
+        }
+        // loader instruction accounts and loader, then signing vault
+        Proposal::Loader {
+            change,
+            vault_index,
+        } => {
+            let mut accounts = loader_change_accounts(change);
+            accounts.push(AccountMeta::new_readonly(
+                bpf_loader_upgradeable::id(),
+                false,
+            ));
+            if let Some(vault_index) = vault_index {
+                let (vault_pda, _) = find_vault_pda(root_pda, *vault_index, program_id);
+                accounts.push(AccountMeta::new(vault_pda, false));
+            }
+            accounts
+        }
+    }
+}
+
+/// returns loader instruction accounts the program expects, authority excluded
+fn loader_change_accounts(change: &LoaderChange) -> Vec<AccountMeta> {
+    change
+        .accounts()
+        .into_iter()
+        .map(|(pubkey, writable)| match writable {
+            true => AccountMeta::new(pubkey, false),
+            false => AccountMeta::new_readonly(pubkey, false),
+        })
+        .collect()
+}
@@ -0,0 +1,12 @@
+//! host side access to mosaic account and instruction types, re-exported from the program crate
+//! so off-chain tooling can't drift from the on-chain layout.
+
+pub mod instructions;
+pub mod pda;
+
+pub use mosaic::{errors, events, programs, seeds, state};
+
+use solana_sdk::pubkey::Pubkey;
+
+/// id the mosaic program is deployed under
+pub const PROGRAM_ID: Pubkey = Pubkey::new_from_array(mosaic::ID);
@@ -0,0 +1,35 @@
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk_ids::bpf_loader_upgradeable;
+
+use crate::seeds::{ROOT_PDA, SIGNING_SESSION_PDA, VAULT_PDA};
+
+/// finds root pda namespaced by creator and name
+pub fn find_root_pda(creator: &Pubkey, name: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ROOT_PDA, creator.as_ref(), name.as_bytes()], program_id)
+}
+
+/// finds signing session pda of the root with given id
+pub fn find_signing_session_pda(
+    root_pda: &Pubkey,
+    session_id: u16,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            root_pda.as_ref(),
+            &session_id.to_be_bytes(),
+            SIGNING_SESSION_PDA,
+        ],
+        program_id,
+    )
+}
+
+/// finds system owned vault pda of the root at given index
+pub fn find_vault_pda(root_pda: &Pubkey, vault_index: u8, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_PDA, root_pda.as_ref(), &[vault_index]], program_id)
+}
+
+/// finds program data account of an upgradeable program
+pub fn find_program_data_address(program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[program.as_ref()], &bpf_loader_upgradeable::id()).0
+}
@@ -0,0 +1,578 @@
+use mosaic_client::{
+    PROGRAM_ID,
+    instructions::{
+        InitializeRootArgs, InitializeSigningSessionArgs, MosaicInstruction, cancel, close_session,
+        execute, initialize_root, initialize_signing_session, reject, sign, unsign,
+    },
+    pda::{find_root_pda, find_signing_session_pda, find_vault_pda},
+    state::{
+        proposal::{ConfigChange, LoaderChange, Proposal, TokenTransfer},
+        root::{ExecutePolicy, Operator, Permission},
+        signing_session::{
+            InstructionAccount, SessionInstruction, SigningSession, SigningSessionPhase,
+        },
+    },
+};
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_sdk_ids::{
+    bpf_loader_upgradeable, system_program,
+    sysvar::{clock, rent},
+};
+
+const ROOT_NAME: &str = "mosaic";
+
+fn session(session_id: u16, root_pda: Pubkey, proposal: Proposal) -> SigningSession {
+    SigningSession {
+        session_id,
+        root_pda,
+        proposer: Pubkey::new_unique(),
+        rent_payer: Pubkey::new_unique(),
+        phase: SigningSessionPhase::Approved,
+        proposal,
+        approvals: 0,
+        rejections: 0,
+        operators_epoch: 0,
+        expires_at: None,
+        approved_at: Some(0),
+        instructions: vec![],
+        vault_index: 0,
+        vault_bump: 0,
+        bump: 0,
+    }
+}
+
+fn session_account(pubkey: Pubkey, writable: bool) -> Vec<u8> {
+    borsh::to_vec(&InstructionAccount {
+        pubkey: pubkey.to_bytes(),
+        signer: false,
+        writable,
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_initialize_root_derives_root_pda() {
+    let payer = Pubkey::new_unique();
+    let (root_pda, bump) = find_root_pda(&payer, ROOT_NAME, &PROGRAM_ID);
+
+    let (instruction, returned_root_pda) = initialize_root(
+        &PROGRAM_ID,
+        &payer,
+        InitializeRootArgs {
+            name: ROOT_NAME.to_string(),
+            operators: vec![Operator {
+                key: payer,
+                weight: 1,
+                permissions: Permission::ALL,
+            }],
+            threshold: 1,
+            allowed_programs: vec![],
+            default_ttl: None,
+            timelock: 0,
+            execute_policy: ExecutePolicy::Operators,
+        },
+    );
+
+    assert_eq!(returned_root_pda, root_pda);
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ]
+    );
+    assert_eq!(
+        instruction.data[0],
+        MosaicInstruction::InitializeOperators as u8
+    );
+    assert_eq!(instruction.data.last(), Some(&bump));
+}
+
+#[test]
+fn test_initialize_signing_session_derives_session_pda() {
+    let payer = Pubkey::new_unique();
+    let (root_pda, _) = find_root_pda(&payer, ROOT_NAME, &PROGRAM_ID);
+    let (signing_pda, bump) = find_signing_session_pda(&root_pda, 3, &PROGRAM_ID);
+
+    let (instruction, returned_signing_pda) = initialize_signing_session(
+        &PROGRAM_ID,
+        &payer,
+        &root_pda,
+        InitializeSigningSessionArgs {
+            session_id: 3,
+            proposal: Proposal::Cpi,
+            instructions: vec![],
+            vault_index: 0,
+            expires_at: None,
+        },
+    );
+
+    assert_eq!(returned_signing_pda, signing_pda);
+    assert_eq!(
+        instruction.accounts[2],
+        AccountMeta::new(signing_pda, false)
+    );
+    assert_eq!(
+        instruction.data[0],
+        MosaicInstruction::InitializeSigningSession as u8
+    );
+    assert_eq!(instruction.data.last(), Some(&bump));
+}
+
+#[test]
+fn test_sign_derives_session_bump() {
+    let signer = Pubkey::new_unique();
+    let (root_pda, _) = find_root_pda(&signer, ROOT_NAME, &PROGRAM_ID);
+    let (signing_pda, bump) = find_signing_session_pda(&root_pda, 1, &PROGRAM_ID);
+
+    let instruction = sign(&PROGRAM_ID, &signer, &root_pda, 1);
+
+    assert_eq!(instruction.data, vec![MosaicInstruction::Sign as u8, bump]);
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ]
+    );
+}
+
+#[test]
+fn test_vote_builders_share_session_accounts() {
+    let signer = Pubkey::new_unique();
+    let (root_pda, _) = find_root_pda(&signer, ROOT_NAME, &PROGRAM_ID);
+    let (signing_pda, bump) = find_signing_session_pda(&root_pda, 1, &PROGRAM_ID);
+
+    for (instruction, opcode) in [
+        (
+            unsign(&PROGRAM_ID, &signer, &root_pda, 1),
+            MosaicInstruction::Unsign,
+        ),
+        (
+            reject(&PROGRAM_ID, &signer, &root_pda, 1),
+            MosaicInstruction::Reject,
+        ),
+        (
+            cancel(&PROGRAM_ID, &signer, &root_pda, 1),
+            MosaicInstruction::Cancel,
+        ),
+    ] {
+        assert_eq!(instruction.data, vec![opcode as u8, bump]);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(signer, true),
+                AccountMeta::new_readonly(root_pda, false),
+                AccountMeta::new(signing_pda, false),
+            ]
+        );
+    }
+}
+
+#[test]
+fn test_close_session_refunds_rent_payer() {
+    let closer = Pubkey::new_unique();
+    let (root_pda, _) = find_root_pda(&closer, ROOT_NAME, &PROGRAM_ID);
+    let (signing_pda, bump) = find_signing_session_pda(&root_pda, 1, &PROGRAM_ID);
+    let session = session(1, root_pda, Proposal::Cpi);
+
+    let instruction = close_session(&PROGRAM_ID, &closer, &root_pda, &session);
+
+    assert_eq!(
+        instruction.data,
+        vec![MosaicInstruction::CloseSession as u8, bump]
+    );
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new_readonly(closer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new(session.rent_payer, false),
+        ]
+    );
+}
+
+#[test]
+fn test_execute_appends_session_instruction_accounts() {
+    let executor = Pubkey::new_unique();
+    let (root_pda, _) = find_root_pda(&executor, ROOT_NAME, &PROGRAM_ID);
+    let (signing_pda, _) = find_signing_session_pda(&root_pda, 1, &PROGRAM_ID);
+    let target_program = Pubkey::new_unique();
+    let other_program = Pubkey::new_unique();
+    let storage = Pubkey::new_unique();
+    let readonly = Pubkey::new_unique();
+
+    let mut session = session(1, root_pda, Proposal::Cpi);
+    session.instructions = vec![
+        SessionInstruction {
+            program_id: target_program,
+            data: vec![1],
+            accounts: vec![
+                session_account(root_pda, false),
+                session_account(storage, true),
+            ],
+        },
+        SessionInstruction {
+            program_id: other_program,
+            data: vec![2],
+            accounts: vec![
+                session_account(storage, true),
+                session_account(readonly, false),
+            ],
+        },
+    ];
+
+    let instruction = execute(&PROGRAM_ID, &executor, &root_pda, &session).unwrap();
+
+    assert_eq!(instruction.data, vec![MosaicInstruction::Execute as u8]);
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(executor, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(target_program, false),
+            AccountMeta::new(storage, false),
+            AccountMeta::new_readonly(other_program, false),
+            AccountMeta::new_readonly(readonly, false),
+        ]
+    );
+}
+
+#[test]
+fn test_execute_merges_duplicate_account_access() {
+    let executor = Pubkey::new_unique();
+    let (root_pda, _) = find_root_pda(&executor, ROOT_NAME, &PROGRAM_ID);
+    let (signing_pda, _) = find_signing_session_pda(&root_pda, 1, &PROGRAM_ID);
+    let target_program = Pubkey::new_unique();
+    let shared = Pubkey::new_unique();
+
+    let mut session = session(1, root_pda, Proposal::Cpi);
+    session.instructions = vec![
+        SessionInstruction {
+            program_id: target_program,
+            data: vec![1],
+            accounts: vec![
+                session_account(shared, false),
+                session_account(executor, false),
+            ],
+        },
+        SessionInstruction {
+            program_id: target_program,
+            data: vec![2],
+            accounts: vec![session_account(shared, true)],
+        },
+    ];
+
+    let instruction = execute(&PROGRAM_ID, &executor, &root_pda, &session).unwrap();
+
+    // later writable use widens readonly meta; executor keeps its signer flag
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(executor, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(target_program, false),
+            AccountMeta::new(shared, false),
+        ]
+    );
+}
+
+#[test]
+fn test_execute_transfer_proposal_appends_vault_and_recipient() {
+    let executor = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let (root_pda, _) = find_root_pda(&executor, ROOT_NAME, &PROGRAM_ID);
+    let (signing_pda, _) = find_signing_session_pda(&root_pda, 1, &PROGRAM_ID);
+    let (vault_pda, _) = find_vault_pda(&root_pda, 2, &PROGRAM_ID);
+
+    let session = session(
+        1,
+        root_pda,
+        Proposal::Transfer {
+            recipient,
+            amount: 1,
+            vault_index: 2,
+        },
+    );
+
+    let instruction = execute(&PROGRAM_ID, &executor, &root_pda, &session).unwrap();
+
+    assert_eq!(instruction.data, vec![MosaicInstruction::Execute as u8]);
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(executor, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+            AccountMeta::new(vault_pda, false),
+            AccountMeta::new(recipient, false),
+        ]
+    );
+}
+
+#[test]
+fn test_execute_config_proposal_writes_root() {
+    let executor = Pubkey::new_unique();
+    let (root_pda, _) = find_root_pda(&executor, ROOT_NAME, &PROGRAM_ID);
+    let (signing_pda, _) = find_signing_session_pda(&root_pda, 1, &PROGRAM_ID);
+
+    let session = session(
+        1,
+        root_pda,
+        Proposal::Config(ConfigChange::ChangeThreshold(2)),
+    );
+
+    let instruction = execute(&PROGRAM_ID, &executor, &root_pda, &session).unwrap();
+
+    assert_eq!(instruction.data, vec![MosaicInstruction::Execute as u8]);
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(executor, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ]
+    );
+}
+
+#[test]
+fn test_execute_token_transfer_proposal_appends_vault() {
+    let executor = Pubkey::new_unique();
+    let (root_pda, _) = find_root_pda(&executor, ROOT_NAME, &PROGRAM_ID);
+    let (signing_pda, _) = find_signing_session_pda(&root_pda, 1, &PROGRAM_ID);
+    let (vault_pda, _) = find_vault_pda(&root_pda, 1, &PROGRAM_ID);
+    let transfer = TokenTransfer {
+        token_program: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        source: Pubkey::new_unique(),
+        destination: Pubkey::new_unique(),
+        amount: 1,
+        decimals: 6,
+        vault_index: Some(1),
+    };
+
+    let session = session(1, root_pda, Proposal::TokenTransfer(transfer.clone()));
+
+    let instruction = execute(&PROGRAM_ID, &executor, &root_pda, &session).unwrap();
+
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(executor, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+            AccountMeta::new(transfer.source, false),
+            AccountMeta::new_readonly(transfer.mint, false),
+            AccountMeta::new(transfer.destination, false),
+            AccountMeta::new_readonly(transfer.token_program, false),
+            AccountMeta::new(vault_pda, false),
+        ]
+    );
+}
+
+#[test]
+fn test_execute_token_transfer_proposal_signed_by_root() {
+    let executor = Pubkey::new_unique();
+    let (root_pda, _) = find_root_pda(&executor, ROOT_NAME, &PROGRAM_ID);
+    let (signing_pda, _) = find_signing_session_pda(&root_pda, 1, &PROGRAM_ID);
+    let transfer = TokenTransfer {
+        token_program: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        source: Pubkey::new_unique(),
+        destination: Pubkey::new_unique(),
+        amount: 1,
+        decimals: 6,
+        vault_index: None,
+    };
+
+    let session = session(1, root_pda, Proposal::TokenTransfer(transfer.clone()));
+
+    let instruction = execute(&PROGRAM_ID, &executor, &root_pda, &session).unwrap();
+
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(executor, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+            AccountMeta::new(transfer.source, false),
+            AccountMeta::new_readonly(transfer.mint, false),
+            AccountMeta::new(transfer.destination, false),
+            AccountMeta::new_readonly(transfer.token_program, false),
+        ]
+    );
+}
+
+#[test]
+fn test_execute_loader_upgrade_proposal_appends_loader_and_vault() {
+    let executor = Pubkey::new_unique();
+    let (root_pda, _) = find_root_pda(&executor, ROOT_NAME, &PROGRAM_ID);
+    let (signing_pda, _) = find_signing_session_pda(&root_pda, 1, &PROGRAM_ID);
+    let (vault_pda, _) = find_vault_pda(&root_pda, 0, &PROGRAM_ID);
+    let program = Pubkey::new_unique();
+    let program_data = Pubkey::new_unique();
+    let buffer = Pubkey::new_unique();
+    let spill = Pubkey::new_unique();
+
+    let session = session(
+        1,
+        root_pda,
+        Proposal::Loader {
+            change: LoaderChange::Upgrade {
+                program,
+                program_data,
+                buffer,
+                spill,
+            },
+            vault_index: Some(0),
+        },
+    );
+
+    let instruction = execute(&PROGRAM_ID, &executor, &root_pda, &session).unwrap();
+
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(executor, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+            AccountMeta::new(program_data, false),
+            AccountMeta::new(program, false),
+            AccountMeta::new(buffer, false),
+            AccountMeta::new(spill, false),
+            AccountMeta::new_readonly(rent::id(), false),
+            AccountMeta::new_readonly(clock::id(), false),
+            AccountMeta::new_readonly(bpf_loader_upgradeable::id(), false),
+            AccountMeta::new(vault_pda, false),
+        ]
+    );
+}
+
+#[test]
+fn test_execute_loader_set_authority_proposal_signed_by_root() {
+    let executor = Pubkey::new_unique();
+    let (root_pda, _) = find_root_pda(&executor, ROOT_NAME, &PROGRAM_ID);
+    let (signing_pda, _) = find_signing_session_pda(&root_pda, 1, &PROGRAM_ID);
+    let program_data = Pubkey::new_unique();
+    let new_authority = Pubkey::new_unique();
+
+    let session = session(
+        1,
+        root_pda,
+        Proposal::Loader {
+            change: LoaderChange::SetAuthority {
+                account: program_data,
+                new_authority: Some(new_authority),
+            },
+            vault_index: None,
+        },
+    );
+
+    let instruction = execute(&PROGRAM_ID, &executor, &root_pda, &session).unwrap();
+
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(executor, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+            AccountMeta::new(program_data, false),
+            AccountMeta::new_readonly(new_authority, false),
+            AccountMeta::new_readonly(bpf_loader_upgradeable::id(), false),
+        ]
+    );
+}
+
+#[test]
+fn test_execute_loader_close_buffer_proposal() {
+    let executor = Pubkey::new_unique();
+    let (root_pda, _) = find_root_pda(&executor, ROOT_NAME, &PROGRAM_ID);
+    let (signing_pda, _) = find_signing_session_pda(&root_pda, 1, &PROGRAM_ID);
+    let buffer = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+
+    let session = session(
+        1,
+        root_pda,
+        Proposal::Loader {
+            change: LoaderChange::CloseBuffer { buffer, recipient },
+            vault_index: None,
+        },
+    );
+
+    let instruction = execute(&PROGRAM_ID, &executor, &root_pda, &session).unwrap();
+
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(executor, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+            AccountMeta::new(buffer, false),
+            AccountMeta::new(recipient, false),
+            AccountMeta::new_readonly(bpf_loader_upgradeable::id(), false),
+        ]
+    );
+}
+
+#[test]
+fn test_execute_keeps_writable_access_of_later_readonly_duplicate() {
+    let executor = Pubkey::new_unique();
+    let (root_pda, _) = find_root_pda(&executor, ROOT_NAME, &PROGRAM_ID);
+    let (signing_pda, _) = find_signing_session_pda(&root_pda, 1, &PROGRAM_ID);
+    let target_program = Pubkey::new_unique();
+    let shared = Pubkey::new_unique();
+
+    let mut session = session(1, root_pda, Proposal::Cpi);
+    session.instructions = vec![
+        SessionInstruction {
+            program_id: target_program,
+            data: vec![1],
+            accounts: vec![
+                session_account(shared, true),
+                session_account(target_program, false),
+            ],
+        },
+        SessionInstruction {
+            program_id: target_program,
+            data: vec![2],
+            accounts: vec![session_account(shared, false)],
+        },
+    ];
+
+    let instruction = execute(&PROGRAM_ID, &executor, &root_pda, &session).unwrap();
+
+    // readonly reuse never narrows writable meta; target program is listed once
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(executor, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(target_program, false),
+            AccountMeta::new(shared, false),
+        ]
+    );
+}
@@ -0,0 +1,413 @@
+//! cpi helpers for programs invoking mosaic, e.g. to propose or approve as a pda operator.
+//!
+//! the signing pda pays for session creation when proposing, so it has to be a system owned
+//! account holding enough lamports. votes are written in place and cost the signer nothing.
+
+use borsh::BorshSerialize;
+use pinocchio::{
+    AccountView, Address, ProgramResult,
+    cpi::{Signer, invoke_signed, invoke_signed_with_slice},
+    error::ProgramError,
+    instruction::{InstructionAccount, InstructionView},
+};
+
+use crate::{
+    ID,
+    instructions::{
+        Instruction, cancel::CancelIxData, execute::ExecuteIxData,
+        init_signing_session::InitializeSigningSessionIxData, reject::RejectIxData,
+        sign::SignIxData, unsign::UnsignIxData,
+    },
+};
+
+/// prepends opcode to borsh serialized instruction data
+fn instruction_data(
+    opcode: Instruction,
+    ix_data: &impl BorshSerialize,
+) -> Result<Vec<u8>, ProgramError> {
+    let mut data = vec![opcode as u8];
+    ix_data
+        .serialize(&mut data)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    Ok(data)
+}
+
+/// Initialize Signing Session
+///
+/// ### accounts:
+///   0. `[WRITE, SIGNER]` proposer; operator with initiate permission
+///   1. `[WRITE]` root pda
+///   2. `[WRITE]` signing session pda of the next session id
+///   3. `[READ]`  system program
+pub struct InitializeSigningSession<'a> {
+    /// proposer paying for the session account.
+    pub proposer: &'a AccountView,
+
+    /// root the session is created in.
+    pub root: &'a AccountView,
+
+    /// signing session pda derived from root and its next session id.
+    pub signing_session: &'a AccountView,
+
+    /// system program.
+    pub system_program: &'a AccountView,
+
+    /// proposal, instructions and signing session bump.
+    pub data: &'a InitializeSigningSessionIxData,
+}
+
+impl InitializeSigningSession<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let instruction_accounts = self.instruction_accounts();
+        let data = self.data()?;
+
+        let instruction = InstructionView {
+            program_id: &Address::new_from_array(ID),
+            accounts: &instruction_accounts,
+            data: &data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                self.proposer,
+                self.root,
+                self.signing_session,
+                self.system_program,
+            ],
+            signers,
+        )
+    }
+
+    /// account metas in instruction order
+    pub fn instruction_accounts(&self) -> [InstructionAccount<'_>; 4] {
+        [
+            InstructionAccount::writable_signer(self.proposer.address()),
+            InstructionAccount::writable(self.root.address()),
+            InstructionAccount::writable(self.signing_session.address()),
+            InstructionAccount::readonly(self.system_program.address()),
+        ]
+    }
+
+    /// opcode followed by borsh serialized instruction data
+    pub fn data(&self) -> Result<Vec<u8>, ProgramError> {
+        instruction_data(Instruction::InitializeSigningSession, self.data)
+    }
+}
+
+/// Sign Session
+///
+/// ### accounts:
+///   0. `[WRITE, SIGNER]` signer; operator with vote permission
+///   1. `[READ]`  root pda
+///   2. `[WRITE]` signing session pda
+pub struct Sign<'a> {
+    /// operator approving the session.
+    pub signer: &'a AccountView,
+
+    /// root the session belongs to.
+    pub root: &'a AccountView,
+
+    /// signing session pda.
+    pub signing_session: &'a AccountView,
+
+    /// bump of the signing session pda.
+    pub bump: u8,
+}
+
+impl Sign<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let instruction_accounts = self.instruction_accounts();
+        let data = self.data()?;
+
+        let instruction = InstructionView {
+            program_id: &Address::new_from_array(ID),
+            accounts: &instruction_accounts,
+            data: &data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.signer, self.root, self.signing_session],
+            signers,
+        )
+    }
+
+    /// account metas in instruction order
+    pub fn instruction_accounts(&self) -> [InstructionAccount<'_>; 3] {
+        [
+            InstructionAccount::writable_signer(self.signer.address()),
+            InstructionAccount::readonly(self.root.address()),
+            InstructionAccount::writable(self.signing_session.address()),
+        ]
+    }
+
+    /// opcode followed by borsh serialized instruction data
+    pub fn data(&self) -> Result<Vec<u8>, ProgramError> {
+        instruction_data(Instruction::Sign, &SignIxData { bump: self.bump })
+    }
+}
+
+/// Unsign Session
+///
+/// ### accounts:
+///   0. `[WRITE, SIGNER]` signer; operator revoking its approval
+///   1. `[READ]`  root pda
+///   2. `[WRITE]` signing session pda
+pub struct Unsign<'a> {
+    /// operator revoking its approval.
+    pub signer: &'a AccountView,
+
+    /// root the session belongs to.
+    pub root: &'a AccountView,
+
+    /// signing session pda.
+    pub signing_session: &'a AccountView,
+
+    /// bump of the signing session pda.
+    pub bump: u8,
+}
+
+impl Unsign<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let instruction_accounts = self.instruction_accounts();
+        let data = self.data()?;
+
+        let instruction = InstructionView {
+            program_id: &Address::new_from_array(ID),
+            accounts: &instruction_accounts,
+            data: &data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.signer, self.root, self.signing_session],
+            signers,
+        )
+    }
+
+    /// account metas in instruction order
+    pub fn instruction_accounts(&self) -> [InstructionAccount<'_>; 3] {
+        [
+            InstructionAccount::writable_signer(self.signer.address()),
+            InstructionAccount::readonly(self.root.address()),
+            InstructionAccount::writable(self.signing_session.address()),
+        ]
+    }
+
+    /// opcode followed by borsh serialized instruction data
+    pub fn data(&self) -> Result<Vec<u8>, ProgramError> {
+        instruction_data(Instruction::Unsign, &UnsignIxData { bump: self.bump })
+    }
+}
+
+/// Reject Session
+///
+/// ### accounts:
+///   0. `[WRITE, SIGNER]` signer; operator with vote permission
+///   1. `[READ]`  root pda
+///   2. `[WRITE]` signing session pda
+pub struct Reject<'a> {
+    /// operator rejecting the session.
+    pub signer: &'a AccountView,
+
+    /// root the session belongs to.
+    pub root: &'a AccountView,
+
+    /// signing session pda.
+    pub signing_session: &'a AccountView,
+
+    /// bump of the signing session pda.
+    pub bump: u8,
+}
+
+impl Reject<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let instruction_accounts = self.instruction_accounts();
+        let data = self.data()?;
+
+        let instruction = InstructionView {
+            program_id: &Address::new_from_array(ID),
+            accounts: &instruction_accounts,
+            data: &data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.signer, self.root, self.signing_session],
+            signers,
+        )
+    }
+
+    /// account metas in instruction order
+    pub fn instruction_accounts(&self) -> [InstructionAccount<'_>; 3] {
+        [
+            InstructionAccount::writable_signer(self.signer.address()),
+            InstructionAccount::readonly(self.root.address()),
+            InstructionAccount::writable(self.signing_session.address()),
+        ]
+    }
+
+    /// opcode followed by borsh serialized instruction data
+    pub fn data(&self) -> Result<Vec<u8>, ProgramError> {
+        instruction_data(Instruction::Reject, &RejectIxData { bump: self.bump })
+    }
+}
+
+/// Cancel Session
+///
+/// ### accounts:
+///   0. `[WRITE, SIGNER]` proposer of the session
+///   1. `[READ]`  root pda
+///   2. `[WRITE]` signing session pda
+pub struct Cancel<'a> {
+    /// proposer cancelling the session.
+    pub proposer: &'a AccountView,
+
+    /// root the session belongs to.
+    pub root: &'a AccountView,
+
+    /// signing session pda.
+    pub signing_session: &'a AccountView,
+
+    /// bump of the signing session pda.
+    pub bump: u8,
+}
+
+impl Cancel<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let instruction_accounts = self.instruction_accounts();
+        let data = self.data()?;
+
+        let instruction = InstructionView {
+            program_id: &Address::new_from_array(ID),
+            accounts: &instruction_accounts,
+            data: &data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[self.proposer, self.root, self.signing_session],
+            signers,
+        )
+    }
+
+    /// account metas in instruction order
+    pub fn instruction_accounts(&self) -> [InstructionAccount<'_>; 3] {
+        [
+            InstructionAccount::writable_signer(self.proposer.address()),
+            InstructionAccount::readonly(self.root.address()),
+            InstructionAccount::writable(self.signing_session.address()),
+        ]
+    }
+
+    /// opcode followed by borsh serialized instruction data
+    pub fn data(&self) -> Result<Vec<u8>, ProgramError> {
+        instruction_data(Instruction::Cancel, &CancelIxData { bump: self.bump })
+    }
+}
+
+/// Execute Session
+///
+/// ### accounts:
+///   0. `[WRITE, SIGNER]` executor allowed by root execute policy
+///   1. `[READ]`  root pda; `[WRITE]` for config proposals
+///   2. `[WRITE]` signing session pda
+///   3. `[READ]`  system program
+///   4. `[READ]`  allowed program targeted by session
+///   5. `[..]`    accounts of session instructions (remaining)
+pub struct Execute<'a> {
+    /// executor paying for the execution.
+    pub executor: &'a AccountView,
+
+    /// root the session belongs to; passed writable for config proposals.
+    pub root: &'a AccountView,
+
+    /// signing session pda.
+    pub signing_session: &'a AccountView,
+
+    /// system program.
+    pub system_program: &'a AccountView,
+
+    /// allowed program targeted by the session.
+    pub program: &'a AccountView,
+
+    /// accounts of session instructions; metas follow the views writable and signer flags.
+    pub remaining: &'a [&'a AccountView],
+}
+
+impl Execute<'_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let instruction_accounts = self.instruction_accounts();
+        let data = self.data()?;
+
+        let instruction = InstructionView {
+            program_id: &Address::new_from_array(ID),
+            accounts: &instruction_accounts,
+            data: &data,
+        };
+
+        let mut account_views = vec![
+            self.executor,
+            self.root,
+            self.signing_session,
+            self.system_program,
+            self.program,
+        ];
+        account_views.extend_from_slice(self.remaining);
+
+        invoke_signed_with_slice(&instruction, &account_views, signers)
+    }
+
+    /// account metas in instruction order
+    pub fn instruction_accounts(&self) -> Vec<InstructionAccount<'_>> {
+        let mut instruction_accounts = vec![
+            InstructionAccount::writable_signer(self.executor.address()),
+            InstructionAccount::new(self.root.address(), self.root.is_writable(), false),
+            InstructionAccount::writable(self.signing_session.address()),
+            InstructionAccount::readonly(self.system_program.address()),
+            InstructionAccount::readonly(self.program.address()),
+        ];
+        instruction_accounts.extend(
+            self.remaining
+                .iter()
+                .map(|account| InstructionAccount::from(*account)),
+        );
+        instruction_accounts
+    }
+
+    /// opcode followed by borsh serialized instruction data
+    pub fn data(&self) -> Result<Vec<u8>, ProgramError> {
+        instruction_data(Instruction::Execute, &ExecuteIxData {})
+    }
+}
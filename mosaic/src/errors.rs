@@ -16,9 +16,46 @@ pub enum MosaicError {
     SigningSessionPhaseAtFinalStage,
     SigningSessionSignerAlreadyApproved,
     SignerIsNotOperator,
-    SigningSessionIdMustEqualRootLastId,
+    SigningSessionIdMustEqualRootLastId, /* unused; kept to preserve error codes */
     ApprovalsDidNotReachThreshold,
     ProvidedDestinationProgramMismatchWithRootDestinationProgram, /* TODO: make test for this case */
+    RootNameTooLong,
+    OperatorAlreadyExists,
+    OperatorNotFound,
+    ThresholdMustNotBeZero,
+    ThresholdMustNotExceedOperators,
+    OperatorsMustBeUnique,
+    SigningSessionSignerAlreadyRejected,
+    SignerIsNotProposer,
+    SigningSessionHasApprovals,
+    SigningSessionNotClosable,
+    RentPayerMismatch,
+    RentPayerAccountMustBeWritable,
+    SigningSessionSignerHasNotApproved,
+    SigningSessionExpired,
+    TimelockNotElapsed,
+    SessionInstructionsMissing,
+    ProgramNotAllowed,
+    AllowedProgramAlreadyExists,
+    AllowedProgramNotFound,
+    AllowedProgramsMustBeUnique,
+    SessionInstructionTooManyAccounts,
+    SessionInstructionsUnexpected,
+    TransferAmountMustNotBeZero,
+    VaultAccountMismatch,
+    RecipientAccountMismatch,
+    TokenProgramNotSupported,
+    TokenAccountMismatch,
+    LoaderAccountMismatch,
+    OperatorWeightMustNotBeZero,
+    OperatorLacksPermission,
+    UnknownOperatorPermissions,
+    ExecutorNotAllowed,
+    ExecutorsMustNotBeEmpty,
+    ExecutorsMustBeUnique,
+    TooManyOperators,
+    MosaicProgramNotAllowed,
+    DefaultTtlMustBePositive,
 }
 
 impl std::fmt::Display for MosaicError {
@@ -82,6 +119,126 @@ impl std::fmt::Display for MosaicError {
                     "root pda destination program should match provided program id for cpi"
                 )
             }
+            MosaicError::RootNameTooLong => {
+                write!(f, "root name must not exceed max seed length")
+            }
+            MosaicError::OperatorAlreadyExists => {
+                write!(f, "operator is already registered in root")
+            }
+            MosaicError::OperatorNotFound => {
+                write!(f, "operator is not registered in root")
+            }
+            MosaicError::ThresholdMustNotBeZero => {
+                write!(f, "threshold must be greater than zero")
+            }
+            MosaicError::ThresholdMustNotExceedOperators => {
+                write!(f, "threshold must not exceed summed operator weight")
+            }
+            MosaicError::OperatorsMustBeUnique => {
+                write!(f, "operator keys must be unique")
+            }
+            MosaicError::SigningSessionSignerAlreadyRejected => {
+                write!(f, "signer already casted rejection for the session")
+            }
+            MosaicError::SignerIsNotProposer => {
+                write!(f, "signer isn't the proposer of the session")
+            }
+            MosaicError::SigningSessionHasApprovals => {
+                write!(f, "session already received approvals")
+            }
+            MosaicError::SigningSessionNotClosable => {
+                write!(
+                    f,
+                    "only executed, rejected or cancelled sessions can be closed"
+                )
+            }
+            MosaicError::RentPayerMismatch => {
+                write!(f, "provided account didn't pay rent for the session")
+            }
+            MosaicError::RentPayerAccountMustBeWritable => {
+                write!(f, "rent payer account must be writable")
+            }
+            MosaicError::SigningSessionSignerHasNotApproved => {
+                write!(f, "signer has no approval to revoke in the session")
+            }
+            MosaicError::SigningSessionExpired => {
+                write!(f, "signing session expired")
+            }
+            MosaicError::TimelockNotElapsed => {
+                write!(f, "timelock since session approval hasn't elapsed yet")
+            }
+            MosaicError::SessionInstructionsMissing => {
+                write!(f, "cpi proposal must carry at least one instruction")
+            }
+            MosaicError::ProgramNotAllowed => {
+                write!(f, "program isn't among allowed programs of the root")
+            }
+            MosaicError::AllowedProgramAlreadyExists => {
+                write!(f, "program is already allowed")
+            }
+            MosaicError::AllowedProgramNotFound => {
+                write!(f, "program isn't among allowed programs")
+            }
+            MosaicError::AllowedProgramsMustBeUnique => {
+                write!(f, "allowed programs must be unique")
+            }
+            MosaicError::SessionInstructionTooManyAccounts => {
+                write!(f, "session instruction exceeds cpi account limit")
+            }
+            MosaicError::SessionInstructionsUnexpected => {
+                write!(f, "only cpi proposal can carry instructions")
+            }
+            MosaicError::TransferAmountMustNotBeZero => {
+                write!(f, "transfer amount must not be zero")
+            }
+            MosaicError::VaultAccountMismatch => {
+                write!(f, "provided account isn't the vault of the proposal")
+            }
+            MosaicError::RecipientAccountMismatch => {
+                write!(f, "provided account isn't the recipient of the proposal")
+            }
+            MosaicError::TokenProgramNotSupported => {
+                write!(f, "only token and token-2022 programs are supported")
+            }
+            MosaicError::LoaderAccountMismatch => {
+                write!(
+                    f,
+                    "provided loader or its accounts don't match the proposal"
+                )
+            }
+            MosaicError::TokenAccountMismatch => {
+                write!(
+                    f,
+                    "provided token program, mint or token accounts don't match the proposal"
+                )
+            }
+            MosaicError::OperatorWeightMustNotBeZero => {
+                write!(f, "operator weight must be greater than zero")
+            }
+            MosaicError::OperatorLacksPermission => {
+                write!(f, "operator wasn't granted permission for this instruction")
+            }
+            MosaicError::UnknownOperatorPermissions => {
+                write!(f, "operator permissions contain unknown bits")
+            }
+            MosaicError::ExecutorNotAllowed => {
+                write!(f, "signer isn't among designated executors")
+            }
+            MosaicError::ExecutorsMustNotBeEmpty => {
+                write!(f, "designated executors must not be empty")
+            }
+            MosaicError::ExecutorsMustBeUnique => {
+                write!(f, "designated executor keys must be unique")
+            }
+            MosaicError::TooManyOperators => {
+                write!(f, "root can't hold more than 64 operator slots")
+            }
+            MosaicError::MosaicProgramNotAllowed => {
+                write!(f, "mosaic program can't be allowed as session target")
+            }
+            MosaicError::DefaultTtlMustBePositive => {
+                write!(f, "default session ttl must be greater than zero")
+            }
         }
     }
 }
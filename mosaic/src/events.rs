@@ -0,0 +1,121 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{Address, ProgramResult, error::ProgramError};
+use solana_program::log::sol_log_data;
+
+use crate::state::{
+    expiry::Expiry,
+    proposal::{ConfigChange, Proposal},
+    root::Operator,
+};
+
+/// first log data field marking mosaic events among other program data
+pub const EVENT_TAG: &[u8] = b"mosaic_event";
+
+/// version of event encoding; bumped on breaking changes of `Event`
+pub const EVENT_VERSION: u8 = 1;
+
+/// state transitions emitted as `Program data: <tag> <version> <borsh event>`
+#[derive(Clone, BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub enum Event {
+    /// root created by its creator
+    RootInitialized {
+        root: Address,
+        creator: Address,
+        operators: Vec<Operator>,
+        threshold: u16,
+    },
+
+    /// signing session created by proposer
+    SessionCreated {
+        root: Address,
+        session_id: u16,
+        proposer: Address,
+        proposal: Proposal,
+        expires_at: Option<Expiry>,
+    },
+
+    /// operator approved session; weight sums approvals of current voters
+    SessionSigned {
+        root: Address,
+        session_id: u16,
+        signer: Address,
+        approval_weight: u32,
+    },
+
+    /// approvals reached root threshold
+    SessionApproved {
+        root: Address,
+        session_id: u16,
+        approved_at: i64,
+    },
+
+    /// approved session carried out
+    SessionExecuted {
+        root: Address,
+        session_id: u16,
+        executor: Address,
+    },
+
+    /// rejections made root threshold unreachable; weight sums rejections of current voters
+    SessionRejected {
+        root: Address,
+        session_id: u16,
+        signer: Address,
+        rejection_weight: u32,
+    },
+
+    /// proposer withdrew session before any approval
+    SessionCancelled {
+        root: Address,
+        session_id: u16,
+        proposer: Address,
+    },
+
+    /// revoked approval dropped approved session back to active
+    SessionReactivated {
+        root: Address,
+        session_id: u16,
+        signer: Address,
+        approval_weight: u32,
+    },
+
+    /// finished or expired session closed with its lamports refunded
+    SessionClosed {
+        root: Address,
+        session_id: u16,
+        rent_payer: Address,
+        refund: u64,
+    },
+
+    /// config proposal applied to root
+    ConfigChanged {
+        root: Address,
+        session_id: u16,
+        change: ConfigChange,
+    },
+
+    /// operator rejected session; weight sums rejections of current voters
+    SessionRejectVote {
+        root: Address,
+        session_id: u16,
+        signer: Address,
+        rejection_weight: u32,
+    },
+
+    /// operator revoked approval; weight sums approvals of current voters
+    SessionUnsigned {
+        root: Address,
+        session_id: u16,
+        signer: Address,
+        approval_weight: u32,
+    },
+}
+
+impl Event {
+    /// logs borsh encoded event through `sol_log_data`
+    pub fn emit(&self) -> ProgramResult {
+        let data = borsh::to_vec(self).map_err(|_| ProgramError::InvalidAccountData)?;
+        sol_log_data(&[EVENT_TAG, &[EVENT_VERSION], &data]);
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{
+    ID,
+    errors::MosaicError,
+    events::Event,
+    instructions::{root_pda_check, signing_session_pda_check},
+    state::{
+        root::{Operator, RootView},
+        signing_session::SigningSessionHeader,
+        zero_copy::ZeroCopy,
+    },
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{AccountView, Address, ProgramResult, error::ProgramError};
+
+/// Cancel Session
+///
+/// only the proposer may cancel, and only while no operator approved
+///
+/// ### accounts:
+///   0. `[WRITE, SIGNER]` proposer
+///   1. `[READ]`   root pda
+///   2. `[WRITE]`  signing session pda
+pub struct CancelIxAccounts<'info> {
+    pub payer: &'info AccountView,
+    pub root: &'info AccountView,
+    pub signing_session: &'info AccountView,
+}
+
+impl<'info> TryFrom<&'info [AccountView]> for CancelIxAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, root, signing_session] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !root.owned_by(&ID.into()) {
+            return Err(MosaicError::RootAccountIncorrectOwner.into());
+        }
+        if !signing_session.owned_by(&ID.into()) {
+            return Err(MosaicError::SigningSessionAccountIncorrectOwner.into());
+        }
+        if !payer.is_signer() {
+            return Err(MosaicError::PayerMustEqualSigner.into());
+        }
+        if !signing_session.is_writable() {
+            return Err(MosaicError::SigningSessionAccountMustBeWritable.into());
+        }
+        if signing_session.is_data_empty() {
+            return Err(MosaicError::SigningSessionAccountMustBeInitialized.into());
+        }
+
+        Ok(Self {
+            payer,
+            root,
+            signing_session,
+        })
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, BorshDeserialize, BorshSerialize)]
+pub struct CancelIxData {
+    pub bump: u8,
+}
+
+impl<'info> TryFrom<&'info [u8]> for CancelIxData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        borsh::from_slice::<Self>(data).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+pub struct Cancel<'info> {
+    pub accounts: CancelIxAccounts<'info>,
+    pub instruction_data: CancelIxData,
+}
+
+impl<'info> TryFrom<(&'info [AccountView], &'info [u8])> for Cancel<'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'info [AccountView], &'info [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = CancelIxAccounts::try_from(accounts)?;
+        let instruction_data = CancelIxData::try_from(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'info> Cancel<'info> {
+    pub fn handler(&mut self) -> ProgramResult {
+        let root_account = self.accounts.root.try_borrow()?;
+        let root = RootView::from_bytes(&root_account)?;
+
+        let mut signing_account = self.accounts.signing_session.try_borrow_mut()?;
+        let signing = SigningSessionHeader::from_bytes_mut(&mut signing_account)?;
+
+        signing_session_pda_check(
+            self.accounts.signing_session.address(),
+            self.accounts.root.address().as_array(),
+            signing.session_id,
+            &[self.instruction_data.bump],
+        )?;
+
+        root_pda_check(
+            self.accounts.root.address(),
+            root.header.creator.as_ref(),
+            root.name(),
+            &[root.header.bump],
+        )?;
+        signing.sync_votes(&root)?;
+        Self::mandatory_account_data_checks(
+            signing,
+            root.operators,
+            self.accounts.payer.address(),
+        )?;
+
+        signing.cancel();
+
+        Event::SessionCancelled {
+            root: *self.accounts.root.address(),
+            session_id: signing.session_id,
+            proposer: *self.accounts.payer.address(),
+        }
+        .emit()
+    }
+
+    fn mandatory_account_data_checks(
+        signing: &SigningSessionHeader,
+        operators: &[Operator],
+        signer: &Address,
+    ) -> Result<(), ProgramError> {
+        signing.must_be_active()?;
+        signing.signer_must_be_proposer(signer)?;
+        signing.must_have_no_approvals(operators)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{
+    ID,
+    errors::MosaicError,
+    events::Event,
+    instructions::{root_pda_check, signing_session_pda_check},
+    state::{root::RootView, signing_session::SigningSessionHeader, zero_copy::ZeroCopy},
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{
+    AccountView, ProgramResult,
+    error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+/// Close Session
+///
+/// closes finished or expired session and refunds its lamports to the rent payer
+///
+/// ### accounts:
+///   0. `[SIGNER]` closer
+///   1. `[READ]`   root pda
+///   2. `[WRITE]`  signing session pda
+///   3. `[WRITE]`  rent payer recorded in the session
+pub struct CloseSessionIxAccounts<'info> {
+    pub closer: &'info AccountView,
+    pub root: &'info AccountView,
+    pub signing_session: &'info AccountView,
+    pub rent_payer: &'info AccountView,
+}
+
+impl<'info> TryFrom<&'info [AccountView]> for CloseSessionIxAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountView]) -> Result<Self, Self::Error> {
+        let [closer, root, signing_session, rent_payer] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !root.owned_by(&ID.into()) {
+            return Err(MosaicError::RootAccountIncorrectOwner.into());
+        }
+        if !signing_session.owned_by(&ID.into()) {
+            return Err(MosaicError::SigningSessionAccountIncorrectOwner.into());
+        }
+        if !closer.is_signer() {
+            return Err(MosaicError::PayerMustEqualSigner.into());
+        }
+        if !signing_session.is_writable() {
+            return Err(MosaicError::SigningSessionAccountMustBeWritable.into());
+        }
+        if signing_session.is_data_empty() {
+            return Err(MosaicError::SigningSessionAccountMustBeInitialized.into());
+        }
+        if !rent_payer.is_writable() {
+            return Err(MosaicError::RentPayerAccountMustBeWritable.into());
+        }
+
+        Ok(Self {
+            closer,
+            root,
+            signing_session,
+            rent_payer,
+        })
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, BorshDeserialize, BorshSerialize)]
+pub struct CloseSessionIxData {
+    pub bump: u8,
+}
+
+impl<'info> TryFrom<&'info [u8]> for CloseSessionIxData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        borsh::from_slice::<Self>(data).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+pub struct CloseSession<'info> {
+    pub accounts: CloseSessionIxAccounts<'info>,
+    pub instruction_data: CloseSessionIxData,
+}
+
+impl<'info> TryFrom<(&'info [AccountView], &'info [u8])> for CloseSession<'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'info [AccountView], &'info [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = CloseSessionIxAccounts::try_from(accounts)?;
+        let instruction_data = CloseSessionIxData::try_from(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'info> CloseSession<'info> {
+    pub fn handler(&mut self) -> ProgramResult {
+        let session_id = {
+            let root_account = self.accounts.root.try_borrow()?;
+            let root = RootView::from_bytes(&root_account)?;
+
+            let signing_account = self.accounts.signing_session.try_borrow()?;
+            let signing = SigningSessionHeader::from_bytes(&signing_account)?;
+
+            signing_session_pda_check(
+                self.accounts.signing_session.address(),
+                self.accounts.root.address().as_array(),
+                signing.session_id,
+                &[self.instruction_data.bump],
+            )?;
+
+            root_pda_check(
+                self.accounts.root.address(),
+                root.header.creator.as_ref(),
+                root.name(),
+                &[root.header.bump],
+            )?;
+
+            signing.must_be_closable(&Clock::get()?)?;
+            signing.rent_payer_must_match(self.accounts.rent_payer.address())?;
+
+            signing.session_id
+        };
+
+        let refund = self.accounts.signing_session.lamports();
+        let rent_payer_lamports = self
+            .accounts
+            .rent_payer
+            .lamports()
+            .checked_add(refund)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.accounts.rent_payer.set_lamports(rent_payer_lamports);
+        self.accounts.signing_session.set_lamports(0);
+
+        self.accounts.signing_session.try_borrow_mut()?.fill(0);
+        self.accounts.signing_session.close()?;
+
+        Event::SessionClosed {
+            root: *self.accounts.root.address(),
+            session_id,
+            rent_payer: *self.accounts.rent_payer.address(),
+            refund,
+        }
+        .emit()
+    }
+}
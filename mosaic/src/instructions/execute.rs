@@ -1,30 +1,42 @@
 use crate::{
     ID,
     errors::MosaicError,
-    instructions::{root_pda_check, signing_session_pda_check},
-    invoke_signed_dynamic,
-    seeds::ROOT_PDA,
+    events::Event,
+    instructions::{root_pda_check, signing_session_pda_check, vault_pda_check, write_resized},
+    programs::{BPF_LOADER_UPGRADEABLE_ID, TRANSFER_CHECKED_DISCRIMINATOR},
+    seeds::{ROOT_PDA, VAULT_PDA},
     state::{
-        root::Root,
-        signing_session::{InstructionAccount, SigningSession},
+        proposal::{ConfigChange, LoaderChange, Proposal, TokenTransfer},
+        root::{Root, RootHeader, RootView},
+        signing_session::{
+            InstructionAccount, SessionInstruction, SessionPayload, SigningSessionHeader,
+        },
+        zero_copy::ZeroCopy,
     },
 };
 use pinocchio::{
     AccountView, Address, ProgramResult,
-    cpi::{Seed, Signer, invoke_signed},
+    cpi::{Seed, Signer, invoke_signed, invoke_signed_with_slice},
     error::ProgramError,
     instruction::{InstructionAccount as PinocchioInstructionAccount, InstructionView},
+    sysvars::{Sysvar, clock::Clock},
 };
 
 /// Execute Instruction
 ///
 /// ### accounts:
-///   0. `[WRITE, SIGNER]` payer
-///   1. `[READ]`   root pda
+///   0. `[WRITE, SIGNER]` payer; executor allowed by root execute policy
+///   1. `[READ]`   root pda; `[WRITE]` for config proposals
 ///   2. `[WRITE]`  signing pda
 ///   3. `[READ]`   system program
-///   4. `[READ]`   destination program
-///   [..]          CPI accounts
+///   4. `[READ]`   allowed program targeted by session; unused by proposals other than cpi
+///   5. `[..]`     CPI accounts, session vault and other targeted programs of session instructions (remaining)
+///
+/// transfer proposals expect `[WRITE]` vault pda followed by `[WRITE]` recipient as remaining;
+/// token transfer proposals expect `[WRITE]` source, `[READ]` mint, `[WRITE]` destination,
+/// `[READ]` token program and vault pda when signed by vault;
+/// loader proposals expect loader instruction accounts without authority, `[READ]` upgradeable
+/// loader and vault pda when signed by vault
 pub struct ExecuteIxAccounts<'info> {
     pub payer: &'info AccountView,
     pub root: &'info AccountView,
@@ -38,7 +50,9 @@ impl<'info> TryFrom<&'info [AccountView]> for ExecuteIxAccounts<'info> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'info [AccountView]) -> Result<Self, Self::Error> {
-        let (required_accounts, remaining) = accounts.split_at(5);
+        let (required_accounts, remaining) = accounts
+            .split_at_checked(5)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
         let [payer, root, signing_session, _sys_program, _dst_program] = required_accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
@@ -77,7 +91,7 @@ impl<'info> TryFrom<&'info [u8]> for ExecuteIxData {
     type Error = ProgramError;
 
     fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
-        Ok(borsh::from_slice::<Self>(&data).map_err(|_| ProgramError::InvalidInstructionData)?)
+        borsh::from_slice::<Self>(data).map_err(|_| ProgramError::InvalidInstructionData)
     }
 }
 
@@ -104,43 +118,126 @@ impl<'info> TryFrom<(&'info [AccountView], &'info [u8])> for Execute<'info> {
 
 impl<'info> Execute<'info> {
     pub fn handler(&mut self) -> ProgramResult {
-        let root_account = self.accounts.root.try_borrow()?;
-        let root_data = Root::deserialize(&root_account)?;
-        let root_pda = Address::create_program_address(&[ROOT_PDA, &[root_data.bump]], &ID.into())
-            .map_err(|_| ProgramError::InvalidSeeds)?;
-
-        let root_bump_seed = [root_data.bump];
-        let root_seed = [Seed::from(ROOT_PDA), Seed::from(&root_bump_seed)];
-        let cpi_signer = Signer::from(&root_seed);
-
-        let signing_data = {
-            let signing_account = self.accounts.signing_session.try_borrow()?;
-            SigningSession::deserialize(&signing_account)?
+        // checks run against borrowed views; borrows end before any cpi touches the accounts
+        let (root_header, signing_header, payload) = {
+            let root_account = self.accounts.root.try_borrow()?;
+            let root = RootView::from_bytes(&root_account)?;
+
+            let mut signing_account = self.accounts.signing_session.try_borrow_mut()?;
+            let signing = SigningSessionHeader::from_bytes_mut(&mut signing_account)?;
+
+            root_pda_check(
+                self.accounts.root.address(),
+                root.header.creator.as_ref(),
+                root.name(),
+                &[root.header.bump],
+            )?;
+            signing_session_pda_check(
+                self.accounts.signing_session.address(),
+                self.accounts.root.address().as_array(),
+                signing.session_id,
+                &[signing.bump],
+            )?;
+            signing.sync_votes(&root)?;
+            Self::mandatory_account_data_checks(
+                signing,
+                &root,
+                self.accounts.payer.address(),
+                &Clock::get()?,
+            )?;
+            // session is executed before any cpi, so re-entering mosaic can't execute it again
+            signing.progress_phase_checked()?;
+            let signing_header = *signing;
+
+            let payload = SessionPayload::from_bytes(&signing_account)?;
+            if let Proposal::Cpi = payload.proposal {
+                root.program_must_be_allowed(self.accounts._dst_program.address())?;
+            }
+            for session_instruction in &payload.instructions {
+                root.program_must_be_allowed(&session_instruction.program_id)?;
+            }
+
+            (*root.header, signing_header, payload)
         };
 
-        root_pda_check(&self.accounts.root.address(), &[root_data.bump])?;
-        signing_session_pda_check(
-            &self.accounts.signing_session.address(),
-            self.accounts.root.address().as_array(),
-            root_data.last_id,
-            &[signing_data.bump],
-        )?;
-        Self::mandatory_account_data_checks(
-            &signing_data,
-            &root_data,
-            self.accounts._dst_program.address(),
-        )?;
+        match &payload.proposal {
+            Proposal::Cpi => self.execute_cpi(
+                &root_header,
+                signing_header.vault_index,
+                signing_header.vault_bump,
+                &payload.instructions,
+            )?,
+            Proposal::Config(change) => {
+                self.execute_config_change(signing_header.session_id, change)?
+            }
+            Proposal::Transfer {
+                recipient,
+                amount,
+                vault_index,
+            } => {
+                self.execute_transfer(recipient, *amount, *vault_index, signing_header.vault_bump)?
+            }
+            Proposal::TokenTransfer(transfer) => {
+                self.execute_token_transfer(&root_header, transfer, signing_header.vault_bump)?
+            }
+            Proposal::Loader {
+                change,
+                vault_index,
+            } => self.execute_loader_change(
+                &root_header,
+                change,
+                *vault_index,
+                signing_header.vault_bump,
+            )?,
+        }
+
+        Event::SessionExecuted {
+            root: *self.accounts.root.address(),
+            session_id: signing_header.session_id,
+            executor: *self.accounts.payer.address(),
+        }
+        .emit()
+    }
+
+    /// cpi into allowed programs with every session instruction in order, signed by root and vault pdas
+    fn execute_cpi(
+        &self,
+        root_header: &RootHeader,
+        vault_index: u8,
+        vault_bump: u8,
+        instructions: &[SessionInstruction],
+    ) -> ProgramResult {
+        let root_pda = self.accounts.root.address();
+
+        with_root_signer(root_header, |root_signer| {
+            with_vault_signer(root_pda, vault_index, vault_bump, |vault_signer| {
+                let cpi_signers = [root_signer, vault_signer];
+                for session_instruction in instructions {
+                    self.invoke_session_instruction(session_instruction, &cpi_signers)?;
+                }
+                Ok(())
+            })
+        })
+    }
+
+    /// cpi with single session instruction; any failure aborts the whole execution
+    fn invoke_session_instruction(
+        &self,
+        session_instruction: &SessionInstruction,
+        cpi_signers: &[Signer],
+    ) -> ProgramResult {
+        let root_pda = self.accounts.root.address();
 
         // dynamic metas; allows for mapping accounts stored in signing session account
         let mut instruction_accounts: Vec<PinocchioInstructionAccount> = vec![];
         let mut addresses = vec![];
 
-        for instruction_account_data in &signing_data.instruction_accounts {
+        for instruction_account_data in &session_instruction.accounts {
             let ix_acc = InstructionAccount::deserialize(instruction_account_data)?;
             addresses.push(Address::new_from_array(ix_acc.pubkey));
         }
 
-        for (i, instruction_account_data) in signing_data.instruction_accounts.iter().enumerate() {
+        for (i, instruction_account_data) in session_instruction.accounts.iter().enumerate() {
             let ix_acc = InstructionAccount::deserialize(instruction_account_data)?;
             let pinocchio_acc = match (ix_acc.signer, ix_acc.writable) {
                 (true, true) => PinocchioInstructionAccount::writable_signer(&addresses[i]),
@@ -166,34 +263,251 @@ impl<'info> Execute<'info> {
             }
         }
 
-        // cpi to destination program
+        // cpi to targeted program
         let instruction = InstructionView {
-            program_id: self.accounts._dst_program.address(),
+            program_id: &session_instruction.program_id,
             accounts: &instruction_accounts,
-            data: &signing_data.instruction_data.clone(),
+            data: &session_instruction.data,
         };
-        invoke_signed_dynamic!(&instruction, account_views, &[cpi_signer])?;
+        invoke_signed_with_slice(&instruction, &account_views, cpi_signers)
+    }
+
+    /// applies config change to root; reallocates root and tops up rent from payer
+    fn execute_config_change(&self, session_id: u16, change: &ConfigChange) -> ProgramResult {
+        if !self.accounts.root.is_writable() {
+            return Err(MosaicError::RootAccountMustBeWrittable.into());
+        }
 
-        // update signing session / prevent re-execution
-        let mut signing_data = signing_data;
-        signing_data.progress_phase_checked()?; /* set signing session phase to executed */
+        let mut root_data = Root::deserialize(&self.accounts.root.try_borrow()?)?;
+        root_data.apply_config_change(change)?;
 
-        let (serialized_data, serialized_len) = signing_data.serialize()?;
-        let mut signing_account = self.accounts.signing_session.try_borrow_mut()?;
-        signing_account[..serialized_len].copy_from_slice(&serialized_data);
+        let (root_data, _) = root_data.serialize()?;
+        write_resized(self.accounts.root, self.accounts.payer, &root_data)?;
 
-        Ok(())
+        Event::ConfigChanged {
+            root: *self.accounts.root.address(),
+            session_id,
+            change: change.clone(),
+        }
+        .emit()
+    }
+
+    /// moves lamports from proposal vault to recipient, signed by vault pda
+    fn execute_transfer(
+        &self,
+        recipient: &Address,
+        amount: u64,
+        vault_index: u8,
+        vault_bump: u8,
+    ) -> ProgramResult {
+        let [vault, recipient_account, ..] = self.accounts.remaining else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let root_pda = self.accounts.root.address();
+        vault_pda_check(
+            vault.address(),
+            root_pda.as_ref(),
+            vault_index,
+            &[vault_bump],
+        )?;
+        if recipient_account.address() != recipient {
+            return Err(MosaicError::RecipientAccountMismatch.into());
+        }
+
+        with_vault_signer(root_pda, vault_index, vault_bump, |vault_signer| {
+            pinocchio_system::instructions::Transfer {
+                from: vault,
+                to: recipient_account,
+                lamports: amount,
+            }
+            .invoke_signed(&[vault_signer])
+        })
+    }
+
+    /// moves tokens with `transfer_checked`, signed by proposal vault or root pda
+    fn execute_token_transfer(
+        &self,
+        root_header: &RootHeader,
+        transfer: &TokenTransfer,
+        vault_bump: u8,
+    ) -> ProgramResult {
+        let [source, mint, destination, token_program, remaining @ ..] = self.accounts.remaining
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        if source.address() != &transfer.source
+            || mint.address() != &transfer.mint
+            || destination.address() != &transfer.destination
+            || token_program.address() != &transfer.token_program
+        {
+            return Err(MosaicError::TokenAccountMismatch.into());
+        }
+
+        let mut data = [0u8; 10];
+        data[0] = TRANSFER_CHECKED_DISCRIMINATOR;
+        data[1..9].copy_from_slice(&transfer.amount.to_le_bytes());
+        data[9] = transfer.decimals;
+
+        let transfer_checked = |authority: &AccountView, signer: Signer| {
+            let instruction_accounts = [
+                PinocchioInstructionAccount::writable(source.address()),
+                PinocchioInstructionAccount::readonly(mint.address()),
+                PinocchioInstructionAccount::writable(destination.address()),
+                PinocchioInstructionAccount::readonly_signer(authority.address()),
+            ];
+            let instruction = InstructionView {
+                program_id: &transfer.token_program,
+                accounts: &instruction_accounts,
+                data: &data,
+            };
+            invoke_signed(
+                &instruction,
+                &[source, mint, destination, authority],
+                &[signer],
+            )
+        };
+
+        match transfer.vault_index {
+            None => with_root_signer(root_header, |root_signer| {
+                transfer_checked(self.accounts.root, root_signer)
+            }),
+            Some(vault_index) => {
+                let [vault, ..] = remaining else {
+                    return Err(ProgramError::NotEnoughAccountKeys);
+                };
+                let root_pda = self.accounts.root.address();
+                vault_pda_check(
+                    vault.address(),
+                    root_pda.as_ref(),
+                    vault_index,
+                    &[vault_bump],
+                )?;
+                with_vault_signer(root_pda, vault_index, vault_bump, |vault_signer| {
+                    transfer_checked(vault, vault_signer)
+                })
+            }
+        }
+    }
+
+    /// cpi into upgradeable loader, signed by proposal vault or root pda as authority
+    fn execute_loader_change(
+        &self,
+        root_header: &RootHeader,
+        change: &LoaderChange,
+        vault_index: Option<u8>,
+        vault_bump: u8,
+    ) -> ProgramResult {
+        let loader_accounts = change.accounts();
+        let (change_accounts, remaining) = self
+            .accounts
+            .remaining
+            .split_at_checked(loader_accounts.len())
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let [loader, remaining @ ..] = remaining else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        if loader.address() != &BPF_LOADER_UPGRADEABLE_ID
+            || change_accounts
+                .iter()
+                .zip(&loader_accounts)
+                .any(|(account, (address, _))| account.address() != address)
+        {
+            return Err(MosaicError::LoaderAccountMismatch.into());
+        }
+
+        let data = change.instruction_data();
+        let invoke_loader = |authority: &AccountView, signer: Signer| {
+            let mut instruction_accounts: Vec<PinocchioInstructionAccount> = loader_accounts
+                .iter()
+                .map(|(address, writable)| match writable {
+                    true => PinocchioInstructionAccount::writable(address),
+                    false => PinocchioInstructionAccount::readonly(address),
+                })
+                .collect();
+            let mut account_views: Vec<&AccountView> = change_accounts.iter().collect();
+
+            let position = change.authority_position();
+            instruction_accounts.insert(
+                position,
+                PinocchioInstructionAccount::readonly_signer(authority.address()),
+            );
+            account_views.insert(position, authority);
+
+            let instruction = InstructionView {
+                program_id: &BPF_LOADER_UPGRADEABLE_ID,
+                accounts: &instruction_accounts,
+                data: &data,
+            };
+            invoke_signed_with_slice(&instruction, &account_views, &[signer])
+        };
+
+        match vault_index {
+            None => with_root_signer(root_header, |root_signer| {
+                invoke_loader(self.accounts.root, root_signer)
+            }),
+            Some(vault_index) => {
+                let [vault, ..] = remaining else {
+                    return Err(ProgramError::NotEnoughAccountKeys);
+                };
+                let root_pda = self.accounts.root.address();
+                vault_pda_check(
+                    vault.address(),
+                    root_pda.as_ref(),
+                    vault_index,
+                    &[vault_bump],
+                )?;
+                with_vault_signer(root_pda, vault_index, vault_bump, |vault_signer| {
+                    invoke_loader(vault, vault_signer)
+                })
+            }
+        }
     }
 
-    #[must_use]
     fn mandatory_account_data_checks(
-        signing_session: &SigningSession,
-        root: &Root,
-        ix_provided_destination_program: &Address,
+        signing_session: &SigningSessionHeader,
+        root: &RootView,
+        executor: &Address,
+        clock: &Clock,
     ) -> Result<(), ProgramError> {
-        signing_session.sessions_must_equal(root.last_id)?;
+        root.executor_must_be_allowed(executor)?;
         signing_session.must_be_approved()?;
-        root.destination_program_address_must_match(ix_provided_destination_program)?;
+        signing_session.must_not_be_expired(clock)?;
+        signing_session.timelock_must_elapse(root.header.timelock, clock)?;
+        signing_session.approvals_must_reach_threshold(root.operators, root.header.threshold)?;
         Ok(())
     }
 }
+
+/// runs `f` with root pda as cpi signer
+fn with_root_signer(
+    root_header: &RootHeader,
+    f: impl FnOnce(Signer) -> ProgramResult,
+) -> ProgramResult {
+    let root_bump_seed = [root_header.bump];
+    let root_seed = [
+        Seed::from(ROOT_PDA),
+        Seed::from(root_header.creator.as_ref()),
+        Seed::from(root_header.name()),
+        Seed::from(&root_bump_seed),
+    ];
+    f(Signer::from(&root_seed))
+}
+
+/// runs `f` with root vault at given index as cpi signer
+fn with_vault_signer(
+    root_pda: &Address,
+    vault_index: u8,
+    vault_bump: u8,
+    f: impl FnOnce(Signer) -> ProgramResult,
+) -> ProgramResult {
+    let vault_index_seed = [vault_index];
+    let vault_bump_seed = [vault_bump];
+    let vault_seed = [
+        Seed::from(VAULT_PDA),
+        Seed::from(root_pda.as_ref()),
+        Seed::from(&vault_index_seed),
+        Seed::from(&vault_bump_seed),
+    ];
+    f(Signer::from(&vault_seed))
+}
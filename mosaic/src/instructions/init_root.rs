@@ -1,5 +1,13 @@
 use crate::{
-    ID, errors::MosaicError, instructions::root_pda_check, seeds::ROOT_PDA, state::root::Root,
+    ID,
+    errors::MosaicError,
+    events::Event,
+    instructions::root_pda_check,
+    seeds::ROOT_PDA,
+    state::{
+        expiry::Expiry,
+        root::{ExecutePolicy, Operator, Root},
+    },
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use pinocchio::{
@@ -12,8 +20,9 @@ use pinocchio::{
 /// Initialize Operators
 ///
 /// ### accounts:
-///   0. `[WRITE, SIGNER]`
+///   0. `[WRITE, SIGNER]` payer / root creator
 ///   1. `[WRITE]` root pda
+///   2. `[READ]`  system program
 pub struct InitializeRootIxAccounts<'info> {
     pub payer: &'info AccountView,
     pub root: &'info AccountView,
@@ -23,7 +32,7 @@ impl<'info> TryFrom<&'info [AccountView]> for InitializeRootIxAccounts<'info> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'info [AccountView]) -> Result<Self, Self::Error> {
-        let [payer, root, _system] = accounts else {
+        let [payer, root, _system_program] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
@@ -43,9 +52,13 @@ impl<'info> TryFrom<&'info [AccountView]> for InitializeRootIxAccounts<'info> {
 
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug, Clone)]
 pub struct InitializeRootIxData {
-    pub operators: Vec<Address>,
-    pub threshold: u8,
-    pub destination_program: Address,
+    pub name: String,
+    pub operators: Vec<Operator>,
+    pub threshold: u16,
+    pub allowed_programs: Vec<Address>,
+    pub default_ttl: Option<Expiry>,
+    pub timelock: u32,
+    pub execute_policy: ExecutePolicy,
     pub bump: u8,
 }
 
@@ -53,7 +66,7 @@ impl<'info> TryFrom<&'info [u8]> for InitializeRootIxData {
     type Error = ProgramError;
 
     fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
-        Ok(borsh::from_slice::<Self>(&data).map_err(|_| ProgramError::InvalidInstructionData)?)
+        borsh::from_slice::<Self>(data).map_err(|_| ProgramError::InvalidInstructionData)
     }
 }
 
@@ -80,13 +93,37 @@ impl<'info> TryFrom<(&'info [AccountView], &'info [u8])> for InitializeOperators
 
 impl<'info> InitializeOperators<'info> {
     pub fn handler(&mut self) -> ProgramResult {
-        root_pda_check(&self.accounts.root.address(), &[self.instruction_data.bump])?;
+        Root::name_must_fit_seed(&self.instruction_data.name)?;
+        root_pda_check(
+            self.accounts.root.address(),
+            self.accounts.payer.address().as_ref(),
+            self.instruction_data.name.as_bytes(),
+            &[self.instruction_data.bump],
+        )?;
 
         let root_ix_data_bump = [self.instruction_data.bump];
-        let root_seeds = [Seed::from(ROOT_PDA), Seed::from(&root_ix_data_bump)];
+        let root_seeds = [
+            Seed::from(ROOT_PDA),
+            Seed::from(self.accounts.payer.address().as_ref()),
+            Seed::from(self.instruction_data.name.as_bytes()),
+            Seed::from(&root_ix_data_bump),
+        ];
         let cpi_signer = Signer::from(&root_seeds);
 
-        let (root_data, root_data_len) = Root::init(self.instruction_data.clone()).serialize()?;
+        let root_data = Root::init(self.instruction_data.clone(), self.accounts.payer.address());
+        root_data
+            .operators
+            .iter()
+            .try_for_each(Root::operator_must_not_be_removed)?;
+        root_data.validate()?;
+
+        let event = Event::RootInitialized {
+            root: *self.accounts.root.address(),
+            creator: root_data.creator,
+            operators: root_data.operators.clone(),
+            threshold: root_data.threshold,
+        };
+        let (root_data, root_data_len) = root_data.serialize()?;
 
         // create account
         pinocchio_system::instructions::CreateAccount {
@@ -102,6 +139,6 @@ impl<'info> InitializeOperators<'info> {
         let mut root_account = self.accounts.root.try_borrow_mut()?;
         root_account[..root_data.len()].copy_from_slice(&root_data);
 
-        Ok(())
+        event.emit()
     }
 }
@@ -1,24 +1,32 @@
 use crate::{
     ID,
     errors::MosaicError,
-    instructions::{root_pda_check, signing_session_pda_check},
+    events::Event,
+    instructions::{find_vault_pda, root_pda_check, signing_session_pda_check},
     seeds::SIGNING_SESSION_PDA,
-    state::{root::Root, signing_session::SigningSession},
+    state::{
+        expiry::Expiry,
+        proposal::Proposal,
+        root::{Permission, RootHeader, RootView},
+        signing_session::{SessionInstruction, SigningSession},
+        zero_copy::ZeroCopy,
+    },
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use pinocchio::{
     AccountView, Address, ProgramResult,
     cpi::{Seed, Signer},
     error::ProgramError,
-    sysvars::{Sysvar, rent::Rent},
+    sysvars::{Sysvar, clock::Clock, rent::Rent},
 };
 
 /// Initialize Signing Session
 ///
 /// ### accounts:
-///   0. `[WRITE, SIGNER]`
+///   0. `[WRITE, SIGNER]` proposer
 ///   1. `[WRITE]`  root pda
 ///   2. `[WRITE]`  signing session pda
+///   3. `[READ]`   system program
 pub struct InitializeSigningSessionIxAccounts<'info> {
     pub payer: &'info AccountView,
     pub root: &'info AccountView,
@@ -62,8 +70,10 @@ impl<'info> TryFrom<&'info [AccountView]> for InitializeSigningSessionIxAccounts
 
 #[derive(Clone, BorshSerialize, BorshDeserialize)]
 pub struct InitializeSigningSessionIxData {
-    pub instruction_data: Vec<u8>,
-    pub instruction_accounts: Vec<Vec<u8>>,
+    pub proposal: Proposal,
+    pub instructions: Vec<SessionInstruction>,
+    pub vault_index: u8,
+    pub expires_at: Option<Expiry>,
     pub bump: u8,
 }
 
@@ -71,7 +81,7 @@ impl<'info> TryFrom<&'info [u8]> for InitializeSigningSessionIxData {
     type Error = ProgramError;
 
     fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
-        Ok(borsh::from_slice::<Self>(&data).map_err(|_| ProgramError::InvalidInstructionData)?)
+        borsh::from_slice::<Self>(data).map_err(|_| ProgramError::InvalidInstructionData)
     }
 }
 
@@ -99,21 +109,28 @@ impl<'info> TryFrom<(&'info [AccountView], &'info [u8])> for InitializeSigningSe
 impl<'info> InitializeSigningSession<'info> {
     pub fn handler(&mut self) -> ProgramResult {
         let mut root_account = self.accounts.root.try_borrow_mut()?;
-        let mut root_data = Root::deserialize(&root_account)?;
+        let root = RootView::from_bytes(&root_account)?;
 
-        root_data.increment_last_id()?;
+        let session_id = root.header.next_session_id()?;
 
         signing_session_pda_check(
-            &self.accounts.signing_session.address(),
+            self.accounts.signing_session.address(),
             self.accounts.root.address().as_array(),
-            root_data.last_id,
+            session_id,
             &[self.instruction_data.bump],
         )?;
 
-        root_pda_check(&self.accounts.root.address(), &[root_data.bump])?;
-        Self::mandatory_account_data_checks(&root_data, self.accounts.payer.address())?;
+        root_pda_check(
+            self.accounts.root.address(),
+            root.header.creator.as_ref(),
+            root.name(),
+            &[root.header.bump],
+        )?;
+        Self::mandatory_account_data_checks(&root, self.accounts.payer.address())?;
 
-        let derivation_new_last_session = &root_data.last_id.to_be_bytes();
+        let clock = Clock::get()?;
+
+        let derivation_new_last_session = &session_id.to_be_bytes();
 
         let signing_session_ix_data_bump = [self.instruction_data.bump];
         let signing_session_seeds = [
@@ -124,12 +141,36 @@ impl<'info> InitializeSigningSession<'info> {
         ];
         let cpi_signer = Signer::from(&signing_session_seeds);
 
-        let (signing_session_data, signing_session_data_len) = SigningSession::init(
+        let expires_at = root.session_expiry(self.instruction_data.expires_at, &clock)?;
+        let mut signing_session = SigningSession::init(
             self.instruction_data.clone(),
-            root_data.last_id,
+            session_id,
             self.accounts.root.address(),
-        )
-        .serialize()?;
+            self.accounts.payer.address(),
+            expires_at,
+            root.header.operators_epoch,
+        );
+        // canonical vault bump is derived once, so execution can't sign for any other vault address
+        if let Some(vault_index) = signing_session.signing_vault_index() {
+            signing_session.vault_bump =
+                find_vault_pda(self.accounts.root.address(), vault_index).1;
+        }
+        signing_session.header().must_not_be_expired(&clock)?;
+        signing_session.instructions_must_match_proposal()?;
+        signing_session.instruction_accounts_must_fit_cpi()?;
+        signing_session.transfer_amount_must_not_be_zero()?;
+        signing_session.token_program_must_be_supported()?;
+        for session_instruction in &signing_session.instructions {
+            root.program_must_be_allowed(&session_instruction.program_id)?;
+        }
+        let event = Event::SessionCreated {
+            root: *self.accounts.root.address(),
+            session_id: signing_session.session_id,
+            proposer: signing_session.proposer,
+            proposal: signing_session.proposal.clone(),
+            expires_at: signing_session.expires_at,
+        };
+        let (signing_session_data, signing_session_data_len) = signing_session.serialize()?;
 
         // create signing session account
         pinocchio_system::instructions::CreateAccount {
@@ -141,20 +182,21 @@ impl<'info> InitializeSigningSession<'info> {
         }
         .invoke_signed(&[cpi_signer])?;
 
-        // write updated root state
-        let (updated_root_data, updated_root_data_len) = root_data.serialize()?;
-        root_account[..updated_root_data_len].copy_from_slice(&updated_root_data);
+        // write updated last id in place
+        RootHeader::from_bytes_mut(&mut root_account)?.last_id = session_id;
 
         // write to signing session account
         let mut signing_data = self.accounts.signing_session.try_borrow_mut()?;
         signing_data[..signing_session_data.len()].copy_from_slice(&signing_session_data);
 
-        Ok(())
+        event.emit()
     }
 
-    #[must_use]
-    fn mandatory_account_data_checks(root: &Root, signer: &Address) -> Result<(), ProgramError> {
-        root.signer_must_be_operator(signer)?;
+    fn mandatory_account_data_checks(
+        root: &RootView,
+        signer: &Address,
+    ) -> Result<(), ProgramError> {
+        root.signer_must_have_permission(signer, Permission::Initiate)?;
 
         Ok(())
     }
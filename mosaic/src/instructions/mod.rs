@@ -1,14 +1,23 @@
-use pinocchio::{Address, error::ProgramError};
+use pinocchio::{
+    AccountView, Address, ProgramResult,
+    error::ProgramError,
+    sysvars::{Sysvar, rent::Rent},
+};
 
 use crate::{
     ID,
-    seeds::{ROOT_PDA, SIGNING_SESSION_PDA},
+    errors::MosaicError,
+    seeds::{ROOT_PDA, SIGNING_SESSION_PDA, VAULT_PDA},
 };
 
+pub mod cancel;
+pub mod close_session;
 pub mod execute;
 pub mod init_root;
 pub mod init_signing_session;
+pub mod reject;
 pub mod sign;
+pub mod unsign;
 
 #[repr(u8)]
 pub enum Instruction {
@@ -16,6 +25,10 @@ pub enum Instruction {
     InitializeSigningSession,
     Sign,
     Execute,
+    Reject,
+    Cancel,
+    CloseSession,
+    Unsign,
 }
 
 impl TryFrom<&u8> for Instruction {
@@ -27,6 +40,10 @@ impl TryFrom<&u8> for Instruction {
             1 => Ok(Instruction::InitializeSigningSession),
             2 => Ok(Instruction::Sign),
             3 => Ok(Instruction::Execute),
+            4 => Ok(Instruction::Reject),
+            5 => Ok(Instruction::Cancel),
+            6 => Ok(Instruction::CloseSession),
+            7 => Ok(Instruction::Unsign),
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
@@ -51,8 +68,61 @@ pub fn signing_session_pda_check(
 }
 
 /// Checks if the provided root PDA is correct
-pub fn root_pda_check(key: &Address, bump: &[u8]) -> Result<(), ProgramError> {
-    check_pda(key, &[ROOT_PDA, bump])
+pub fn root_pda_check(
+    key: &Address,
+    creator: &[u8],
+    name: &[u8],
+    bump: &[u8],
+) -> Result<(), ProgramError> {
+    check_pda(key, &[ROOT_PDA, creator, name, bump])
+}
+
+/// Finds system owned vault PDA of the root at given index
+pub fn find_vault_pda(root_pda: &Address, vault_index: u8) -> (Address, u8) {
+    Address::find_program_address(&[VAULT_PDA, root_pda.as_ref(), &[vault_index]], &ID.into())
+}
+
+/// Checks if the provided vault PDA is correct
+pub fn vault_pda_check(
+    key: &Address,
+    root_pda: &[u8],
+    vault_index: u8,
+    bump: &[u8],
+) -> Result<(), ProgramError> {
+    check_pda(key, &[VAULT_PDA, root_pda, &[vault_index], bump])
+        .map_err(|_| MosaicError::VaultAccountMismatch.into())
+}
+
+/// Writes data to program owned account; resizes it and tops up missing rent from payer if needed
+pub fn write_resized(account: &AccountView, payer: &AccountView, data: &[u8]) -> ProgramResult {
+    let new_len = data.len();
+
+    if new_len != account.data_len() {
+        // calculate new rent
+        let rent = Rent::get()?;
+        let new_minimum_balance = rent.try_minimum_balance(new_len)?;
+        let current_lamports = account.lamports();
+
+        // top-up missing rent
+        if current_lamports < new_minimum_balance {
+            let lamports_needed = new_minimum_balance - current_lamports;
+
+            pinocchio_system::instructions::Transfer {
+                from: payer,
+                to: account,
+                lamports: lamports_needed,
+            }
+            .invoke()?;
+        }
+
+        // resize account
+        account.resize(new_len)?;
+    }
+
+    let mut account_data = account.try_borrow_mut()?;
+    account_data[..new_len].copy_from_slice(data);
+
+    Ok(())
 }
 
 fn check_pda(key: &Address, seeds: &[&[u8]]) -> Result<(), ProgramError> {
@@ -63,43 +133,3 @@ fn check_pda(key: &Address, seeds: &[&[u8]]) -> Result<(), ProgramError> {
     }
     Ok(())
 }
-
-/// Allows for dynamic dispatch with invoke signed; up to 20 arbitrary accounts
-#[macro_export]
-macro_rules! invoke_signed_dynamic {
-    ($instruction:expr, $account_infos:expr, $signers:expr) => {{
-        macro_rules! try_invoke {
-            ($n:expr) => {
-                if $account_infos.len() == $n {
-                    let arr: &[&pinocchio::AccountView; $n] =
-                        $account_infos.as_slice().try_into().unwrap();
-                    break invoke_signed($instruction, arr, $signers);
-                }
-            };
-        }
-
-        loop {
-            try_invoke!(1);
-            try_invoke!(2);
-            try_invoke!(3);
-            try_invoke!(4);
-            try_invoke!(5);
-            try_invoke!(6);
-            try_invoke!(7);
-            try_invoke!(8);
-            try_invoke!(9);
-            try_invoke!(10);
-            try_invoke!(11);
-            try_invoke!(12);
-            try_invoke!(13);
-            try_invoke!(14);
-            try_invoke!(15);
-            try_invoke!(16);
-            try_invoke!(17);
-            try_invoke!(18);
-            try_invoke!(19);
-            try_invoke!(20);
-            break Err(ProgramError::InvalidArgument.into());
-        }
-    }};
-}
@@ -0,0 +1,152 @@
+use crate::{
+    ID,
+    errors::MosaicError,
+    events::Event,
+    instructions::{root_pda_check, signing_session_pda_check},
+    state::{
+        root::{Permission, RootView},
+        signing_session::SigningSessionHeader,
+        zero_copy::ZeroCopy,
+    },
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{AccountView, Address, ProgramResult, error::ProgramError};
+
+/// Reject Session
+///
+/// ### accounts:
+///   0. `[WRITE, SIGNER]` operator
+///   1. `[READ]`   root pda
+///   2. `[WRITE]`  signing session pda
+pub struct RejectIxAccounts<'info> {
+    pub payer: &'info AccountView,
+    pub root: &'info AccountView,
+    pub signing_session: &'info AccountView,
+}
+
+impl<'info> TryFrom<&'info [AccountView]> for RejectIxAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, root, signing_session] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !root.owned_by(&ID.into()) {
+            return Err(MosaicError::RootAccountIncorrectOwner.into());
+        }
+        if !signing_session.owned_by(&ID.into()) {
+            return Err(MosaicError::SigningSessionAccountIncorrectOwner.into());
+        }
+        if !payer.is_signer() {
+            return Err(MosaicError::PayerMustEqualSigner.into());
+        }
+        if !signing_session.is_writable() {
+            return Err(MosaicError::SigningSessionAccountMustBeWritable.into());
+        }
+        if signing_session.is_data_empty() {
+            return Err(MosaicError::SigningSessionAccountMustBeInitialized.into());
+        }
+
+        Ok(Self {
+            payer,
+            root,
+            signing_session,
+        })
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, BorshDeserialize, BorshSerialize)]
+pub struct RejectIxData {
+    pub bump: u8,
+}
+
+impl<'info> TryFrom<&'info [u8]> for RejectIxData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        borsh::from_slice::<Self>(data).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+pub struct Reject<'info> {
+    pub accounts: RejectIxAccounts<'info>,
+    pub instruction_data: RejectIxData,
+}
+
+impl<'info> TryFrom<(&'info [AccountView], &'info [u8])> for Reject<'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'info [AccountView], &'info [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = RejectIxAccounts::try_from(accounts)?;
+        let instruction_data = RejectIxData::try_from(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'info> Reject<'info> {
+    pub fn handler(&mut self) -> ProgramResult {
+        let root_account = self.accounts.root.try_borrow()?;
+        let root = RootView::from_bytes(&root_account)?;
+
+        let mut signing_account = self.accounts.signing_session.try_borrow_mut()?;
+        let signing = SigningSessionHeader::from_bytes_mut(&mut signing_account)?;
+
+        signing_session_pda_check(
+            self.accounts.signing_session.address(),
+            self.accounts.root.address().as_array(),
+            signing.session_id,
+            &[self.instruction_data.bump],
+        )?;
+
+        root_pda_check(
+            self.accounts.root.address(),
+            root.header.creator.as_ref(),
+            root.name(),
+            &[root.header.bump],
+        )?;
+        signing.sync_votes(&root)?;
+        let position =
+            Self::mandatory_account_data_checks(signing, &root, self.accounts.payer.address())?;
+
+        signing.reject_checked(position)?;
+        Event::SessionRejectVote {
+            root: *self.accounts.root.address(),
+            session_id: signing.session_id,
+            signer: *self.accounts.payer.address(),
+            rejection_weight: root.weight_of(signing.rejections),
+        }
+        .emit()?;
+
+        if signing.check_rejections_preventing_threshold(root.operators, root.header.threshold) {
+            signing.reject();
+            Event::SessionRejected {
+                root: *self.accounts.root.address(),
+                session_id: signing.session_id,
+                signer: *self.accounts.payer.address(),
+                rejection_weight: root.weight_of(signing.rejections),
+            }
+            .emit()?;
+        }
+
+        Ok(())
+    }
+
+    /// returns position of the signer among root operators
+    fn mandatory_account_data_checks(
+        signing: &SigningSessionHeader,
+        root: &RootView,
+        signer: &Address,
+    ) -> Result<usize, ProgramError> {
+        signing.must_be_active()?;
+
+        root.signer_must_have_permission(signer, Permission::Vote)
+    }
+}
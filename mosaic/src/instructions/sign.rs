@@ -1,21 +1,26 @@
 use crate::{
     ID,
     errors::MosaicError,
+    events::Event,
     instructions::{root_pda_check, signing_session_pda_check},
-    state::{root::Root, signing_session::SigningSession},
+    state::{
+        root::{Permission, RootView},
+        signing_session::SigningSessionHeader,
+        zero_copy::ZeroCopy,
+    },
 };
 use borsh::{BorshDeserialize, BorshSerialize};
 use pinocchio::{
     AccountView, Address, ProgramResult,
     error::ProgramError,
-    sysvars::{Sysvar, rent::Rent},
+    sysvars::{Sysvar, clock::Clock},
 };
 
 /// Sign Session
 ///
 /// ### accounts:
-///   0. `[WRITE, SIGNER]`
-///   1. `[WRITE]`  root pda
+///   0. `[WRITE, SIGNER]` operator
+///   1. `[READ]`   root pda
 ///   2. `[WRITE]`  signing session pda
 pub struct SignIxAccounts<'info> {
     pub payer: &'info AccountView,
@@ -27,7 +32,7 @@ impl<'info> TryFrom<&'info [AccountView]> for SignIxAccounts<'info> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'info [AccountView]) -> Result<Self, Self::Error> {
-        let [payer, root, signing_session, _system_program] = accounts else {
+        let [payer, root, signing_session] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
@@ -65,7 +70,7 @@ impl<'info> TryFrom<&'info [u8]> for SignIxData {
     type Error = ProgramError;
 
     fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
-        Ok(borsh::from_slice::<Self>(&data).map_err(|_| ProgramError::InvalidInstructionData)?)
+        borsh::from_slice::<Self>(data).map_err(|_| ProgramError::InvalidInstructionData)
     }
 }
 
@@ -92,71 +97,67 @@ impl<'info> TryFrom<(&'info [AccountView], &'info [u8])> for Sign<'info> {
 
 impl<'info> Sign<'info> {
     pub fn handler(&mut self) -> ProgramResult {
-        let root_account = &self.accounts.root.try_borrow()?;
-        let root_data = Root::deserialize(&root_account)?;
+        let root_account = self.accounts.root.try_borrow()?;
+        let root = RootView::from_bytes(&root_account)?;
+
+        let mut signing_account = self.accounts.signing_session.try_borrow_mut()?;
+        let signing = SigningSessionHeader::from_bytes_mut(&mut signing_account)?;
 
         signing_session_pda_check(
-            &self.accounts.signing_session.address(),
+            self.accounts.signing_session.address(),
             self.accounts.root.address().as_array(),
-            root_data.last_id,
+            signing.session_id,
             &[self.instruction_data.bump],
         )?;
 
-        let signing_account = self.accounts.signing_session.try_borrow()?;
-        let mut signing: SigningSession = SigningSession::deserialize(&signing_account)?;
-
-        root_pda_check(&self.accounts.root.address(), &[root_data.bump])?;
-        Self::mandatory_account_data_checks(&signing, &root_data, self.accounts.payer.address())?;
-
-        signing.approve_checked(self.accounts.payer.address())?;
+        root_pda_check(
+            self.accounts.root.address(),
+            root.header.creator.as_ref(),
+            root.name(),
+            &[root.header.bump],
+        )?;
+        signing.sync_votes(&root)?;
+        let clock = Clock::get()?;
+        let position = Self::mandatory_account_data_checks(
+            signing,
+            &root,
+            self.accounts.payer.address(),
+            &clock,
+        )?;
 
-        if signing.check_approvals_reaching_threshold(root_data.threshold.into()) {
-            signing.progress_phase_checked()?;
+        signing.approve_checked(position)?;
+        Event::SessionSigned {
+            root: *self.accounts.root.address(),
+            session_id: signing.session_id,
+            signer: *self.accounts.payer.address(),
+            approval_weight: root.weight_of(signing.approvals),
         }
+        .emit()?;
 
-        let (signing, new_signing_len) = signing.serialize()?;
-        let current_data_len = signing_account.len();
-
-        if new_signing_len != current_data_len {
-            drop(signing_account);
-
-            // calculate new rent
-            let rent = Rent::get()?;
-            let new_minimum_balance = rent.try_minimum_balance(new_signing_len)?;
-            let current_lamports = self.accounts.signing_session.lamports();
-
-            // top-up missing rent
-            if current_lamports < new_minimum_balance {
-                let lamports_needed = new_minimum_balance - current_lamports;
-
-                pinocchio_system::instructions::Transfer {
-                    from: self.accounts.payer,
-                    to: self.accounts.signing_session,
-                    lamports: lamports_needed,
-                }
-                .invoke()?;
+        if signing.check_approvals_reaching_threshold(root.operators, root.header.threshold) {
+            signing.progress_phase_checked()?;
+            signing.record_approval_time(&clock);
+            Event::SessionApproved {
+                root: *self.accounts.root.address(),
+                session_id: signing.session_id,
+                approved_at: clock.unix_timestamp,
             }
-
-            // resize signing session account
-            self.accounts.signing_session.resize(new_signing_len)?;
-
-            // re-borrow signing session account / write to
-            let mut signing_account = self.accounts.signing_session.try_borrow_mut()?;
-            signing_account[..new_signing_len].copy_from_slice(&signing);
+            .emit()?;
         }
 
         Ok(())
     }
 
-    #[must_use]
+    /// returns position of the signer among root operators
     fn mandatory_account_data_checks(
-        signing: &SigningSession,
-        root: &Root,
+        signing: &SigningSessionHeader,
+        root: &RootView,
         signer: &Address,
-    ) -> Result<(), ProgramError> {
+        clock: &Clock,
+    ) -> Result<usize, ProgramError> {
         signing.must_be_active()?;
-        root.signer_must_be_operator(signer)?;
+        signing.must_not_be_expired(clock)?;
 
-        Ok(())
+        root.signer_must_have_permission(signer, Permission::Vote)
     }
 }
@@ -0,0 +1,157 @@
+use crate::{
+    ID,
+    errors::MosaicError,
+    events::Event,
+    instructions::{root_pda_check, signing_session_pda_check},
+    state::{
+        root::{Permission, RootView},
+        signing_session::{SigningSessionHeader, SigningSessionPhase},
+        zero_copy::ZeroCopy,
+    },
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{AccountView, Address, ProgramResult, error::ProgramError};
+
+/// Unsign Session
+///
+/// revokes approval of the signer before execution
+///
+/// ### accounts:
+///   0. `[WRITE, SIGNER]` operator
+///   1. `[READ]`   root pda
+///   2. `[WRITE]`  signing session pda
+pub struct UnsignIxAccounts<'info> {
+    pub payer: &'info AccountView,
+    pub root: &'info AccountView,
+    pub signing_session: &'info AccountView,
+}
+
+impl<'info> TryFrom<&'info [AccountView]> for UnsignIxAccounts<'info> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'info [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, root, signing_session] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !root.owned_by(&ID.into()) {
+            return Err(MosaicError::RootAccountIncorrectOwner.into());
+        }
+        if !signing_session.owned_by(&ID.into()) {
+            return Err(MosaicError::SigningSessionAccountIncorrectOwner.into());
+        }
+        if !payer.is_signer() {
+            return Err(MosaicError::PayerMustEqualSigner.into());
+        }
+        if !signing_session.is_writable() {
+            return Err(MosaicError::SigningSessionAccountMustBeWritable.into());
+        }
+        if signing_session.is_data_empty() {
+            return Err(MosaicError::SigningSessionAccountMustBeInitialized.into());
+        }
+
+        Ok(Self {
+            payer,
+            root,
+            signing_session,
+        })
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, BorshDeserialize, BorshSerialize)]
+pub struct UnsignIxData {
+    pub bump: u8,
+}
+
+impl<'info> TryFrom<&'info [u8]> for UnsignIxData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        borsh::from_slice::<Self>(data).map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+pub struct Unsign<'info> {
+    pub accounts: UnsignIxAccounts<'info>,
+    pub instruction_data: UnsignIxData,
+}
+
+impl<'info> TryFrom<(&'info [AccountView], &'info [u8])> for Unsign<'info> {
+    type Error = ProgramError;
+
+    fn try_from(
+        (accounts, data): (&'info [AccountView], &'info [u8]),
+    ) -> Result<Self, Self::Error> {
+        let accounts = UnsignIxAccounts::try_from(accounts)?;
+        let instruction_data = UnsignIxData::try_from(data)?;
+
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+impl<'info> Unsign<'info> {
+    pub fn handler(&mut self) -> ProgramResult {
+        let root_account = self.accounts.root.try_borrow()?;
+        let root = RootView::from_bytes(&root_account)?;
+
+        let mut signing_account = self.accounts.signing_session.try_borrow_mut()?;
+        let signing = SigningSessionHeader::from_bytes_mut(&mut signing_account)?;
+
+        signing_session_pda_check(
+            self.accounts.signing_session.address(),
+            self.accounts.root.address().as_array(),
+            signing.session_id,
+            &[self.instruction_data.bump],
+        )?;
+
+        root_pda_check(
+            self.accounts.root.address(),
+            root.header.creator.as_ref(),
+            root.name(),
+            &[root.header.bump],
+        )?;
+        signing.sync_votes(&root)?;
+        let position =
+            Self::mandatory_account_data_checks(signing, &root, self.accounts.payer.address())?;
+
+        signing.revoke_approval_checked(position)?;
+        Event::SessionUnsigned {
+            root: *self.accounts.root.address(),
+            session_id: signing.session_id,
+            signer: *self.accounts.payer.address(),
+            approval_weight: root.weight_of(signing.approvals),
+        }
+        .emit()?;
+
+        if !signing.check_approvals_reaching_threshold(root.operators, root.header.threshold) {
+            let was_approved = signing.phase() == SigningSessionPhase::Approved;
+            signing.reactivate();
+            if was_approved {
+                Event::SessionReactivated {
+                    root: *self.accounts.root.address(),
+                    session_id: signing.session_id,
+                    signer: *self.accounts.payer.address(),
+                    approval_weight: root.weight_of(signing.approvals),
+                }
+                .emit()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// returns position of the signer among root operators
+    fn mandatory_account_data_checks(
+        signing: &SigningSessionHeader,
+        root: &RootView,
+        signer: &Address,
+    ) -> Result<usize, ProgramError> {
+        signing.must_be_active_or_approved()?;
+
+        root.signer_must_have_permission(signer, Permission::Vote)
+    }
+}
@@ -1,17 +1,25 @@
+#[cfg(feature = "cpi")]
+pub mod cpi;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod processor;
+pub mod programs;
 pub mod seeds;
 pub mod state;
 
+#[cfg(not(feature = "no-entrypoint"))]
 use {
     pinocchio::program_entrypoint,
     processor::process_instruction,
     solana_program::{custom_heap_default, custom_panic_default},
 };
 
+#[cfg(not(feature = "no-entrypoint"))]
 custom_heap_default!();
+#[cfg(not(feature = "no-entrypoint"))]
 custom_panic_default!();
+#[cfg(not(feature = "no-entrypoint"))]
 program_entrypoint!(process_instruction);
 
 pinocchio_pubkey::declare_id!("s75D2Kb5WnVBsFQiSLj5E4oRgwDJU63487cSnp2khXh");
@@ -1,6 +1,7 @@
 use crate::instructions::{
-    Instruction, execute::Execute, init_root::InitializeOperators,
-    init_signing_session::InitializeSigningSession, sign::Sign,
+    Instruction, cancel::Cancel, close_session::CloseSession, execute::Execute,
+    init_root::InitializeOperators, init_signing_session::InitializeSigningSession, reject::Reject,
+    sign::Sign, unsign::Unsign,
 };
 use pinocchio::{AccountView, Address, ProgramResult, error::ProgramError};
 
@@ -24,10 +25,13 @@ pub fn process_instruction(
         }
         Instruction::Sign => Sign::try_from((accounts, data))?.handler(),
         Instruction::Execute => Execute::try_from((accounts, data))?.handler(),
+        Instruction::Reject => Reject::try_from((accounts, data))?.handler(),
+        Instruction::Cancel => Cancel::try_from((accounts, data))?.handler(),
+        Instruction::CloseSession => CloseSession::try_from((accounts, data))?.handler(),
+        Instruction::Unsign => Unsign::try_from((accounts, data))?.handler(),
     }
 }
 
-#[must_use]
 fn mandatory_checks(program_id: &Address) -> Result<(), ProgramError> {
     if program_id != &crate::ID.into() {
         return Err(ProgramError::IncorrectProgramId);
@@ -0,0 +1,38 @@
+use pinocchio::Address;
+
+/// spl token program.
+pub const TOKEN_PROGRAM_ID: Address = Address::new_from_array(pinocchio_pubkey::pubkey!(
+    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+));
+
+/// spl token-2022 program.
+pub const TOKEN_2022_PROGRAM_ID: Address = Address::new_from_array(pinocchio_pubkey::pubkey!(
+    "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+));
+
+/// `transfer_checked` discriminator shared by both token programs.
+pub const TRANSFER_CHECKED_DISCRIMINATOR: u8 = 12;
+
+/// bpf upgradeable loader program.
+pub const BPF_LOADER_UPGRADEABLE_ID: Address = Address::new_from_array(pinocchio_pubkey::pubkey!(
+    "BPFLoaderUpgradeab1e11111111111111111111111"
+));
+
+/// rent sysvar.
+pub const RENT_SYSVAR_ID: Address = Address::new_from_array(pinocchio_pubkey::pubkey!(
+    "SysvarRent111111111111111111111111111111111"
+));
+
+/// clock sysvar.
+pub const CLOCK_SYSVAR_ID: Address = Address::new_from_array(pinocchio_pubkey::pubkey!(
+    "SysvarC1ock11111111111111111111111111111111"
+));
+
+/// `Upgrade` instruction tag of the upgradeable loader.
+pub const LOADER_UPGRADE_TAG: u32 = 3;
+
+/// `SetAuthority` instruction tag of the upgradeable loader.
+pub const LOADER_SET_AUTHORITY_TAG: u32 = 4;
+
+/// `Close` instruction tag of the upgradeable loader.
+pub const LOADER_CLOSE_TAG: u32 = 5;
@@ -3,3 +3,6 @@ pub const ROOT_PDA: &[u8] = b"root_pda";
 
 /// seed of the signing session PDA.
 pub const SIGNING_SESSION_PDA: &[u8] = b"signing_session_pda";
+
+/// seed of the vault PDA.
+pub const VAULT_PDA: &[u8] = b"vault_pda";
@@ -0,0 +1,37 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::sysvars::clock::Clock;
+
+/// point in time, or duration when used as ttl, measured in slots or unix seconds
+#[derive(Clone, Copy, BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub enum Expiry {
+    Slot(u64),
+    UnixTimestamp(i64),
+}
+
+impl Expiry {
+    /// checks if the moment was reached at the given clock
+    pub fn is_reached(&self, clock: &Clock) -> bool {
+        match self {
+            Expiry::Slot(slot) => clock.slot >= *slot,
+            Expiry::UnixTimestamp(timestamp) => clock.unix_timestamp >= *timestamp,
+        }
+    }
+
+    /// treats self as ttl and checks if it spans any slot or second
+    pub fn is_positive(&self) -> bool {
+        match self {
+            Expiry::Slot(slots) => *slots > 0,
+            Expiry::UnixTimestamp(seconds) => *seconds > 0,
+        }
+    }
+
+    /// treats self as ttl and returns moment it elapses counting from the given clock
+    pub fn after(&self, clock: &Clock) -> Self {
+        match self {
+            Expiry::Slot(slots) => Expiry::Slot(clock.slot.saturating_add(*slots)),
+            Expiry::UnixTimestamp(seconds) => {
+                Expiry::UnixTimestamp(clock.unix_timestamp.saturating_add(*seconds))
+            }
+        }
+    }
+}
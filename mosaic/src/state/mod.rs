@@ -1,2 +1,5 @@
+pub mod expiry;
+pub mod proposal;
 pub mod root;
 pub mod signing_session;
+pub mod zero_copy;
@@ -0,0 +1,162 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::Address;
+
+use crate::{
+    programs::{
+        CLOCK_SYSVAR_ID, LOADER_CLOSE_TAG, LOADER_SET_AUTHORITY_TAG, LOADER_UPGRADE_TAG,
+        RENT_SYSVAR_ID,
+    },
+    state::{
+        expiry::Expiry,
+        root::{ExecutePolicy, Operator},
+    },
+};
+
+/// action carried out once the session gets executed
+#[derive(Clone, BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub enum Proposal {
+    /// cpi into allowed programs with session instructions
+    Cpi,
+
+    /// change of root configuration; applied by mosaic itself
+    Config(ConfigChange),
+
+    /// lamports moved out of root vault; performed by mosaic itself
+    Transfer {
+        recipient: Address,
+        amount: u64,
+        vault_index: u8,
+    },
+
+    /// tokens moved with `transfer_checked`; performed by mosaic itself
+    TokenTransfer(TokenTransfer),
+
+    /// upgradeable loader call signed by given vault or root pda when none
+    Loader {
+        change: LoaderChange,
+        vault_index: Option<u8>,
+    },
+}
+
+/// token transfer signed by given vault or root pda when none
+#[derive(Clone, BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub struct TokenTransfer {
+    pub token_program: Address,
+    pub mint: Address,
+    pub source: Address,
+    pub destination: Address,
+    pub amount: u64,
+    pub decimals: u8,
+    pub vault_index: Option<u8>,
+}
+
+/// root configuration change governed by operators
+#[derive(Clone, BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub enum ConfigChange {
+    /// adds new operator with its weight
+    AddOperator(Operator),
+
+    /// removes existing operator
+    RemoveOperator(Address),
+
+    /// swaps existing operator key for a new one
+    ReplaceOperator { old: Address, new: Address },
+
+    /// sets new required weight of operator approvals
+    ChangeThreshold(u16),
+
+    /// sets session lifetime applied when proposer sets no expiry
+    ChangeDefaultTtl(Option<Expiry>),
+
+    /// sets seconds between session approval and its earliest execution
+    ChangeTimelock(u32),
+
+    /// adds program sessions are allowed to cpi into
+    AddAllowedProgram(Address),
+
+    /// removes program from allowed ones
+    RemoveAllowedProgram(Address),
+
+    /// sets voting weight of existing operator
+    ChangeOperatorWeight { operator: Address, weight: u8 },
+
+    /// sets permission bitmask of existing operator
+    ChangeOperatorPermissions { operator: Address, permissions: u8 },
+
+    /// sets who may execute approved sessions
+    ChangeExecutePolicy(ExecutePolicy),
+}
+
+/// upgradeable loader call governed by operators
+#[derive(Clone, BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub enum LoaderChange {
+    /// upgrades program with buffer contents; buffer lamports go to spill
+    Upgrade {
+        program: Address,
+        program_data: Address,
+        buffer: Address,
+        spill: Address,
+    },
+
+    /// hands buffer or program data authority over; none makes it immutable
+    SetAuthority {
+        account: Address,
+        new_authority: Option<Address>,
+    },
+
+    /// closes buffer moving its lamports to recipient
+    CloseBuffer { buffer: Address, recipient: Address },
+}
+
+impl LoaderChange {
+    /// returns loader instruction accounts as (address, writable) without the authority
+    pub fn accounts(&self) -> Vec<(Address, bool)> {
+        match self {
+            LoaderChange::Upgrade {
+                program,
+                program_data,
+                buffer,
+                spill,
+            } => vec![
+                (*program_data, true),
+                (*program, true),
+                (*buffer, true),
+                (*spill, true),
+                (RENT_SYSVAR_ID, false),
+                (CLOCK_SYSVAR_ID, false),
+            ],
+            LoaderChange::SetAuthority {
+                account,
+                new_authority,
+            } => {
+                let mut accounts = vec![(*account, true)];
+                if let Some(new_authority) = new_authority {
+                    accounts.push((*new_authority, false));
+                }
+                accounts
+            }
+            LoaderChange::CloseBuffer { buffer, recipient } => {
+                vec![(*buffer, true), (*recipient, true)]
+            }
+        }
+    }
+
+    /// returns position of the signing authority among loader instruction accounts
+    pub fn authority_position(&self) -> usize {
+        match self {
+            LoaderChange::Upgrade { .. } => 6,
+            LoaderChange::SetAuthority { .. } => 1,
+            LoaderChange::CloseBuffer { .. } => 2,
+        }
+    }
+
+    /// returns bincode encoded loader instruction data
+    pub fn instruction_data(&self) -> [u8; 4] {
+        match self {
+            LoaderChange::Upgrade { .. } => LOADER_UPGRADE_TAG,
+            LoaderChange::SetAuthority { .. } => LOADER_SET_AUTHORITY_TAG,
+            LoaderChange::CloseBuffer { .. } => LOADER_CLOSE_TAG,
+        }
+        .to_le_bytes()
+    }
+}
@@ -1,77 +1,612 @@
-use crate::{errors::MosaicError, instructions::init_root::InitializeRootIxData};
-use pinocchio::{Address, error::ProgramError};
+use crate::{
+    ID,
+    errors::MosaicError,
+    instructions::init_root::InitializeRootIxData,
+    state::{
+        expiry::Expiry, proposal::ConfigChange, signing_session::vote_bit, zero_copy::ZeroCopy,
+    },
+};
+use borsh::{BorshDeserialize, BorshSerialize};
+use pinocchio::{Address, address::MAX_SEED_LEN, error::ProgramError, sysvars::clock::Clock};
+
+/// most operator slots a root can hold; session votes keep a single bit per operator position
+///
+/// removed operators keep their slot as `Operator::REMOVED` so positions of remaining
+/// operators, and with them votes of in-flight sessions, stay valid. added and replacing
+/// operators take a slot over in a new operators epoch, so sessions drop votes of its previous
+/// holder before counting them
+pub const MAX_OPERATORS: usize = 64;
 
 /// root data
-#[derive(Clone, borsh::BorshDeserialize, borsh::BorshSerialize, Debug)]
+///
+/// stored as `RootHeader` followed by borsh encoded `RootConfig`
+#[derive(Clone, Debug)]
 pub struct Root {
-    /// keys of multisig operators
-    pub operators: Vec<Address>,
+    /// key of the root creator; part of root pda derivation
+    pub creator: Address,
+
+    /// user chosen root name; part of root pda derivation
+    pub name: String,
+
+    /// multisig operators with their voting weight
+    pub operators: Vec<Operator>,
+
+    /// epoch each operator slot got its current holder in
+    pub operator_epochs: Vec<u32>,
+
+    /// bumped whenever an operator slot changes holder
+    pub operators_epoch: u32,
+
+    /// last approval id
+    pub last_id: u16,
+
+    /// required summed weight of operator approvals
+    pub threshold: u16,
+
+    /// programs sessions are allowed to cpi into
+    pub allowed_programs: Vec<Address>,
+
+    /// session lifetime applied when proposer sets no expiry
+    pub default_ttl: Option<Expiry>,
+
+    /// seconds between session approval and its earliest execution
+    pub timelock: u32,
+
+    /// who may execute approved sessions
+    pub execute_policy: ExecutePolicy,
+
+    /// cannonical bump
+    pub bump: u8,
+}
+
+/// fixed part of root account data, read and written in place
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug)]
+pub struct RootHeader {
+    /// key of the root creator; part of root pda derivation
+    pub creator: Address,
+
+    /// length of the name
+    pub name_len: u8,
+
+    /// user chosen root name padded with zeroes; part of root pda derivation
+    pub name: [u8; MAX_SEED_LEN],
 
     /// last approval id
     pub last_id: u16,
 
-    /// required operator approvals
-    pub threshold: u8,
+    /// required summed weight of operator approvals
+    pub threshold: u16,
 
-    /// program governed by multisig
-    pub destination_program: Address,
+    /// seconds between session approval and its earliest execution
+    pub timelock: u32,
+
+    /// bumped whenever an operator slot changes holder
+    pub operators_epoch: u32,
 
     /// cannonical bump
     pub bump: u8,
 }
 
+unsafe impl ZeroCopy for RootHeader {}
+
+/// variable part of root account data following `RootHeader`
+#[derive(Clone, BorshDeserialize, BorshSerialize, Debug)]
+pub struct RootConfig {
+    /// multisig operators; encoded as `u32` length followed by fixed size operators
+    pub operators: Vec<Operator>,
+
+    /// programs sessions are allowed to cpi into
+    pub allowed_programs: Vec<Address>,
+
+    /// session lifetime applied when proposer sets no expiry
+    pub default_ttl: Option<Expiry>,
+
+    /// who may execute approved sessions
+    pub execute_policy: ExecutePolicy,
+
+    /// epoch each operator slot got its current holder in
+    pub operator_epochs: Vec<u32>,
+}
+
+impl RootHeader {
+    /// returns user chosen root name
+    pub fn name(&self) -> &[u8] {
+        &self.name[..usize::from(self.name_len)]
+    }
+
+    /// returns id of the next signing session
+    pub fn next_session_id(&self) -> Result<u16, ProgramError> {
+        self.last_id
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)
+    }
+}
+
+/// zero-copy view of root account data; reads header, operators and allowed programs without
+/// deserializing, decoding the rest of `RootConfig` on demand
+pub struct RootView<'a> {
+    pub header: &'a RootHeader,
+    pub operators: &'a [Operator],
+    pub allowed_programs: &'a [Address],
+    /// borsh encoded `default_ttl`, `execute_policy` and `operator_epochs`
+    config: &'a [u8],
+}
+
+impl<'a> RootView<'a> {
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let header = RootHeader::from_bytes(data)?;
+        let config = data
+            .get(RootHeader::LEN..)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let (operators, config) = Operator::vec_from_bytes(config)?;
+        let (allowed_programs, config) = Address::vec_from_bytes(config)?;
+
+        Ok(Self {
+            header,
+            operators,
+            allowed_programs,
+            config,
+        })
+    }
+
+    /// returns user chosen root name
+    pub fn name(&self) -> &[u8] {
+        self.header.name()
+    }
+
+    /// returns session lifetime applied when proposer sets no expiry
+    pub fn default_ttl(&self) -> Result<Option<Expiry>, ProgramError> {
+        Option::<Expiry>::deserialize(&mut &self.config[..])
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// returns who may execute approved sessions
+    pub fn execute_policy(&self) -> Result<ExecutePolicy, ProgramError> {
+        let mut config = self.config;
+        Option::<Expiry>::deserialize(&mut config)
+            .and_then(|_| ExecutePolicy::deserialize(&mut config))
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// returns epoch each operator slot got its current holder in
+    pub fn operator_epochs(&self) -> Result<Vec<u32>, ProgramError> {
+        let mut config = self.config;
+        Option::<Expiry>::deserialize(&mut config)
+            .and_then(|_| ExecutePolicy::deserialize(&mut config))
+            .and_then(|_| Vec::<u32>::deserialize(&mut config))
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// checks if program is present among allowed programs
+    pub fn program_must_be_allowed(&self, program: &Address) -> Result<(), ProgramError> {
+        if !self.allowed_programs.contains(program) {
+            return Err(MosaicError::ProgramNotAllowed.into());
+        }
+        Ok(())
+    }
+
+    /// checks if executor is allowed by execute policy
+    pub fn executor_must_be_allowed(&self, executor: &Address) -> Result<(), ProgramError> {
+        match self.execute_policy()? {
+            ExecutePolicy::Anyone => Ok(()),
+            ExecutePolicy::Operators => self
+                .signer_must_have_permission(executor, Permission::Execute)
+                .map(|_| ()),
+            ExecutePolicy::Executors(executors) => {
+                if !executors.contains(executor) {
+                    return Err(MosaicError::ExecutorNotAllowed.into());
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// returns requested session expiry, falling back to default ttl counted from now
+    pub fn session_expiry(
+        &self,
+        requested: Option<Expiry>,
+        clock: &Clock,
+    ) -> Result<Option<Expiry>, ProgramError> {
+        match requested {
+            Some(expiry) => Ok(Some(expiry)),
+            None => Ok(self.default_ttl()?.map(|ttl| ttl.after(clock))),
+        }
+    }
+
+    /// checks if signer is known operator granted given permission; returns its position
+    pub fn signer_must_have_permission(
+        &self,
+        signer: &Address,
+        permission: Permission,
+    ) -> Result<usize, ProgramError> {
+        permitted_operator_position(self.operators, signer, permission)
+    }
+
+    /// returns summed weight of operators allowed to vote
+    pub fn total_weight(&self) -> u32 {
+        votes_weight(self.operators, u64::MAX)
+    }
+
+    /// returns summed weight of operators allowed to vote whose position is set among votes
+    pub fn weight_of(&self, votes: u64) -> u32 {
+        votes_weight(self.operators, votes)
+    }
+}
+
+/// multisig operator
+#[repr(C)]
+#[derive(Clone, Copy, BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub struct Operator {
+    /// operator key
+    pub key: Address,
+
+    /// voting power added by operator approval
+    pub weight: u8,
+
+    /// bitmask of granted `Permission`s
+    pub permissions: u8,
+}
+
+unsafe impl ZeroCopy for Operator {}
+
+impl Operator {
+    /// tombstone left in the slot of removed operator; never matches a signer nor votes
+    pub const REMOVED: Operator = Operator {
+        key: Address::new_from_array([0; 32]),
+        weight: 0,
+        permissions: 0,
+    };
+
+    /// checks if slot holds removed operator
+    pub fn is_removed(&self) -> bool {
+        *self == Self::REMOVED
+    }
+
+    /// checks if operator was granted given permission
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.permissions & permission as u8 != 0
+    }
+}
+
+/// actions operators are allowed to take
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Permission {
+    /// creates signing sessions
+    Initiate = 1,
+
+    /// approves, unsigns and rejects signing sessions
+    Vote = 1 << 1,
+
+    /// executes approved signing sessions
+    Execute = 1 << 2,
+}
+
+impl Permission {
+    /// bitmask with every permission granted
+    pub const ALL: u8 =
+        Permission::Initiate as u8 | Permission::Vote as u8 | Permission::Execute as u8;
+}
+
+/// who may execute approved sessions
+#[derive(Clone, BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub enum ExecutePolicy {
+    /// any signer
+    Anyone,
+
+    /// operators granted execute permission
+    Operators,
+
+    /// listed keys only
+    Executors(Vec<Address>),
+}
+
 impl Root {
-    pub fn init(data: InitializeRootIxData) -> Self {
+    pub fn init(data: InitializeRootIxData, creator: &Address) -> Self {
         Self {
+            creator: *creator,
+            name: data.name,
+            operator_epochs: vec![0; data.operators.len()],
             operators: data.operators,
+            operators_epoch: 0,
             last_id: 0,
             threshold: data.threshold,
-            destination_program: data.destination_program,
+            allowed_programs: data.allowed_programs,
+            default_ttl: data.default_ttl,
+            timelock: data.timelock,
+            execute_policy: data.execute_policy,
             bump: data.bump,
         }
     }
 }
 
 impl Root {
-    /// check if destination program address match passed within instruction
-    pub fn destination_program_address_must_match(
-        &self,
-        destination_program: &Address,
-    ) -> Result<(), ProgramError> {
-        if &self.destination_program != destination_program {
-            return Err(
-                MosaicError::ProvidedDestinationProgramMismatchWithRootDestinationProgram.into(),
-            );
-        };
+    /// checks if root name fits into a single pda seed
+    pub fn name_must_fit_seed(name: &str) -> Result<(), ProgramError> {
+        if name.len() > MAX_SEED_LEN {
+            return Err(MosaicError::RootNameTooLong.into());
+        }
         Ok(())
     }
-    /// checks if signer is present among known operators
-    pub fn signer_must_be_operator(&self, signer: &Address) -> Result<(), ProgramError> {
-        if !self.operators.contains(signer) {
-            return Err(MosaicError::SignerIsNotOperator.into());
+
+    /// applies config change approved by operators
+    pub fn apply_config_change(&mut self, change: &ConfigChange) -> Result<(), ProgramError> {
+        match change {
+            ConfigChange::AddOperator(operator) => {
+                Self::operator_must_not_be_removed(operator)?;
+                self.operator_must_not_exist(&operator.key)?;
+                match self.operators.iter().position(Operator::is_removed) {
+                    Some(position) => self.reassign_slot(position, *operator)?,
+                    None => {
+                        self.operators.push(*operator);
+                        self.operator_epochs.push(self.operators_epoch);
+                    }
+                }
+            }
+            ConfigChange::RemoveOperator(operator) => {
+                let position = self.operator_position(operator)?;
+                self.operators[position] = Operator::REMOVED;
+            }
+            ConfigChange::ReplaceOperator { old, new } => {
+                let position = self.operator_position(old)?;
+                self.operator_must_not_exist(new)?;
+                // new key takes the slot over; votes cast by old key get dropped, not inherited
+                let replacement = Operator {
+                    key: *new,
+                    ..self.operators[position]
+                };
+                self.reassign_slot(position, replacement)?;
+            }
+            ConfigChange::ChangeOperatorWeight { operator, weight } => {
+                let position = self.operator_position(operator)?;
+                self.operators[position].weight = *weight;
+            }
+            ConfigChange::ChangeOperatorPermissions {
+                operator,
+                permissions,
+            } => {
+                let position = self.operator_position(operator)?;
+                self.operators[position].permissions = *permissions;
+            }
+            ConfigChange::ChangeExecutePolicy(policy) => {
+                self.execute_policy = policy.clone();
+            }
+            ConfigChange::ChangeThreshold(threshold) => {
+                self.threshold = *threshold;
+            }
+            ConfigChange::ChangeDefaultTtl(ttl) => {
+                default_ttl_must_be_positive(ttl)?;
+                self.default_ttl = *ttl;
+            }
+            ConfigChange::ChangeTimelock(timelock) => {
+                self.timelock = *timelock;
+            }
+            ConfigChange::AddAllowedProgram(program) => {
+                self.allowed_program_must_not_exist(program)?;
+                self.allowed_programs.push(*program);
+            }
+            ConfigChange::RemoveAllowedProgram(program) => {
+                let position = self.allowed_program_position(program)?;
+                self.allowed_programs.remove(position);
+            }
+        }
+        self.validate()
+    }
+
+    /// checks invariants of operators, threshold, allowed programs and executors
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        if self.operators.len() > MAX_OPERATORS {
+            return Err(MosaicError::TooManyOperators.into());
+        }
+        if self.operator_epochs.len() != self.operators.len() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if self.threshold == 0 {
+            return Err(MosaicError::ThresholdMustNotBeZero.into());
+        }
+        if u32::from(self.threshold) > self.total_weight() {
+            return Err(MosaicError::ThresholdMustNotExceedOperators.into());
+        }
+        default_ttl_must_be_positive(&self.default_ttl)?;
+        for (i, operator) in self.operators.iter().enumerate() {
+            if operator.is_removed() {
+                continue;
+            }
+            if operator.weight == 0 {
+                return Err(MosaicError::OperatorWeightMustNotBeZero.into());
+            }
+            if operator.permissions & !Permission::ALL != 0 {
+                return Err(MosaicError::UnknownOperatorPermissions.into());
+            }
+            if self.operators[i + 1..]
+                .iter()
+                .any(|other| other.key == operator.key && !other.is_removed())
+            {
+                return Err(MosaicError::OperatorsMustBeUnique.into());
+            }
+        }
+        for (i, program) in self.allowed_programs.iter().enumerate() {
+            // executing sessions re-entering mosaic could vote or execute on behalf of root pda
+            if program.as_array() == &ID {
+                return Err(MosaicError::MosaicProgramNotAllowed.into());
+            }
+            if self.allowed_programs[i + 1..].contains(program) {
+                return Err(MosaicError::AllowedProgramsMustBeUnique.into());
+            }
+        }
+        if let ExecutePolicy::Executors(executors) = &self.execute_policy {
+            if executors.is_empty() {
+                return Err(MosaicError::ExecutorsMustNotBeEmpty.into());
+            }
+            for (i, executor) in executors.iter().enumerate() {
+                if executors[i + 1..].contains(executor) {
+                    return Err(MosaicError::ExecutorsMustBeUnique.into());
+                }
+            }
         }
         Ok(())
     }
 
-    /// increments last id session
-    pub fn increment_last_id(&mut self) -> Result<(), ProgramError> {
-        self.last_id = self
-            .last_id
+    /// returns summed weight of operators allowed to vote
+    pub fn total_weight(&self) -> u32 {
+        votes_weight(&self.operators, u64::MAX)
+    }
+
+    /// returns summed weight of operators allowed to vote whose position is set among votes
+    pub fn weight_of(&self, votes: u64) -> u32 {
+        votes_weight(&self.operators, votes)
+    }
+
+    /// checks if operator does not look like a removed operator tombstone
+    pub fn operator_must_not_be_removed(operator: &Operator) -> Result<(), ProgramError> {
+        if operator.is_removed() {
+            return Err(MosaicError::OperatorWeightMustNotBeZero.into());
+        }
+        Ok(())
+    }
+
+    /// hands operator slot over to new holder in a new operators epoch
+    fn reassign_slot(&mut self, position: usize, operator: Operator) -> Result<(), ProgramError> {
+        self.operators_epoch = self
+            .operators_epoch
             .checked_add(1)
             .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.operators[position] = operator;
+        self.operator_epochs[position] = self.operators_epoch;
+        Ok(())
+    }
+
+    /// returns position of operator among known operators
+    fn operator_position(&self, operator: &Address) -> Result<usize, ProgramError> {
+        operator_position(&self.operators, operator).ok_or(MosaicError::OperatorNotFound.into())
+    }
+
+    /// checks if operator is not yet among known operators
+    fn operator_must_not_exist(&self, operator: &Address) -> Result<(), ProgramError> {
+        if operator_position(&self.operators, operator).is_some() {
+            return Err(MosaicError::OperatorAlreadyExists.into());
+        }
+        Ok(())
+    }
+
+    /// returns position of program among allowed programs
+    fn allowed_program_position(&self, program: &Address) -> Result<usize, ProgramError> {
+        self.allowed_programs
+            .iter()
+            .position(|known| known == program)
+            .ok_or(MosaicError::AllowedProgramNotFound.into())
+    }
+
+    /// checks if program is not yet among allowed programs
+    fn allowed_program_must_not_exist(&self, program: &Address) -> Result<(), ProgramError> {
+        if self.allowed_programs.contains(program) {
+            return Err(MosaicError::AllowedProgramAlreadyExists.into());
+        }
         Ok(())
     }
 }
 
 impl Root {
+    /// returns fixed part of root data
+    pub fn header(&self) -> Result<RootHeader, ProgramError> {
+        Self::name_must_fit_seed(&self.name)?;
+        let mut name = [0; MAX_SEED_LEN];
+        name[..self.name.len()].copy_from_slice(self.name.as_bytes());
+
+        Ok(RootHeader {
+            creator: self.creator,
+            name_len: self.name.len() as u8,
+            name,
+            last_id: self.last_id,
+            threshold: self.threshold,
+            timelock: self.timelock,
+            operators_epoch: self.operators_epoch,
+            bump: self.bump,
+        })
+    }
+
     /// returns serialized data with length
     pub fn serialize(&self) -> Result<(Vec<u8>, usize), ProgramError> {
-        let data = borsh::to_vec(&self).map_err(|_| ProgramError::InvalidAccountData)?;
+        let mut data = self.header()?.as_bytes().to_vec();
+        // fields in `RootConfig` order
+        (
+            &self.operators,
+            &self.allowed_programs,
+            &self.default_ttl,
+            &self.execute_policy,
+            &self.operator_epochs,
+        )
+            .serialize(&mut data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
         let size = data.len();
         Ok((data, size))
     }
+
     /// returns deserialized data
     pub fn deserialize(data: &[u8]) -> Result<Self, ProgramError> {
-        borsh::from_slice(&data).map_err(|_| ProgramError::InvalidAccountData)
+        let header = RootHeader::from_bytes(data)?;
+        let config: RootConfig = borsh::from_slice(&data[RootHeader::LEN..])
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let name = header
+            .name
+            .get(..usize::from(header.name_len))
+            .and_then(|name| core::str::from_utf8(name).ok())
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        Ok(Self {
+            creator: header.creator,
+            name: name.to_string(),
+            operators: config.operators,
+            operator_epochs: config.operator_epochs,
+            operators_epoch: header.operators_epoch,
+            last_id: header.last_id,
+            threshold: header.threshold,
+            allowed_programs: config.allowed_programs,
+            default_ttl: config.default_ttl,
+            timelock: header.timelock,
+            execute_policy: config.execute_policy,
+            bump: header.bump,
+        })
+    }
+}
+
+/// returns position of operator among operators, skipping removed operator slots
+fn operator_position(operators: &[Operator], key: &Address) -> Option<usize> {
+    operators
+        .iter()
+        .position(|operator| &operator.key == key && !operator.is_removed())
+}
+
+/// returns position of signer among operators, checking it was granted given permission
+fn permitted_operator_position(
+    operators: &[Operator],
+    signer: &Address,
+    permission: Permission,
+) -> Result<usize, ProgramError> {
+    let position = operator_position(operators, signer).ok_or(MosaicError::SignerIsNotOperator)?;
+    if !operators[position].has_permission(permission) {
+        return Err(MosaicError::OperatorLacksPermission.into());
+    }
+    Ok(position)
+}
+
+/// checks if default ttl, when set, gives sessions any time before expiring
+fn default_ttl_must_be_positive(default_ttl: &Option<Expiry>) -> Result<(), ProgramError> {
+    if default_ttl.is_some_and(|ttl| !ttl.is_positive()) {
+        return Err(MosaicError::DefaultTtlMustBePositive.into());
     }
+    Ok(())
+}
+
+/// returns summed weight of operators allowed to vote whose position is set among votes
+pub fn votes_weight(operators: &[Operator], votes: u64) -> u32 {
+    operators
+        .iter()
+        .enumerate()
+        .filter(|(position, operator)| {
+            votes & vote_bit(*position) != 0 && operator.has_permission(Permission::Vote)
+        })
+        .map(|(_, operator)| u32::from(operator.weight))
+        .sum()
 }
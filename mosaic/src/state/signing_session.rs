@@ -1,8 +1,16 @@
 use crate::{
-    errors::MosaicError, instructions::init_signing_session::InitializeSigningSessionIxData,
+    errors::MosaicError,
+    instructions::init_signing_session::InitializeSigningSessionIxData,
+    programs::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+    state::{
+        expiry::Expiry,
+        proposal::{Proposal, TokenTransfer},
+        root::{Operator, RootView, votes_weight},
+        zero_copy::ZeroCopy,
+    },
 };
 use borsh::{BorshDeserialize, BorshSerialize};
-use pinocchio::{Address, error::ProgramError};
+use pinocchio::{Address, cpi::MAX_CPI_ACCOUNTS, error::ProgramError, sysvars::clock::Clock};
 
 /// proposal phase
 #[derive(Clone, Copy, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -11,6 +19,8 @@ pub enum SigningSessionPhase {
     Active,
     Approved,
     Executed,
+    Rejected,
+    Cancelled,
 }
 
 impl From<u8> for SigningSessionPhase {
@@ -20,6 +30,8 @@ impl From<u8> for SigningSessionPhase {
             1 => SigningSessionPhase::Active,
             2 => SigningSessionPhase::Approved,
             3 => SigningSessionPhase::Executed,
+            4 => SigningSessionPhase::Rejected,
+            5 => SigningSessionPhase::Cancelled,
             _ => panic!("invalid account state value: {value}"),
         }
     }
@@ -32,6 +44,8 @@ impl From<SigningSessionPhase> for u8 {
             SigningSessionPhase::Active => 1,
             SigningSessionPhase::Approved => 2,
             SigningSessionPhase::Executed => 3,
+            SigningSessionPhase::Rejected => 4,
+            SigningSessionPhase::Cancelled => 5,
         }
     }
 }
@@ -53,12 +67,27 @@ impl InstructionAccount {
     }
 
     pub fn deserialize(data: &[u8]) -> Result<Self, ProgramError> {
-        borsh::from_slice(&data).map_err(|_| ProgramError::InvalidAccountData)
+        borsh::from_slice(data).map_err(|_| ProgramError::InvalidAccountData)
     }
 }
 
+/// single cpi carried out on execution
+#[derive(Clone, BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub struct SessionInstruction {
+    /// program invoked by cpi
+    pub program_id: Address,
+
+    /// instruction data passed to the program
+    pub data: Vec<u8>,
+
+    /// serialized `InstructionAccount`s of the instruction
+    pub accounts: Vec<Vec<u8>>,
+}
+
 /// signing session data
-#[derive(Clone, BorshDeserialize, BorshSerialize, Debug)]
+///
+/// stored as `SigningSessionHeader` followed by borsh encoded `SessionPayload`
+#[derive(Clone, Debug)]
 pub struct SigningSession {
     /// proposal id
     pub session_id: u16,
@@ -66,100 +95,512 @@ pub struct SigningSession {
     /// associated root pda // its not used for security checks but for account identification purposes
     pub root_pda: Address,
 
+    /// key of operator who created the session
+    pub proposer: Address,
+
+    /// key which paid rent for the session account, refunded on close
+    pub rent_payer: Address,
+
     /// current phase
     pub phase: SigningSessionPhase,
 
-    /// keys of operators who signed
-    pub approvals: Vec<Address>,
+    /// action carried out on execution
+    pub proposal: Proposal,
+
+    /// bitmap of root operator positions who signed
+    pub approvals: u64,
+
+    /// bitmap of root operator positions who rejected
+    pub rejections: u64,
+
+    /// root operators epoch vote bitmaps were last synced with
+    pub operators_epoch: u32,
+
+    /// moment after which session can't be signed nor executed
+    pub expires_at: Option<Expiry>,
+
+    /// unix timestamp of reaching approval threshold; timelock counts from it
+    pub approved_at: Option<i64>,
+
+    /// instructions to execute in order after consensus being reached
+    pub instructions: Vec<SessionInstruction>,
+
+    /// index of root vault signing session instructions alongside root pda
+    pub vault_index: u8,
+
+    /// cannonical bump of the vault signing on execution; 0 when only root pda signs
+    pub vault_bump: u8,
+
+    /// cannonical bump
+    pub bump: u8,
+}
+
+/// fixed part of signing session account data, read and written in place
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug)]
+pub struct SigningSessionHeader {
+    /// proposal id
+    pub session_id: u16,
+
+    /// associated root pda
+    pub root_pda: Address,
+
+    /// key of operator who created the session
+    pub proposer: Address,
+
+    /// key which paid rent for the session account, refunded on close
+    pub rent_payer: Address,
+
+    /// current `SigningSessionPhase`
+    pub phase: u8,
+
+    /// bitmap of root operator positions who signed
+    pub approvals: u64,
+
+    /// bitmap of root operator positions who rejected
+    pub rejections: u64,
+
+    /// root operators epoch vote bitmaps were last synced with
+    pub operators_epoch: u32,
+
+    /// 0 without expiry, 1 for `Expiry::Slot`, 2 for `Expiry::UnixTimestamp`
+    pub expiry_kind: u8,
+
+    /// slot or unix timestamp of expiry, per `expiry_kind`
+    pub expiry: u64,
+
+    /// 1 once approval threshold was reached
+    pub approved: u8,
+
+    /// unix timestamp of reaching approval threshold; timelock counts from it
+    pub approved_at: i64,
 
-    /// instruction data to execute after consensus being reached
-    pub instruction_data: Vec<u8>,
+    /// index of root vault signing session instructions alongside root pda
+    pub vault_index: u8,
 
-    /// instruction accounts to instruction_data
-    pub instruction_accounts: Vec<Vec<u8>>,
+    /// cannonical bump of the vault signing on execution; 0 when only root pda signs
+    pub vault_bump: u8,
 
     /// cannonical bump
     pub bump: u8,
 }
 
+unsafe impl ZeroCopy for SigningSessionHeader {}
+
+/// variable part of signing session account data following `SigningSessionHeader`
+#[derive(Clone, BorshDeserialize, BorshSerialize, Debug)]
+pub struct SessionPayload {
+    /// action carried out on execution
+    pub proposal: Proposal,
+
+    /// instructions to execute in order after consensus being reached
+    pub instructions: Vec<SessionInstruction>,
+}
+
+impl SessionPayload {
+    /// returns deserialized payload following the header in signing session account data
+    pub fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        data.get(SigningSessionHeader::LEN..)
+            .and_then(|payload| borsh::from_slice(payload).ok())
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+}
+
+/// returns bit of operator position within vote bitmaps
+pub fn vote_bit(position: usize) -> u64 {
+    1u64.checked_shl(position as u32).unwrap_or(0)
+}
+
+/// returns bits of operator slots which changed holder after given operators epoch
+pub fn stale_votes(operator_epochs: &[u32], operators_epoch: u32) -> u64 {
+    operator_epochs
+        .iter()
+        .enumerate()
+        .filter(|(_, epoch)| **epoch > operators_epoch)
+        .fold(0, |votes, (position, _)| votes | vote_bit(position))
+}
+
 impl SigningSession {
-    pub fn init(data: InitializeSigningSessionIxData, id: u16, root_pda: &Address) -> Self {
+    pub fn init(
+        data: InitializeSigningSessionIxData,
+        id: u16,
+        root_pda: &Address,
+        payer: &Address,
+        expires_at: Option<Expiry>,
+        operators_epoch: u32,
+    ) -> Self {
         Self {
             session_id: id,
             root_pda: *root_pda,
+            proposer: *payer,
+            rent_payer: *payer,
             phase: SigningSessionPhase::Active,
-            approvals: Vec::new(),
-            instruction_data: data.instruction_data,
-            instruction_accounts: data.instruction_accounts,
+            proposal: data.proposal,
+            approvals: 0,
+            rejections: 0,
+            operators_epoch,
+            expires_at,
+            approved_at: None,
+            instructions: data.instructions,
+            vault_index: data.vault_index,
+            vault_bump: 0,
             bump: data.bump,
         }
     }
 }
 
-impl SigningSession {
-    /// checks if amount of approvals reached expected threshold    
-    pub fn check_approvals_reaching_threshold(&self, threshold: usize) -> bool {
-        self.approvals.len() == threshold
+impl SigningSessionHeader {
+    /// returns current phase
+    pub fn phase(&self) -> SigningSessionPhase {
+        self.phase.into()
+    }
+
+    /// returns moment after which session can't be signed nor executed
+    pub fn expires_at(&self) -> Option<Expiry> {
+        match self.expiry_kind {
+            1 => Some(Expiry::Slot(self.expiry)),
+            2 => Some(Expiry::UnixTimestamp(self.expiry as i64)),
+            _ => None,
+        }
+    }
+
+    /// returns unix timestamp of reaching approval threshold
+    pub fn approved_at(&self) -> Option<i64> {
+        (self.approved != 0).then_some(self.approved_at)
+    }
+
+    /// drops votes of operator slots which changed holder since last sync with root
+    pub fn sync_votes(&mut self, root: &RootView) -> Result<(), ProgramError> {
+        let operators_epoch = root.header.operators_epoch;
+        if self.operators_epoch == operators_epoch {
+            return Ok(());
+        }
+        let stale = stale_votes(&root.operator_epochs()?, self.operators_epoch);
+        self.approvals &= !stale;
+        self.rejections &= !stale;
+        self.operators_epoch = operators_epoch;
+        Ok(())
+    }
+
+    /// checks if weight of approvals reached root threshold
+    pub fn check_approvals_reaching_threshold(
+        &self,
+        operators: &[Operator],
+        threshold: u16,
+    ) -> bool {
+        votes_weight(operators, self.approvals) >= u32::from(threshold)
+    }
+
+    /// checks if approvals of current operators still satisfy root threshold
+    pub fn approvals_must_reach_threshold(
+        &self,
+        operators: &[Operator],
+        threshold: u16,
+    ) -> Result<(), ProgramError> {
+        if !self.check_approvals_reaching_threshold(operators, threshold) {
+            return Err(MosaicError::ApprovalsDidNotReachThreshold.into());
+        }
+        Ok(())
+    }
+
+    /// checks if rejections of current operators make reaching root threshold impossible
+    pub fn check_rejections_preventing_threshold(
+        &self,
+        operators: &[Operator],
+        threshold: u16,
+    ) -> bool {
+        votes_weight(operators, u64::MAX).saturating_sub(votes_weight(operators, self.rejections))
+            < u32::from(threshold)
     }
 
     /// progress signing phase with overflow check
     pub fn progress_phase_checked(&mut self) -> Result<(), ProgramError> {
-        match self.phase {
-            SigningSessionPhase::Executed => {
+        match self.phase() {
+            SigningSessionPhase::Executed
+            | SigningSessionPhase::Rejected
+            | SigningSessionPhase::Cancelled => {
                 Err(MosaicError::SigningSessionPhaseAtFinalStage.into())
             }
             _ => {
-                let current_phase_value: u8 = self.phase.into();
-                self.phase = (current_phase_value + 1).into();
+                self.phase += 1;
                 Ok(())
             }
         }
     }
 
-    /// push signer approval with check if it already was casted
-    pub fn approve_checked(&mut self, signer: &Address) -> Result<(), ProgramError> {
-        if self.approvals.contains(signer) {
+    /// sets approval bit of operator position with check if vote was already casted
+    pub fn approve_checked(&mut self, position: usize) -> Result<(), ProgramError> {
+        self.vote_must_not_be_casted(position)?;
+        self.approvals |= vote_bit(position);
+
+        Ok(())
+    }
+
+    /// sets rejection bit of operator position with check if vote was already casted
+    pub fn reject_checked(&mut self, position: usize) -> Result<(), ProgramError> {
+        self.vote_must_not_be_casted(position)?;
+        self.rejections |= vote_bit(position);
+
+        Ok(())
+    }
+
+    /// clears approval bit of operator position with check if it was set
+    pub fn revoke_approval_checked(&mut self, position: usize) -> Result<(), ProgramError> {
+        if self.approvals & vote_bit(position) == 0 {
+            return Err(MosaicError::SigningSessionSignerHasNotApproved.into());
+        }
+        self.approvals &= !vote_bit(position);
+
+        Ok(())
+    }
+
+    /// checks if operator position neither approved nor rejected the session
+    fn vote_must_not_be_casted(&self, position: usize) -> Result<(), ProgramError> {
+        if self.approvals & vote_bit(position) != 0 {
             return Err(MosaicError::SigningSessionSignerAlreadyApproved.into());
         };
-        self.approvals.push(*signer);
+        if self.rejections & vote_bit(position) != 0 {
+            return Err(MosaicError::SigningSessionSignerAlreadyRejected.into());
+        };
+        Ok(())
+    }
 
+    /// marks session as rejected; approval became unreachable
+    pub fn reject(&mut self) {
+        self.phase = SigningSessionPhase::Rejected.into();
+    }
+
+    /// moves session back to active phase, e.g. after approvals dropped below threshold
+    pub fn reactivate(&mut self) {
+        self.phase = SigningSessionPhase::Active.into();
+        self.approved = 0;
+        self.approved_at = 0;
+    }
+
+    /// stores the moment session got approved
+    pub fn record_approval_time(&mut self, clock: &Clock) {
+        self.approved = 1;
+        self.approved_at = clock.unix_timestamp;
+    }
+
+    /// returns unix timestamp from which session can be executed under given timelock
+    pub fn earliest_execution_time(&self, timelock: u32) -> Option<i64> {
+        self.approved_at()
+            .map(|approved_at| approved_at.saturating_add(timelock.into()))
+    }
+
+    /// checks if timelock elapsed since approval
+    pub fn timelock_must_elapse(&self, timelock: u32, clock: &Clock) -> Result<(), ProgramError> {
+        if timelock == 0 {
+            return Ok(());
+        }
+        match self.earliest_execution_time(timelock) {
+            Some(earliest) if clock.unix_timestamp >= earliest => Ok(()),
+            _ => Err(MosaicError::TimelockNotElapsed.into()),
+        }
+    }
+
+    /// marks session as cancelled by proposer
+    pub fn cancel(&mut self) {
+        self.phase = SigningSessionPhase::Cancelled.into();
+    }
+
+    /// checks if signer is the one who created the session
+    pub fn signer_must_be_proposer(&self, signer: &Address) -> Result<(), ProgramError> {
+        if &self.proposer != signer {
+            return Err(MosaicError::SignerIsNotProposer.into());
+        }
+        Ok(())
+    }
+
+    /// checks if no current operator approval weighs in; removed operators' approvals don't count
+    pub fn must_have_no_approvals(&self, operators: &[Operator]) -> Result<(), ProgramError> {
+        if votes_weight(operators, self.approvals) != 0 {
+            return Err(MosaicError::SigningSessionHasApprovals.into());
+        }
+        Ok(())
+    }
+
+    /// checks if signing session reached a stage it can't progress from or expired
+    pub fn must_be_closable(&self, clock: &Clock) -> Result<(), ProgramError> {
+        match self.phase() {
+            SigningSessionPhase::Executed
+            | SigningSessionPhase::Rejected
+            | SigningSessionPhase::Cancelled => Ok(()),
+            _ if self.is_expired(clock) => Ok(()),
+            _ => Err(MosaicError::SigningSessionNotClosable.into()),
+        }
+    }
+
+    /// checks if session expiry was reached
+    pub fn is_expired(&self, clock: &Clock) -> bool {
+        self.expires_at()
+            .is_some_and(|expires_at| expires_at.is_reached(clock))
+    }
+
+    /// checks if session expiry wasn't reached yet
+    pub fn must_not_be_expired(&self, clock: &Clock) -> Result<(), ProgramError> {
+        if self.is_expired(clock) {
+            return Err(MosaicError::SigningSessionExpired.into());
+        }
+        Ok(())
+    }
+
+    /// checks if provided account is the one who paid rent for the session
+    pub fn rent_payer_must_match(&self, rent_payer: &Address) -> Result<(), ProgramError> {
+        if &self.rent_payer != rent_payer {
+            return Err(MosaicError::RentPayerMismatch.into());
+        }
         Ok(())
     }
 
     /// checks if signing session is active
     pub fn must_be_active(&self) -> Result<(), ProgramError> {
-        if self.phase != SigningSessionPhase::Active {
+        if self.phase() != SigningSessionPhase::Active {
             return Err(MosaicError::SigningSessionPhaseIncorrect.into());
         }
         Ok(())
     }
 
+    /// checks if signing session is active or approved but not yet executed
+    pub fn must_be_active_or_approved(&self) -> Result<(), ProgramError> {
+        match self.phase() {
+            SigningSessionPhase::Active | SigningSessionPhase::Approved => Ok(()),
+            _ => Err(MosaicError::SigningSessionPhaseIncorrect.into()),
+        }
+    }
+
     /// checks if signing session is approved
     pub fn must_be_approved(&self) -> Result<(), ProgramError> {
-        if self.phase != SigningSessionPhase::Approved {
+        if self.phase() != SigningSessionPhase::Approved {
             return Err(MosaicError::SigningSessionPhaseIncorrect.into());
         }
         Ok(())
     }
+}
+
+impl SigningSession {
+    /// checks if only cpi proposal carries instructions to execute
+    pub fn instructions_must_match_proposal(&self) -> Result<(), ProgramError> {
+        match (&self.proposal, self.instructions.is_empty()) {
+            (Proposal::Cpi, true) => Err(MosaicError::SessionInstructionsMissing.into()),
+            (
+                Proposal::Config(_)
+                | Proposal::Transfer { .. }
+                | Proposal::TokenTransfer(_)
+                | Proposal::Loader { .. },
+                false,
+            ) => Err(MosaicError::SessionInstructionsUnexpected.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// checks if transfer proposal moves any lamports or tokens
+    pub fn transfer_amount_must_not_be_zero(&self) -> Result<(), ProgramError> {
+        if let Proposal::Transfer { amount: 0, .. }
+        | Proposal::TokenTransfer(TokenTransfer { amount: 0, .. }) = self.proposal
+        {
+            return Err(MosaicError::TransferAmountMustNotBeZero.into());
+        }
+        Ok(())
+    }
 
-    /// checks if root last id equals the session id
-    pub fn sessions_must_equal(&self, root_last_id: u16) -> Result<(), ProgramError> {
-        if self.session_id != root_last_id {
-            return Err(MosaicError::SigningSessionIdMustEqualRootLastId.into());
+    /// checks if token transfer proposal targets token or token-2022 program
+    pub fn token_program_must_be_supported(&self) -> Result<(), ProgramError> {
+        if let Proposal::TokenTransfer(transfer) = &self.proposal
+            && transfer.token_program != TOKEN_PROGRAM_ID
+            && transfer.token_program != TOKEN_2022_PROGRAM_ID
+        {
+            return Err(MosaicError::TokenProgramNotSupported.into());
+        }
+        Ok(())
+    }
+
+    /// checks if every session instruction fits into runtime cpi account limit
+    pub fn instruction_accounts_must_fit_cpi(&self) -> Result<(), ProgramError> {
+        if self
+            .instructions
+            .iter()
+            .any(|instruction| instruction.accounts.len() > MAX_CPI_ACCOUNTS)
+        {
+            return Err(MosaicError::SessionInstructionTooManyAccounts.into());
         }
         Ok(())
     }
 }
 
 impl SigningSession {
+    /// returns index of the vault signing on execution; none when only root pda signs
+    pub fn signing_vault_index(&self) -> Option<u8> {
+        match &self.proposal {
+            Proposal::Cpi => Some(self.vault_index),
+            Proposal::Config(_) => None,
+            Proposal::Transfer { vault_index, .. } => Some(*vault_index),
+            Proposal::TokenTransfer(transfer) => transfer.vault_index,
+            Proposal::Loader { vault_index, .. } => *vault_index,
+        }
+    }
+
+    /// returns fixed part of signing session data
+    pub fn header(&self) -> SigningSessionHeader {
+        let (expiry_kind, expiry) = match self.expires_at {
+            None => (0, 0),
+            Some(Expiry::Slot(slot)) => (1, slot),
+            Some(Expiry::UnixTimestamp(timestamp)) => (2, timestamp as u64),
+        };
+        SigningSessionHeader {
+            session_id: self.session_id,
+            root_pda: self.root_pda,
+            proposer: self.proposer,
+            rent_payer: self.rent_payer,
+            phase: self.phase.into(),
+            approvals: self.approvals,
+            rejections: self.rejections,
+            operators_epoch: self.operators_epoch,
+            expiry_kind,
+            expiry,
+            approved: self.approved_at.is_some().into(),
+            approved_at: self.approved_at.unwrap_or_default(),
+            vault_index: self.vault_index,
+            vault_bump: self.vault_bump,
+            bump: self.bump,
+        }
+    }
+
     /// returns serialized data with length
     pub fn serialize(&self) -> Result<(Vec<u8>, usize), ProgramError> {
-        let data = borsh::to_vec(&self).map_err(|_| ProgramError::InvalidAccountData)?;
+        let mut data = self.header().as_bytes().to_vec();
+        // fields in `SessionPayload` order
+        (&self.proposal, &self.instructions)
+            .serialize(&mut data)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
         let size = data.len();
         Ok((data, size))
     }
 
     pub fn deserialize(data: &[u8]) -> Result<Self, ProgramError> {
-        borsh::from_slice(&data).map_err(|_| ProgramError::InvalidAccountData)
+        let header = SigningSessionHeader::from_bytes(data)?;
+        let payload = SessionPayload::from_bytes(data)?;
+        if header.phase > SigningSessionPhase::Cancelled.into() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            session_id: header.session_id,
+            root_pda: header.root_pda,
+            proposer: header.proposer,
+            rent_payer: header.rent_payer,
+            phase: header.phase(),
+            proposal: payload.proposal,
+            approvals: header.approvals,
+            rejections: header.rejections,
+            operators_epoch: header.operators_epoch,
+            expires_at: header.expires_at(),
+            approved_at: header.approved_at(),
+            instructions: payload.instructions,
+            vault_index: header.vault_index,
+            vault_bump: header.vault_bump,
+            bump: header.bump,
+        })
     }
 }
@@ -0,0 +1,62 @@
+use core::{mem::size_of, slice};
+
+use pinocchio::{Address, error::ProgramError};
+
+/// fixed layout type read and written in place within account data
+///
+/// # Safety
+///
+/// implementors must have alignment of one (`repr(C, packed)` or byte fields only), no padding
+/// and be valid for any bit pattern
+pub unsafe trait ZeroCopy: Sized {
+    /// size of the layout in bytes
+    const LEN: usize = size_of::<Self>();
+
+    /// returns view of layout at the start of data
+    fn from_bytes(data: &[u8]) -> Result<&Self, ProgramError> {
+        if data.len() < Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // SAFETY: length checked; alignment of one and any bit pattern guaranteed by implementor
+        Ok(unsafe { &*(data.as_ptr() as *const Self) })
+    }
+
+    /// returns mutable view of layout at the start of data
+    fn from_bytes_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if data.len() < Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // SAFETY: length checked; alignment of one and any bit pattern guaranteed by implementor
+        Ok(unsafe { &mut *(data.as_mut_ptr() as *mut Self) })
+    }
+
+    /// returns view of `len` consecutive layouts at the start of data
+    fn slice_from_bytes(data: &[u8], len: usize) -> Result<&[Self], ProgramError> {
+        let size = len
+            .checked_mul(Self::LEN)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        if data.len() < size {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // SAFETY: length checked; alignment of one and any bit pattern guaranteed by implementor
+        Ok(unsafe { slice::from_raw_parts(data.as_ptr() as *const Self, len) })
+    }
+
+    /// returns view of borsh encoded `Vec` at the start of data along with bytes following it
+    fn vec_from_bytes(data: &[u8]) -> Result<(&[Self], &[u8]), ProgramError> {
+        let (len, data) = data
+            .split_first_chunk::<4>()
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let len = u32::from_le_bytes(*len) as usize;
+        let items = Self::slice_from_bytes(data, len)?;
+        Ok((items, &data[len * Self::LEN..]))
+    }
+
+    /// returns raw bytes of the layout
+    fn as_bytes(&self) -> &[u8] {
+        // SAFETY: implementor has no padding, so every byte is initialized
+        unsafe { slice::from_raw_parts(self as *const Self as *const u8, Self::LEN) }
+    }
+}
+
+unsafe impl ZeroCopy for Address {}
@@ -0,0 +1,205 @@
+mod common;
+
+use {
+    borsh::to_vec,
+    common::*,
+    mollusk_svm::{Mollusk, result::Check},
+};
+
+use mosaic::{
+    events::Event,
+    instructions::{Instruction as ProgramIx, cancel::CancelIxData},
+    state::{
+        proposal::Proposal,
+        root::Operator,
+        signing_session::{SigningSession, SigningSessionPhase},
+    },
+};
+
+use solana_sdk::instruction::{AccountMeta, Instruction};
+
+#[test]
+fn test_cancel() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_votes(
+            &mollusk,
+            session_id,
+            root_pda,
+            signer,
+            0,                                                   // approvals
+            votes_of(&operators_pubkey, &[operators_pubkey[1]]), // rejections
+            SigningSessionPhase::Active,
+            Proposal::Cpi,
+            vec![],
+            vec![],
+        );
+
+    let logs = collect_logs(&mut mollusk);
+
+    // cancel
+    let ix_data_cancel = CancelIxData {
+        bump: signing_pda_bump,
+    };
+    let data_cancel = [
+        vec![ProgramIx::Cancel as u8],
+        to_vec(&ix_data_cancel).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_cancel,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    // rejections do not prevent the proposer from cancelling
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Cancelled);
+    assert!(parsed_signing_session_pda_data.proposer == signer);
+    assert!(
+        emitted_events(&logs)
+            == vec![Event::SessionCancelled {
+                root: root_pda,
+                session_id,
+                proposer: signer,
+            }]
+    );
+}
+
+#[test]
+fn test_cancel_with_removed_operator_approval() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        mut root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // operator was removed after approving; its slot is left as a tombstone
+    root_pda_init_state.operators[1] = Operator::REMOVED;
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_votes(
+            &mollusk,
+            session_id,
+            root_pda,
+            signer,
+            votes_of(&operators_pubkey, &[operators_pubkey[1]]), // approvals
+            0,                                                   // rejections
+            SigningSessionPhase::Active,
+            Proposal::Cpi,
+            vec![],
+            vec![],
+        );
+
+    // cancel
+    let ix_data_cancel = CancelIxData {
+        bump: signing_pda_bump,
+    };
+    let data_cancel = [
+        vec![ProgramIx::Cancel as u8],
+        to_vec(&ix_data_cancel).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_cancel,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.into()),
+            (root_pda, root_account.into()),
+            (signing_pda, signing_account.into()),
+        ],
+        &[Check::success()],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    // approval of removed operator carries no weight, so it can't block cancellation
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Cancelled);
+}
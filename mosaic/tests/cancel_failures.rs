@@ -0,0 +1,170 @@
+mod common;
+
+use {
+    borsh::to_vec,
+    common::*,
+    mollusk_svm::{Mollusk, result::Check},
+};
+
+use mosaic::{
+    errors::MosaicError,
+    instructions::{Instruction as ProgramIx, cancel::CancelIxData},
+    state::{
+        proposal::Proposal,
+        signing_session::{SigningSessionPhase, vote_bit},
+    },
+};
+
+use solana_sdk::{
+    account::AccountSharedData,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// runs cancel by operator at `signer` index (outsider when `None`) against a session
+/// proposed by operator at `proposer` index in given phase, with approvals given as operator indexes
+fn process_cancel(
+    signer: Option<usize>,
+    proposer: usize,
+    approvals: &[usize],
+    phase: SigningSessionPhase,
+    error: MosaicError,
+) {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = match signer {
+        Some(index) => operators.operators[index].clone(),
+        None => (
+            Pubkey::new_unique(),
+            AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program),
+        ),
+    };
+    let approvals = approvals.iter().fold(0, |votes, i| votes | vote_bit(*i));
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_votes(
+            &mollusk,
+            session_id,
+            root_pda,
+            operators_pubkey[proposer],
+            approvals,
+            0,
+            phase,
+            Proposal::Cpi,
+            vec![],
+            vec![],
+        );
+
+    // cancel
+    let ix_data_cancel = CancelIxData {
+        bump: signing_pda_bump,
+    };
+    let data_cancel = [
+        vec![ProgramIx::Cancel as u8],
+        to_vec(&ix_data_cancel).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_cancel,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.into()),
+            (root_pda, root_account.into()),
+            (signing_pda, signing_account.into()),
+        ],
+        &[Check::err(ProgramError::Custom(error as u32))],
+    );
+}
+
+#[test]
+fn test_cancel_signer_is_not_proposer_failure() {
+    process_cancel(
+        Some(0),
+        1,
+        &[],
+        SigningSessionPhase::Active,
+        MosaicError::SignerIsNotProposer,
+    );
+}
+
+#[test]
+fn test_cancel_outsider_failure() {
+    process_cancel(
+        None,
+        0,
+        &[],
+        SigningSessionPhase::Active,
+        MosaicError::SignerIsNotProposer,
+    );
+}
+
+#[test]
+fn test_cancel_session_with_approvals_failure() {
+    process_cancel(
+        Some(0),
+        0,
+        &[1],
+        SigningSessionPhase::Active,
+        MosaicError::SigningSessionHasApprovals,
+    );
+}
+
+#[test]
+fn test_cancel_approved_session_failure() {
+    process_cancel(
+        Some(0),
+        0,
+        &[1, 2],
+        SigningSessionPhase::Approved,
+        MosaicError::SigningSessionPhaseIncorrect,
+    );
+}
+
+#[test]
+fn test_cancel_cancelled_session_failure() {
+    process_cancel(
+        Some(0),
+        0,
+        &[],
+        SigningSessionPhase::Cancelled,
+        MosaicError::SigningSessionPhaseIncorrect,
+    );
+}
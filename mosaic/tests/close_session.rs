@@ -0,0 +1,165 @@
+mod common;
+
+use {
+    borsh::to_vec,
+    common::*,
+    mollusk_svm::{Mollusk, result::Check},
+};
+
+use mosaic::{
+    events::Event,
+    instructions::{Instruction as ProgramIx, close_session::CloseSessionIxData},
+    state::{
+        expiry::Expiry,
+        proposal::Proposal,
+        signing_session::{SigningSession, SigningSessionPhase},
+    },
+};
+
+use solana_sdk::{
+    account::ReadableAccount,
+    instruction::{AccountMeta, Instruction},
+};
+
+/// closes session in given phase and checks rent being refunded to the proposer
+fn close_session_in_phase(phase: SigningSessionPhase) {
+    close_session(phase, None);
+}
+
+/// closes session in given phase, optionally expired at slot 10, and checks rent refund
+fn close_session(phase: SigningSessionPhase, expires_at: Option<Expiry>) {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (proposer, proposer_account) = operators.operators[0].clone();
+    let (closer, closer_account) = operators.operators[1].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, signing_pda_bump, signing_init_state_serialized, _signing_account) =
+        prepare_signing_session_with_votes(
+            &mollusk,
+            session_id,
+            root_pda,
+            proposer,
+            0, // approvals
+            0, // rejections
+            phase,
+            Proposal::Cpi,
+            vec![],
+            vec![],
+        );
+
+    let mut signing_init_state =
+        SigningSession::deserialize(&signing_init_state_serialized).unwrap();
+    signing_init_state.expires_at = expires_at;
+    let signing_account = signing_session_account_from_state(&mollusk, &signing_init_state);
+    mollusk.warp_to_slot(10);
+    let logs = collect_logs(&mut mollusk);
+
+    // close
+    let ix_data_close = CloseSessionIxData {
+        bump: signing_pda_bump,
+    };
+    let data_close = [
+        vec![ProgramIx::CloseSession as u8],
+        to_vec(&ix_data_close).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_close,
+        vec![
+            AccountMeta::new(closer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new(proposer, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (closer, closer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (proposer, proposer_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).closed().build(),
+            Check::account(&proposer)
+                .lamports(proposer_account.lamports() + signing_account.lamports())
+                .build(),
+            Check::account(&closer)
+                .lamports(closer_account.lamports())
+                .build(),
+        ],
+    );
+
+    let closed_signing_session_account = result.get_account(&signing_pda).unwrap();
+    assert!(
+        closed_signing_session_account
+            .data
+            .iter()
+            .all(|byte| *byte == 0)
+    );
+    assert!(
+        emitted_events(&logs)
+            == vec![Event::SessionClosed {
+                root: root_pda,
+                session_id,
+                rent_payer: proposer,
+                refund: signing_account.lamports(),
+            }]
+    );
+}
+
+#[test]
+fn test_close_executed_session() {
+    close_session_in_phase(SigningSessionPhase::Executed);
+}
+
+#[test]
+fn test_close_rejected_session() {
+    close_session_in_phase(SigningSessionPhase::Rejected);
+}
+
+#[test]
+fn test_close_cancelled_session() {
+    close_session_in_phase(SigningSessionPhase::Cancelled);
+}
+
+#[test]
+fn test_close_expired_active_session() {
+    close_session(SigningSessionPhase::Active, Some(Expiry::Slot(10)));
+}
+
+#[test]
+fn test_close_expired_approved_session() {
+    close_session(SigningSessionPhase::Approved, Some(Expiry::Slot(10)));
+}
@@ -0,0 +1,217 @@
+mod common;
+
+use {
+    borsh::to_vec,
+    common::*,
+    mollusk_svm::{Mollusk, result::Check},
+};
+
+use mosaic::{
+    errors::MosaicError,
+    instructions::{Instruction as ProgramIx, close_session::CloseSessionIxData},
+    state::{
+        expiry::Expiry,
+        proposal::Proposal,
+        signing_session::{SigningSession, SigningSessionPhase},
+    },
+};
+
+use solana_sdk::{
+    account::AccountSharedData,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// describes how close session instruction is built in a failure case
+struct CloseSessionCase {
+    phase: SigningSessionPhase,
+    closer_is_signer: bool,
+    rent_payer_is_proposer: bool,
+    rent_payer_is_writable: bool,
+    expires_at: Option<Expiry>,
+}
+
+impl Default for CloseSessionCase {
+    fn default() -> Self {
+        Self {
+            phase: SigningSessionPhase::Executed,
+            closer_is_signer: true,
+            rent_payer_is_proposer: true,
+            rent_payer_is_writable: true,
+            expires_at: None,
+        }
+    }
+}
+
+/// runs close session for given case and expects it to fail with given error
+fn process_close_session(case: CloseSessionCase, error: ProgramError) {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (proposer, proposer_account) = operators.operators[0].clone();
+    let (closer, closer_account) = operators.operators[1].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, signing_pda_bump, signing_init_state_serialized, _signing_account) =
+        prepare_signing_session_with_votes(
+            &mollusk,
+            session_id,
+            root_pda,
+            proposer,
+            0, // approvals
+            0, // rejections
+            case.phase,
+            Proposal::Cpi,
+            vec![],
+            vec![],
+        );
+
+    let mut signing_init_state =
+        SigningSession::deserialize(&signing_init_state_serialized).unwrap();
+    signing_init_state.expires_at = case.expires_at;
+    let signing_account = signing_session_account_from_state(&mollusk, &signing_init_state);
+    mollusk.warp_to_slot(10);
+
+    let (rent_payer, rent_payer_account) = if case.rent_payer_is_proposer {
+        (proposer, proposer_account)
+    } else {
+        (
+            Pubkey::new_unique(),
+            AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program),
+        )
+    };
+
+    // close
+    let ix_data_close = CloseSessionIxData {
+        bump: signing_pda_bump,
+    };
+    let data_close = [
+        vec![ProgramIx::CloseSession as u8],
+        to_vec(&ix_data_close).unwrap(),
+    ]
+    .concat();
+
+    let rent_payer_meta = if case.rent_payer_is_writable {
+        AccountMeta::new(rent_payer, false)
+    } else {
+        AccountMeta::new_readonly(rent_payer, false)
+    };
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_close,
+        vec![
+            AccountMeta::new(closer, case.closer_is_signer),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            rent_payer_meta,
+        ],
+    );
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (closer, closer_account.into()),
+            (root_pda, root_account.into()),
+            (signing_pda, signing_account.into()),
+            (rent_payer, rent_payer_account.into()),
+        ],
+        &[
+            Check::err(error),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+}
+
+#[test]
+fn test_close_session_active_failure() {
+    process_close_session(
+        CloseSessionCase {
+            phase: SigningSessionPhase::Active,
+            ..Default::default()
+        },
+        MosaicError::SigningSessionNotClosable.into(),
+    );
+}
+
+#[test]
+fn test_close_session_approved_failure() {
+    process_close_session(
+        CloseSessionCase {
+            phase: SigningSessionPhase::Approved,
+            ..Default::default()
+        },
+        MosaicError::SigningSessionNotClosable.into(),
+    );
+}
+
+#[test]
+fn test_close_session_not_yet_expired_failure() {
+    process_close_session(
+        CloseSessionCase {
+            phase: SigningSessionPhase::Active,
+            expires_at: Some(Expiry::Slot(11)),
+            ..Default::default()
+        },
+        MosaicError::SigningSessionNotClosable.into(),
+    );
+}
+
+#[test]
+fn test_close_session_rent_payer_mismatch_failure() {
+    process_close_session(
+        CloseSessionCase {
+            rent_payer_is_proposer: false,
+            ..Default::default()
+        },
+        MosaicError::RentPayerMismatch.into(),
+    );
+}
+
+#[test]
+fn test_close_session_rent_payer_not_writable_failure() {
+    process_close_session(
+        CloseSessionCase {
+            rent_payer_is_writable: false,
+            ..Default::default()
+        },
+        MosaicError::RentPayerAccountMustBeWritable.into(),
+    );
+}
+
+#[test]
+fn test_close_session_closer_is_not_signer_failure() {
+    process_close_session(
+        CloseSessionCase {
+            closer_is_signer: false,
+            ..Default::default()
+        },
+        MosaicError::PayerMustEqualSigner.into(),
+    );
+}
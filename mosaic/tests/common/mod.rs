@@ -1,17 +1,29 @@
 #![allow(dead_code)]
 
+use std::{cell::RefCell, rc::Rc};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
 use mollusk_svm::Mollusk;
 
 use mosaic::{
     ID,
-    seeds::{ROOT_PDA, SIGNING_SESSION_PDA},
+    events::{EVENT_TAG, EVENT_VERSION, Event},
+    seeds::{ROOT_PDA, SIGNING_SESSION_PDA, VAULT_PDA},
     state::{
-        root::Root,
-        signing_session::{InstructionAccount, SigningSession, SigningSessionPhase},
+        proposal::Proposal,
+        root::{ExecutePolicy, Operator, Permission, Root},
+        signing_session::{
+            InstructionAccount, SessionInstruction, SigningSession, SigningSessionPhase, vote_bit,
+        },
     },
 };
 
-use solana_sdk::{account::AccountSharedData, native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+use solana_sdk::{
+    account::{AccountSharedData, WritableAccount},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+};
+use solana_svm_log_collector::LogCollector;
 
 pub const PROGRAM_ID: Pubkey = Pubkey::new_from_array(ID);
 pub const DESTINATION_PROGRAM_ID: Pubkey =
@@ -19,13 +31,16 @@ pub const DESTINATION_PROGRAM_ID: Pubkey =
 pub const _DEFAULT_LOADER_KEY: Pubkey =
     solana_sdk::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
 
+pub const ROOT_CREATOR: Pubkey = Pubkey::new_from_array([7; 32]);
+pub const ROOT_NAME: &str = "mosaic";
+
 pub const MOSAIC_BINARY_PATH: &str = "./target/deploy/mosaic";
 pub const EXAMPLE_BINARY_PATH: &str = "./target/deploy/mosaic";
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Operators {
     pub operators: Vec<(Pubkey, AccountSharedData)>,
-    pub threshold: u8,
+    pub threshold: u16,
 }
 
 impl Operators {
@@ -35,10 +50,10 @@ impl Operators {
         for _ in 0..num {
             operators.push((
                 Pubkey::new_unique(),
-                AccountSharedData::new(1 * LAMPORTS_PER_SOL, 0, &owner),
+                AccountSharedData::new(LAMPORTS_PER_SOL, 0, &owner),
             ));
         }
-        let threshold = (operators.len() as u8 * 2) / 3;
+        let threshold = (operators.len() as u16 * 2) / 3;
         Self {
             operators,
             threshold,
@@ -46,6 +61,44 @@ impl Operators {
     }
 }
 
+/// returns operators of given keys, each with weight of one and every permission
+pub fn equal_weight_operators(keys: &[Pubkey]) -> Vec<Operator> {
+    keys.iter()
+        .map(|key| Operator {
+            key: *key,
+            weight: 1,
+            permissions: Permission::ALL,
+        })
+        .collect()
+}
+
+/// returns vote bitmap with positions of given voters among operator keys set
+pub fn votes_of(operators: &[Pubkey], voters: &[Pubkey]) -> u64 {
+    operators
+        .iter()
+        .enumerate()
+        .filter(|(_, operator)| voters.contains(operator))
+        .fold(0, |votes, (position, _)| votes | vote_bit(position))
+}
+
+/// checks if position of voter among operator keys is set in vote bitmap
+pub fn has_voted(votes: u64, operators: &[Pubkey], voter: &Pubkey) -> bool {
+    operators
+        .iter()
+        .position(|operator| operator == voter)
+        .is_some_and(|position| votes & vote_bit(position) != 0)
+}
+
+/// finds root pda namespaced by creator and name
+pub fn find_root_pda(creator: &Pubkey, name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ROOT_PDA, creator.as_ref(), name.as_bytes()], &PROGRAM_ID)
+}
+
+/// finds system owned vault pda of the root at given index
+pub fn find_vault_pda(root_pda: &Pubkey, vault_index: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_PDA, root_pda.as_ref(), &[vault_index]], &PROGRAM_ID)
+}
+
 /// prepares fixture with state account for tests of arbitrary accounts execution
 pub fn prepare_state_for_arbitrary(
     mollusk: &Mollusk,
@@ -53,16 +106,22 @@ pub fn prepare_state_for_arbitrary(
     operators_pubkey: Vec<Pubkey>,
     session_id: u16,
 ) -> (Pubkey, u8, Root, Vec<u8>, AccountSharedData) {
-    let (root_pda, root_pda_bump) =
-        solana_sdk::pubkey::Pubkey::find_program_address(&[ROOT_PDA], &PROGRAM_ID);
+    let (root_pda, root_pda_bump) = find_root_pda(&ROOT_CREATOR, ROOT_NAME);
     let root_pda_init_state = Root {
-        operators: operators_pubkey,
+        creator: ROOT_CREATOR,
+        name: ROOT_NAME.to_string(),
+        operators: equal_weight_operators(&operators_pubkey),
+        operator_epochs: vec![0; operators_pubkey.len()],
+        operators_epoch: 0,
         last_id: session_id,
-        destination_program: DESTINATION_PROGRAM_ID,
+        allowed_programs: vec![DESTINATION_PROGRAM_ID],
         threshold: operators.threshold,
+        default_ttl: None,
+        timelock: 0,
+        execute_policy: ExecutePolicy::Operators,
         bump: root_pda_bump,
     };
-    let root_pda_initial_state_serialized = borsh::to_vec(&root_pda_init_state).unwrap();
+    let root_pda_initial_state_serialized = root_pda_init_state.serialize().unwrap().0;
     let root_pda_size = root_pda_initial_state_serialized.len();
     let root_pda_rent = mollusk.sysvars.rent.minimum_balance(root_pda_size);
     let mut root_account = AccountSharedData::new(root_pda_rent, root_pda_size, &PROGRAM_ID);
@@ -85,16 +144,22 @@ pub fn prepare_root(
     session_id: u16,
     destination_program: Pubkey,
 ) -> (Pubkey, u8, Root, Vec<u8>, AccountSharedData) {
-    let (root_pda, root_pda_bump) =
-        solana_sdk::pubkey::Pubkey::find_program_address(&[ROOT_PDA], &PROGRAM_ID);
+    let (root_pda, root_pda_bump) = find_root_pda(&ROOT_CREATOR, ROOT_NAME);
     let root_pda_init_state = Root {
-        operators: operators_pubkey,
+        creator: ROOT_CREATOR,
+        name: ROOT_NAME.to_string(),
+        operators: equal_weight_operators(&operators_pubkey),
+        operator_epochs: vec![0; operators_pubkey.len()],
+        operators_epoch: 0,
         last_id: session_id,
-        destination_program,
+        allowed_programs: vec![destination_program],
         threshold: operators.threshold,
+        default_ttl: None,
+        timelock: 0,
+        execute_policy: ExecutePolicy::Operators,
         bump: root_pda_bump,
     };
-    let root_pda_initial_state_serialized = borsh::to_vec(&root_pda_init_state).unwrap();
+    let root_pda_initial_state_serialized = root_pda_init_state.serialize().unwrap().0;
     let root_pda_size = root_pda_initial_state_serialized.len();
     let root_pda_rent = mollusk.sysvars.rent.minimum_balance(root_pda_size);
     let mut root_account = AccountSharedData::new(root_pda_rent, root_pda_size, &PROGRAM_ID);
@@ -109,13 +174,90 @@ pub fn prepare_root(
     )
 }
 
+/// builds rent exempt root account holding given state
+pub fn root_account_from_state(mollusk: &Mollusk, root: &Root) -> AccountSharedData {
+    let root_serialized = root.serialize().unwrap().0;
+    let root_rent = mollusk.sysvars.rent.minimum_balance(root_serialized.len());
+    let mut root_account = AccountSharedData::new(root_rent, root_serialized.len(), &PROGRAM_ID);
+    root_account.set_data_from_slice(&root_serialized);
+    root_account
+}
+
+/// builds rent exempt signing session account holding given state
+pub fn signing_session_account_from_state(
+    mollusk: &Mollusk,
+    signing: &SigningSession,
+) -> AccountSharedData {
+    let signing_serialized = signing.serialize().unwrap().0;
+    let signing_rent = mollusk
+        .sysvars
+        .rent
+        .minimum_balance(signing_serialized.len());
+    let mut signing_account =
+        AccountSharedData::new(signing_rent, signing_serialized.len(), &PROGRAM_ID);
+    signing_account.set_data_from_slice(&signing_serialized);
+    signing_account
+}
+
 /// prepares fixture with signing session account state for tests
 pub fn prepare_signing_session(
     mollusk: &Mollusk,
     session_id: u16,
     root_pda: Pubkey,
-    approvals: Vec<Pubkey>,
+    approvals: u64,
+    phase: SigningSessionPhase,
+    cpi_instruction_accounts: Vec<Vec<u8>>,
+    cpi_instruction_data: Vec<u8>,
+) -> (Pubkey, u8, Vec<u8>, AccountSharedData) {
+    prepare_signing_session_with_proposal(
+        mollusk,
+        session_id,
+        root_pda,
+        approvals,
+        phase,
+        Proposal::Cpi,
+        cpi_instruction_accounts,
+        cpi_instruction_data,
+    )
+}
+
+/// prepares fixture with signing session account state carrying given proposal
+#[allow(clippy::too_many_arguments)]
+pub fn prepare_signing_session_with_proposal(
+    mollusk: &Mollusk,
+    session_id: u16,
+    root_pda: Pubkey,
+    approvals: u64,
+    phase: SigningSessionPhase,
+    proposal: Proposal,
+    cpi_instruction_accounts: Vec<Vec<u8>>,
+    cpi_instruction_data: Vec<u8>,
+) -> (Pubkey, u8, Vec<u8>, AccountSharedData) {
+    prepare_signing_session_with_votes(
+        mollusk,
+        session_id,
+        root_pda,
+        Pubkey::default(),
+        approvals,
+        0,
+        phase,
+        proposal,
+        cpi_instruction_accounts,
+        cpi_instruction_data,
+    )
+}
+
+/// prepares fixture with signing session account state carrying proposer and casted votes
+#[allow(clippy::too_many_arguments)]
+pub fn prepare_signing_session_with_votes(
+    mollusk: &Mollusk,
+    session_id: u16,
+    root_pda: Pubkey,
+    proposer: Pubkey,
+    approvals: u64,
+    rejections: u64,
     phase: SigningSessionPhase,
+    proposal: Proposal,
     cpi_instruction_accounts: Vec<Vec<u8>>,
     cpi_instruction_data: Vec<u8>,
 ) -> (Pubkey, u8, Vec<u8>, AccountSharedData) {
@@ -127,16 +269,27 @@ pub fn prepare_signing_session(
         ],
         &PROGRAM_ID,
     );
-    let signing_init_state = SigningSession {
+    let mut signing_init_state = SigningSession {
         session_id,
         root_pda,
+        proposer,
+        rent_payer: proposer,
         phase,
+        proposal,
         approvals,
-        instruction_data: cpi_instruction_data,
-        instruction_accounts: cpi_instruction_accounts,
+        rejections,
+        operators_epoch: 0,
+        instructions: destination_instructions(cpi_instruction_accounts, cpi_instruction_data),
+        expires_at: None,
+        approved_at: None,
+        vault_index: 0,
+        vault_bump: 0,
         bump: signing_pda_bump,
     };
-    let signing_init_state_serialized = borsh::to_vec(&signing_init_state).unwrap();
+    if let Some(vault_index) = signing_init_state.signing_vault_index() {
+        signing_init_state.vault_bump = find_vault_pda(&root_pda, vault_index).1;
+    }
+    let signing_init_state_serialized = signing_init_state.serialize().unwrap().0;
     let signing_size = signing_init_state_serialized.len();
     let signing_init_state_rent = mollusk.sysvars.rent.minimum_balance(signing_size);
     let mut signing_account =
@@ -151,14 +304,27 @@ pub fn prepare_signing_session(
     )
 }
 
+/// wraps single cpi into destination program as session instructions; none when both empty
+pub fn destination_instructions(
+    cpi_instruction_accounts: Vec<Vec<u8>>,
+    cpi_instruction_data: Vec<u8>,
+) -> Vec<SessionInstruction> {
+    if cpi_instruction_accounts.is_empty() && cpi_instruction_data.is_empty() {
+        return vec![];
+    }
+    vec![SessionInstruction {
+        program_id: DESTINATION_PROGRAM_ID,
+        data: cpi_instruction_data,
+        accounts: cpi_instruction_accounts,
+    }]
+}
+
 /// prepares records program data
 pub fn records_program_ix_accs(storage_id: Pubkey, authority: Pubkey) -> (Vec<Vec<u8>>, Vec<u8>) {
     // record program accounts and instruction data
-    let mut cpi_instruction_accounts = vec![];
-
     // the accounts the destination program CPI needs
     // order does matter
-    cpi_instruction_accounts.push(
+    let cpi_instruction_accounts = vec![
         InstructionAccount {
             pubkey: storage_id.to_bytes(),
             signer: false,
@@ -167,8 +333,6 @@ pub fn records_program_ix_accs(storage_id: Pubkey, authority: Pubkey) -> (Vec<Ve
         .serialize()
         .unwrap()
         .0,
-    );
-    cpi_instruction_accounts.push(
         InstructionAccount {
             pubkey: authority.to_bytes(),
             signer: true, // signer in the CPI
@@ -177,19 +341,75 @@ pub fn records_program_ix_accs(storage_id: Pubkey, authority: Pubkey) -> (Vec<Ve
         .serialize()
         .unwrap()
         .0,
-    );
+    ];
 
-    let offset = 0u64;
     let data_to_write_after_33 = &[0x2A]; // 42
-    let data_length = data_to_write_after_33.len() as u32;
+    let cpi_instruction_data = records_program_write_data(0, data_to_write_after_33);
+
+    (cpi_instruction_accounts, cpi_instruction_data)
+}
+
+/// builds readonly accounts appended to a cpi beyond what destination program reads
+pub fn padding_instruction_accounts(
+    count: usize,
+) -> (Vec<Vec<u8>>, Vec<(Pubkey, AccountSharedData)>) {
+    let mut cpi_instruction_accounts = vec![];
+    let mut accounts = vec![];
+    for _ in 0..count {
+        let pubkey = Pubkey::new_unique();
+        cpi_instruction_accounts.push(
+            InstructionAccount {
+                pubkey: pubkey.to_bytes(),
+                signer: false,
+                writable: false,
+            }
+            .serialize()
+            .unwrap()
+            .0,
+        );
+        accounts.push((pubkey, AccountSharedData::default()));
+    }
+    (cpi_instruction_accounts, accounts)
+}
+
+/// builds system program transfer accounts and instruction data
+pub fn system_transfer_ix_accs(from: Pubkey, to: Pubkey, lamports: u64) -> (Vec<Vec<u8>>, Vec<u8>) {
+    let cpi_instruction_accounts = vec![
+        InstructionAccount {
+            pubkey: from.to_bytes(),
+            signer: true,
+            writable: true,
+        }
+        .serialize()
+        .unwrap()
+        .0,
+        InstructionAccount {
+            pubkey: to.to_bytes(),
+            signer: false,
+            writable: true,
+        }
+        .serialize()
+        .unwrap()
+        .0,
+    ];
+
+    let mut cpi_instruction_data = Vec::new();
+    cpi_instruction_data.extend_from_slice(&2u32.to_le_bytes()); // transfer discriminator
+    cpi_instruction_data.extend_from_slice(&lamports.to_le_bytes());
+
+    (cpi_instruction_accounts, cpi_instruction_data)
+}
+
+/// builds record program write instruction data placing `data` at `offset` after the header
+pub fn records_program_write_data(offset: u64, data: &[u8]) -> Vec<u8> {
+    let data_length = data.len() as u32;
 
     let mut cpi_instruction_data = Vec::new();
     cpi_instruction_data.push(1); // ix discriminator
     cpi_instruction_data.extend_from_slice(&offset.to_le_bytes());
     cpi_instruction_data.extend_from_slice(&data_length.to_le_bytes()); // 4 bytes
-    cpi_instruction_data.extend_from_slice(data_to_write_after_33);
-
-    (cpi_instruction_accounts, cpi_instruction_data)
+    cpi_instruction_data.extend_from_slice(data);
+    cpi_instruction_data
 }
 
 pub fn prepare_storage_account(
@@ -223,3 +443,126 @@ pub fn prepare_storage_account(
     storage_pda_account.set_data_from_slice(&storage_data);
     (storage_pda, storage_pda_account)
 }
+
+/// builds upgradeable loader buffer account holding payload under given authority
+pub fn loader_buffer_account(authority: Pubkey, payload: &[u8]) -> AccountSharedData {
+    let mut data = Vec::new();
+    data.extend_from_slice(&1u32.to_le_bytes()); // buffer state discriminator
+    data.push(1); // authority is set
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(payload);
+
+    let mut account = AccountSharedData::new(LAMPORTS_PER_SOL, data.len(), &_DEFAULT_LOADER_KEY);
+    account.set_data_from_slice(&data);
+    account
+}
+
+/// length of upgradeable loader program data header preceding the program elf
+pub const LOADER_PROGRAM_DATA_HEADER_LEN: usize = 45;
+
+/// builds executable upgradeable loader program account pointing at its program data
+pub fn loader_program_account(program_data: Pubkey) -> AccountSharedData {
+    let mut data = Vec::new();
+    data.extend_from_slice(&2u32.to_le_bytes()); // program state discriminator
+    data.extend_from_slice(program_data.as_ref());
+
+    let mut account = AccountSharedData::new(LAMPORTS_PER_SOL, data.len(), &_DEFAULT_LOADER_KEY);
+    account.set_data_from_slice(&data);
+    account.set_executable(true);
+    account
+}
+
+/// builds upgradeable loader program data account deployed at slot under given authority
+pub fn loader_program_data_account(
+    authority: Option<Pubkey>,
+    slot: u64,
+    payload: &[u8],
+) -> AccountSharedData {
+    let mut data = Vec::new();
+    data.extend_from_slice(&3u32.to_le_bytes()); // program data state discriminator
+    data.extend_from_slice(&slot.to_le_bytes());
+    data.push(authority.is_some().into());
+    data.extend_from_slice(authority.unwrap_or_default().as_ref()); // zeroed when immutable
+    data.extend_from_slice(payload);
+
+    let mut account = AccountSharedData::new(LAMPORTS_PER_SOL, data.len(), &_DEFAULT_LOADER_KEY);
+    account.set_data_from_slice(&data);
+    account
+}
+
+/// length of token mint account without extensions
+pub const TOKEN_MINT_LEN: usize = 82;
+
+/// length of token account without extensions
+pub const TOKEN_ACCOUNT_LEN: usize = 165;
+
+/// builds initialized mint of token program without mint and freeze authorities
+pub fn token_mint_account(
+    mollusk: &Mollusk,
+    token_program: &Pubkey,
+    supply: u64,
+    decimals: u8,
+) -> AccountSharedData {
+    let mut data = vec![0u8; TOKEN_MINT_LEN];
+    data[36..44].copy_from_slice(&supply.to_le_bytes());
+    data[44] = decimals;
+    data[45] = 1; // initialized
+
+    let rent = mollusk.sysvars.rent.minimum_balance(TOKEN_MINT_LEN);
+    let mut account = AccountSharedData::new(rent, TOKEN_MINT_LEN, token_program);
+    account.set_data_from_slice(&data);
+    account
+}
+
+/// builds initialized token account of mint held by owner
+pub fn token_account(
+    mollusk: &Mollusk,
+    token_program: &Pubkey,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) -> AccountSharedData {
+    let mut data = vec![0u8; TOKEN_ACCOUNT_LEN];
+    data[..32].copy_from_slice(mint.as_ref());
+    data[32..64].copy_from_slice(owner.as_ref());
+    data[64..72].copy_from_slice(&amount.to_le_bytes());
+    data[108] = 1; // initialized
+
+    let rent = mollusk.sysvars.rent.minimum_balance(TOKEN_ACCOUNT_LEN);
+    let mut account = AccountSharedData::new(rent, TOKEN_ACCOUNT_LEN, token_program);
+    account.set_data_from_slice(&data);
+    account
+}
+
+/// reads amount held by token account data
+pub fn token_account_amount(data: &[u8]) -> u64 {
+    u64::from_le_bytes(data[64..72].try_into().unwrap())
+}
+
+/// attaches log collector to mollusk; returned handle reads logs of processed instructions
+pub fn collect_logs(mollusk: &mut Mollusk) -> Rc<RefCell<LogCollector>> {
+    let logs = LogCollector::new_ref();
+    mollusk.logger = Some(logs.clone());
+    logs
+}
+
+/// decodes mosaic events from `Program data:` lines of collected logs
+pub fn emitted_events(logs: &Rc<RefCell<LogCollector>>) -> Vec<Event> {
+    logs.borrow()
+        .get_recorded_content()
+        .iter()
+        .filter_map(|line| line.strip_prefix("Program data: "))
+        .filter_map(|fields| {
+            let fields: Vec<Vec<u8>> = fields
+                .split(' ')
+                .map(|field| STANDARD.decode(field).unwrap())
+                .collect();
+            match fields.as_slice() {
+                [tag, version, event] if tag == EVENT_TAG && version == &[EVENT_VERSION] => {
+                    Some(borsh::from_slice::<Event>(event).unwrap())
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,639 @@
+//! compute unit budgets per instruction
+//!
+//! every budget is built from the baseline each handler can't go below: `PDA_DERIVATION` per
+//! pda check or signer derivation, `CPI_INVOKE` plus callee units per cpi, and `HEADROOM` for
+//! entrypoint, instruction data and account data parsing, sysvars and the emitted event.
+//! fixtures are small (3 operators, one session instruction), so a handler going back to
+//! deserializing and reallocating whole accounts on the hot path exceeds its budget
+//!
+//! zero-copy delta: before zero-copy, sign, unsign, reject and cancel decoded the whole root and
+//! session accounts, re-encoded the session and wrote it back through `write_resized`; execute
+//! did the same after its cpis and searched the vault bump with up to 256 pda derivation
+//! attempts. their units grew with operators, allowed programs and session instructions on top
+//! of the baseline above. votes now read and write the session header in place, so their units
+//! don't depend on the session payload at all, which `test_vote_compute_units_ignore_payload`
+//! asserts; execute's vault bump search moved to session init, counted in its budget
+mod common;
+
+use {
+    borsh::to_vec,
+    common::*,
+    mollusk_svm::{Mollusk, result::Check, result::InstructionResult},
+};
+
+use mosaic::{
+    instructions::{
+        Instruction as ProgramIx, cancel::CancelIxData, close_session::CloseSessionIxData,
+        execute::ExecuteIxData, init_root::InitializeRootIxData,
+        init_signing_session::InitializeSigningSessionIxData, reject::RejectIxData,
+        sign::SignIxData, unsign::UnsignIxData,
+    },
+    seeds::SIGNING_SESSION_PDA,
+    state::{
+        proposal::Proposal,
+        root::ExecutePolicy,
+        signing_session::{SigningSessionPhase, vote_bit},
+    },
+};
+
+use solana_sdk::{
+    account::{AccountSharedData, ReadableAccount},
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+/// units charged per `create_program_address` call or `find_program_address` attempt
+const PDA_DERIVATION: u64 = 1_500;
+
+/// units charged per cpi before the callee runs
+const CPI_INVOKE: u64 = 1_000;
+
+/// units consumed by system program `create_account`
+const SYSTEM_CREATE_ACCOUNT: u64 = 150;
+
+/// units consumed by spl record `write` of the fixture record
+const RECORD_WRITE: u64 = 3_000;
+
+/// room for parsing, sysvars and event logging on top of syscalls
+const HEADROOM: u64 = 4_000;
+
+/// root pda check, create account cpi
+const INITIALIZE_ROOT_BUDGET: u64 = PDA_DERIVATION + CPI_INVOKE + SYSTEM_CREATE_ACCOUNT + HEADROOM;
+
+/// root and session pda checks, canonical vault bump search, create account cpi
+fn initialize_signing_session_budget(vault_bump: u8) -> u64 {
+    let vault_find_attempts = u64::from(u8::MAX - vault_bump) + 1;
+    (2 + vault_find_attempts) * PDA_DERIVATION + CPI_INVOKE + SYSTEM_CREATE_ACCOUNT + HEADROOM
+}
+
+/// root and session pda checks; votes are written in place
+const VOTE_BUDGET: u64 = 2 * PDA_DERIVATION + HEADROOM;
+
+/// root and session pda checks; phase is written in place
+const CANCEL_BUDGET: u64 = 2 * PDA_DERIVATION + HEADROOM;
+
+/// root and session pda checks
+const CLOSE_SESSION_BUDGET: u64 = 2 * PDA_DERIVATION + HEADROOM;
+
+/// root and session pda checks, vault signer derivation, record program cpi
+const EXECUTE_BUDGET: u64 = 3 * PDA_DERIVATION + CPI_INVOKE + RECORD_WRITE + HEADROOM;
+
+/// checks instruction consumed no more compute units than budgeted
+fn assert_within_budget(name: &str, result: &InstructionResult, budget: u64) {
+    assert!(
+        result.compute_units_consumed <= budget,
+        "{name} consumed {} compute units, budget is {budget}",
+        result.compute_units_consumed
+    );
+}
+
+/// runs sign, unsign or reject by operator at `signer` index against active session with
+/// given votes and instruction of `payload_accounts` accounts; checks it succeeds in place
+/// within compute budget and returns consumed units
+fn process_vote(
+    ix: ProgramIx,
+    signer: usize,
+    approvals: u64,
+    rejections: u64,
+    payload_accounts: usize,
+) -> u64 {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[signer].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (cpi_instruction_accounts, _) = padding_instruction_accounts(payload_accounts);
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_votes(
+            &mollusk,
+            session_id,
+            root_pda,
+            operators_pubkey[0],
+            approvals,
+            rejections,
+            SigningSessionPhase::Active,
+            Proposal::Cpi,
+            cpi_instruction_accounts,
+            vec![],
+        );
+
+    let (name, ix_data) = match ix {
+        ProgramIx::Sign => (
+            "sign",
+            to_vec(&SignIxData {
+                bump: signing_pda_bump,
+            }),
+        ),
+        ProgramIx::Unsign => (
+            "unsign",
+            to_vec(&UnsignIxData {
+                bump: signing_pda_bump,
+            }),
+        ),
+        ProgramIx::Reject => (
+            "reject",
+            to_vec(&RejectIxData {
+                bump: signing_pda_bump,
+            }),
+        ),
+        _ => unreachable!("not a vote instruction"),
+    };
+    let data = [vec![ix as u8], ix_data.unwrap()].concat();
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let result: InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            // votes are written in place; no reallocation nor rent top-up
+            Check::account(&signing_pda)
+                .space(signing_account.data().len())
+                .lamports(signing_account.lamports())
+                .build(),
+        ],
+    );
+
+    assert_within_budget(name, &result, VOTE_BUDGET);
+    result.compute_units_consumed
+}
+
+#[test]
+fn test_initialize_root_compute_units() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<Pubkey> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let (root_pda, root_pda_bump) = find_root_pda(&signer, ROOT_NAME);
+    let root_account = AccountSharedData::new(0, 0, &system_program);
+
+    let ix_data = InitializeRootIxData {
+        name: ROOT_NAME.to_string(),
+        operators: equal_weight_operators(&operators_pubkey),
+        threshold: operators.threshold,
+        default_ttl: None,
+        timelock: 0,
+        execute_policy: ExecutePolicy::Operators,
+        bump: root_pda_bump,
+        allowed_programs: vec![DESTINATION_PROGRAM_ID],
+    };
+    let data = [
+        vec![ProgramIx::InitializeOperators as u8],
+        to_vec(&ix_data).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let result: InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::success()],
+    );
+
+    assert_within_budget("initialize root", &result, INITIALIZE_ROOT_BUDGET);
+}
+
+#[test]
+fn test_initialize_signing_session_compute_units() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let session_id = 0;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // storage
+    let (storage_pda, _storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing
+    let next_session_id = root_pda_init_state.last_id + 1;
+    let (signing_pda, signing_pda_bump) = Pubkey::find_program_address(
+        &[
+            &root_pda.to_bytes(),
+            &next_session_id.to_be_bytes(),
+            SIGNING_SESSION_PDA,
+        ],
+        &PROGRAM_ID,
+    );
+    let signing_account = AccountSharedData::new(0, 0, &system_program);
+    let (_vault_pda, vault_bump) = find_vault_pda(&root_pda, 0);
+
+    let ix_data = InitializeSigningSessionIxData {
+        proposal: Proposal::Cpi,
+        instructions: destination_instructions(cpi_instruction_accounts, cpi_instruction_data),
+        vault_index: 0,
+        expires_at: None,
+        bump: signing_pda_bump,
+    };
+    let data = [
+        vec![ProgramIx::InitializeSigningSession as u8],
+        to_vec(&ix_data).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let result: InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[
+            Check::success(),
+            // last id is written in place; root keeps its size
+            Check::account(&root_pda)
+                .space(root_account.data().len())
+                .build(),
+        ],
+    );
+
+    assert_within_budget(
+        "initialize signing session",
+        &result,
+        initialize_signing_session_budget(vault_bump),
+    );
+}
+
+#[test]
+fn test_sign_compute_units() {
+    process_vote(ProgramIx::Sign, 0, 0, 0, 0);
+}
+
+#[test]
+fn test_sign_reaching_threshold_compute_units() {
+    process_vote(ProgramIx::Sign, 0, vote_bit(1), 0, 0);
+}
+
+#[test]
+fn test_unsign_compute_units() {
+    process_vote(ProgramIx::Unsign, 1, vote_bit(1), 0, 0);
+}
+
+#[test]
+fn test_reject_compute_units() {
+    process_vote(ProgramIx::Reject, 0, 0, vote_bit(1), 0);
+}
+
+#[test]
+fn test_vote_compute_units_ignore_payload() {
+    // session instruction accounts decoded and re-encoded on every vote before zero-copy
+    let payload_accounts = 32;
+
+    assert_eq!(
+        process_vote(ProgramIx::Sign, 0, 0, 0, 0),
+        process_vote(ProgramIx::Sign, 0, 0, 0, payload_accounts)
+    );
+    assert_eq!(
+        process_vote(ProgramIx::Unsign, 1, vote_bit(1), 0, 0),
+        process_vote(ProgramIx::Unsign, 1, vote_bit(1), 0, payload_accounts)
+    );
+    assert_eq!(
+        process_vote(ProgramIx::Reject, 0, 0, vote_bit(1), 0),
+        process_vote(ProgramIx::Reject, 0, 0, vote_bit(1), payload_accounts)
+    );
+}
+
+#[test]
+fn test_cancel_compute_units() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_votes(
+            &mollusk,
+            session_id,
+            root_pda,
+            signer,
+            0, // approvals
+            0, // rejections
+            SigningSessionPhase::Active,
+            Proposal::Cpi,
+            vec![],
+            vec![],
+        );
+
+    let data = [
+        vec![ProgramIx::Cancel as u8],
+        to_vec(&CancelIxData {
+            bump: signing_pda_bump,
+        })
+        .unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let result: InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+        ],
+        &[Check::success()],
+    );
+
+    assert_within_budget("cancel", &result, CANCEL_BUDGET);
+}
+
+#[test]
+fn test_execute_compute_units() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    mollusk.add_program(&DESTINATION_PROGRAM_ID, "tests/spl_record");
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let dst_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // storage
+    let (storage_pda, storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            cpi_instruction_accounts,
+            cpi_instruction_data,
+        );
+    let data = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ExecuteIxData {}).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(DESTINATION_PROGRAM_ID, false),
+            AccountMeta::new(storage_pda, false),
+        ],
+    );
+    let result: InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (DESTINATION_PROGRAM_ID, dst_program_account.clone().into()),
+            (storage_pda, storage_pda_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            // phase is written in place
+            Check::account(&signing_pda)
+                .space(signing_account.data().len())
+                .build(),
+        ],
+    );
+
+    assert_within_budget("execute", &result, EXECUTE_BUDGET);
+}
+
+#[test]
+fn test_close_session_compute_units() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (proposer, proposer_account) = operators.operators[0].clone();
+    let (closer, closer_account) = operators.operators[1].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_votes(
+            &mollusk,
+            session_id,
+            root_pda,
+            proposer,
+            0, // approvals
+            0, // rejections
+            SigningSessionPhase::Executed,
+            Proposal::Cpi,
+            vec![],
+            vec![],
+        );
+
+    let data = [
+        vec![ProgramIx::CloseSession as u8],
+        to_vec(&CloseSessionIxData {
+            bump: signing_pda_bump,
+        })
+        .unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(closer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new(proposer, false),
+        ],
+    );
+    let result: InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (closer, closer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (proposer, proposer_account.clone().into()),
+        ],
+        &[Check::success()],
+    );
+
+    assert_within_budget("close session", &result, CLOSE_SESSION_BUDGET);
+}
@@ -0,0 +1,241 @@
+//! host tests of cpi helpers; built with `cargo test --features cpi`.
+//!
+//! invoke is a no-op off chain, so these assert the metas and data handed to it and that the
+//! account views passed along line up with them.
+
+use pinocchio::{
+    AccountView, Address,
+    account::{NOT_BORROWED, RuntimeAccount},
+    instruction::InstructionAccount,
+};
+
+use mosaic::{
+    ID,
+    cpi::{Cancel, Execute, InitializeSigningSession, Reject, Sign, Unsign},
+    instructions::{
+        Instruction as ProgramIx, init_signing_session::InitializeSigningSessionIxData,
+    },
+    state::proposal::Proposal,
+};
+
+/// runtime account without data; views are only read for address and flags
+fn runtime_account(seed: u8, is_signer: bool, is_writable: bool) -> Box<RuntimeAccount> {
+    Box::new(RuntimeAccount {
+        borrow_state: NOT_BORROWED,
+        is_signer: is_signer as u8,
+        is_writable: is_writable as u8,
+        executable: 0,
+        resize_delta: 0,
+        address: Address::new_from_array([seed; 32]),
+        owner: Address::new_from_array(ID),
+        lamports: 0,
+        data_len: 0,
+    })
+}
+
+fn account_view(account: &mut RuntimeAccount) -> AccountView {
+    // SAFETY: account outlives the view and holds no data
+    unsafe { AccountView::new_unchecked(account) }
+}
+
+fn metas(instruction_accounts: &[InstructionAccount]) -> Vec<(Address, bool, bool)> {
+    instruction_accounts
+        .iter()
+        .map(|account| (*account.address, account.is_writable, account.is_signer))
+        .collect()
+}
+
+fn address(seed: u8) -> Address {
+    Address::new_from_array([seed; 32])
+}
+
+#[test]
+fn test_cpi_initialize_signing_session() {
+    let mut proposer = runtime_account(1, true, true);
+    let mut root = runtime_account(2, false, true);
+    let mut signing_session = runtime_account(3, false, true);
+    let mut system_program = runtime_account(4, false, false);
+    let (proposer, root, signing_session, system_program) = (
+        account_view(&mut proposer),
+        account_view(&mut root),
+        account_view(&mut signing_session),
+        account_view(&mut system_program),
+    );
+
+    let ix_data = InitializeSigningSessionIxData {
+        proposal: Proposal::Cpi,
+        instructions: vec![],
+        vault_index: 0,
+        expires_at: None,
+        bump: 254,
+    };
+    let cpi = InitializeSigningSession {
+        proposer: &proposer,
+        root: &root,
+        signing_session: &signing_session,
+        system_program: &system_program,
+        data: &ix_data,
+    };
+
+    assert_eq!(
+        metas(&cpi.instruction_accounts()),
+        vec![
+            (address(1), true, true),
+            (address(2), true, false),
+            (address(3), true, false),
+            (address(4), false, false),
+        ]
+    );
+    assert_eq!(
+        cpi.data().unwrap(),
+        [
+            vec![ProgramIx::InitializeSigningSession as u8],
+            borsh::to_vec(&ix_data).unwrap(),
+        ]
+        .concat()
+    );
+    assert!(cpi.invoke().is_ok());
+}
+
+#[test]
+fn test_cpi_votes() {
+    let mut signer = runtime_account(1, true, true);
+    let mut root = runtime_account(2, false, false);
+    let mut signing_session = runtime_account(3, false, true);
+    let (signer, root, signing_session) = (
+        account_view(&mut signer),
+        account_view(&mut root),
+        account_view(&mut signing_session),
+    );
+    let expected_metas = vec![
+        (address(1), true, true),
+        (address(2), false, false),
+        (address(3), true, false),
+    ];
+
+    let sign = Sign {
+        signer: &signer,
+        root: &root,
+        signing_session: &signing_session,
+        bump: 253,
+    };
+    assert_eq!(metas(&sign.instruction_accounts()), expected_metas);
+    assert_eq!(sign.data().unwrap(), vec![ProgramIx::Sign as u8, 253]);
+    assert!(sign.invoke().is_ok());
+
+    let unsign = Unsign {
+        signer: &signer,
+        root: &root,
+        signing_session: &signing_session,
+        bump: 253,
+    };
+    assert_eq!(metas(&unsign.instruction_accounts()), expected_metas);
+    assert_eq!(unsign.data().unwrap(), vec![ProgramIx::Unsign as u8, 253]);
+    assert!(unsign.invoke().is_ok());
+
+    let reject = Reject {
+        signer: &signer,
+        root: &root,
+        signing_session: &signing_session,
+        bump: 253,
+    };
+    assert_eq!(metas(&reject.instruction_accounts()), expected_metas);
+    assert_eq!(reject.data().unwrap(), vec![ProgramIx::Reject as u8, 253]);
+    assert!(reject.invoke().is_ok());
+
+    let cancel = Cancel {
+        proposer: &signer,
+        root: &root,
+        signing_session: &signing_session,
+        bump: 253,
+    };
+    assert_eq!(metas(&cancel.instruction_accounts()), expected_metas);
+    assert_eq!(cancel.data().unwrap(), vec![ProgramIx::Cancel as u8, 253]);
+    assert!(cancel.invoke().is_ok());
+}
+
+#[test]
+fn test_cpi_execute() {
+    let mut executor = runtime_account(1, true, true);
+    let mut root = runtime_account(2, false, true);
+    let mut signing_session = runtime_account(3, false, true);
+    let mut system_program = runtime_account(4, false, false);
+    let mut program = runtime_account(5, false, false);
+    let mut vault = runtime_account(6, false, true);
+    let mut recipient = runtime_account(7, false, true);
+    let mut mint = runtime_account(8, false, false);
+    let (executor, root, signing_session, system_program, program, vault, recipient, mint) = (
+        account_view(&mut executor),
+        account_view(&mut root),
+        account_view(&mut signing_session),
+        account_view(&mut system_program),
+        account_view(&mut program),
+        account_view(&mut vault),
+        account_view(&mut recipient),
+        account_view(&mut mint),
+    );
+
+    let remaining = [&vault, &recipient, &mint];
+    let cpi = Execute {
+        executor: &executor,
+        root: &root,
+        signing_session: &signing_session,
+        system_program: &system_program,
+        program: &program,
+        remaining: &remaining,
+    };
+
+    // root follows the view, writable here as for config proposals
+    assert_eq!(
+        metas(&cpi.instruction_accounts()),
+        vec![
+            (address(1), true, true),
+            (address(2), true, false),
+            (address(3), true, false),
+            (address(4), false, false),
+            (address(5), false, false),
+            (address(6), true, false),
+            (address(7), true, false),
+            (address(8), false, false),
+        ]
+    );
+    assert_eq!(cpi.data().unwrap(), vec![ProgramIx::Execute as u8]);
+    assert!(cpi.invoke().is_ok());
+}
+
+#[test]
+fn test_cpi_execute_readonly_root() {
+    let mut executor = runtime_account(1, true, true);
+    let mut root = runtime_account(2, false, false);
+    let mut signing_session = runtime_account(3, false, true);
+    let mut system_program = runtime_account(4, false, false);
+    let mut program = runtime_account(5, false, false);
+    let (executor, root, signing_session, system_program, program) = (
+        account_view(&mut executor),
+        account_view(&mut root),
+        account_view(&mut signing_session),
+        account_view(&mut system_program),
+        account_view(&mut program),
+    );
+
+    let cpi = Execute {
+        executor: &executor,
+        root: &root,
+        signing_session: &signing_session,
+        system_program: &system_program,
+        program: &program,
+        remaining: &[],
+    };
+
+    assert_eq!(
+        metas(&cpi.instruction_accounts()),
+        vec![
+            (address(1), true, true),
+            (address(2), false, false),
+            (address(3), true, false),
+            (address(4), false, false),
+            (address(5), false, false),
+        ]
+    );
+    assert!(cpi.invoke().is_ok());
+}
@@ -7,19 +7,31 @@ use {
 };
 
 use mosaic::{
+    events::Event,
     instructions::{Instruction as ProgramIx, execute::ExecuteIxData},
-    state::signing_session::{SigningSession, SigningSessionPhase},
+    programs::{TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID},
+    state::{
+        expiry::Expiry,
+        proposal::{ConfigChange, LoaderChange, Proposal, TokenTransfer},
+        root::{ExecutePolicy, MAX_OPERATORS, Operator, Permission, Root},
+        signing_session::{SessionInstruction, SigningSession, SigningSessionPhase},
+    },
 };
 
+use pinocchio::cpi::MAX_CPI_ACCOUNTS;
+
 use solana_sdk::{
     account::AccountSharedData,
     instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
 };
 
 #[test]
 fn test_execute() {
     let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
     mollusk.add_program(&DESTINATION_PROGRAM_ID, "tests/spl_record");
+    let logs = collect_logs(&mut mollusk);
 
     let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
     let dst_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
@@ -64,7 +76,7 @@ fn test_execute() {
             &mollusk,
             session_id,
             root_pda,
-            vec![signer, operators_pubkey[1]], // approvals
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
             SigningSessionPhase::Approved, // signing session phase / must be Approved to Execute
             cpi_instruction_accounts,
             cpi_instruction_data,
@@ -82,22 +94,22 @@ fn test_execute() {
         PROGRAM_ID,
         &data_execute,
         vec![
-            AccountMeta::new(signer.into(), true),      // 0: payer/signer
-            AccountMeta::new_readonly(root_pda, false), // 1: root_pda
-            AccountMeta::new(signing_pda, false),       // 2: signing_pda
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
             AccountMeta::new_readonly(system_program, false), // 3: system_program
             AccountMeta::new_readonly(DESTINATION_PROGRAM_ID, false), // 4: DESTINATION_PROGRAM_ID
-            AccountMeta::new(storage_pda, false),       // 5: storage_pda (remaining[0])
+            AccountMeta::new(storage_pda, false),             // 5: storage_pda (remaining[0])
         ],
     );
 
     let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()), // 0: payer/signer
-            (root_pda, root_account.clone().into()),        // 1: root_pda
-            (signing_pda, signing_account.clone().into()),  // 2: signing_pda
-            (system_program, system_account.clone().into()), // 3: system_program
+            (signer, signer_account.clone().into()), // 0: payer/signer
+            (root_pda, root_account.clone().into()), // 1: root_pda
+            (signing_pda, signing_account.clone().into()), // 2: signing_pda
+            (system_program, system_account.clone()), // 3: system_program
             (DESTINATION_PROGRAM_ID, dst_program_account.clone().into()), // 4: DESTINATION_PROGRAM_ID
             (storage_pda, storage_pda_account.clone().into()), // 5: storage_pda (remaining[0])
         ],
@@ -109,18 +121,2544 @@ fn test_execute() {
 
     let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
     let parsed_signing_session_pda_data =
-        borsh::from_slice::<SigningSession>(&updated_signing_session_pda_account.data).unwrap();
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
 
     let copy_of_initial_storage_data = &storage_pda_account.clone().into();
     let updated_storage_pda_account = result.get_account(&storage_pda).unwrap();
 
     assert!(updated_storage_pda_account != copy_of_initial_storage_data,);
     assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Executed);
-    assert!(parsed_signing_session_pda_data.approvals.contains(&signer));
+    assert!(has_voted(
+        parsed_signing_session_pda_data.approvals,
+        &operators_pubkey,
+        &signer
+    ));
+    assert!(has_voted(
+        parsed_signing_session_pda_data.approvals,
+        &operators_pubkey,
+        &operators_pubkey[1]
+    ));
+    assert!(parsed_signing_session_pda_data.bump == signing_pda_bump);
     assert!(
-        parsed_signing_session_pda_data
-            .approvals
-            .contains(&operators_pubkey[1])
+        emitted_events(&logs)
+            == vec![Event::SessionExecuted {
+                root: root_pda,
+                session_id,
+                executor: signer,
+            }]
+    );
+}
+
+#[test]
+fn test_execute_batched_instructions() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    mollusk.add_program(&DESTINATION_PROGRAM_ID, "tests/spl_record");
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let dst_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // storage
+    let (storage_pda, storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, signing_init_state_serialized, _signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved, // signing session phase / must be Approved to Execute
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data,
+        );
+
+    // second write overrides the first one, so final byte proves execution order
+    let mut signing_init_state =
+        SigningSession::deserialize(&signing_init_state_serialized).unwrap();
+    signing_init_state.instructions = vec![
+        SessionInstruction {
+            program_id: DESTINATION_PROGRAM_ID,
+            data: records_program_write_data(0, &[0x2A]),
+            accounts: cpi_instruction_accounts.clone(),
+        },
+        SessionInstruction {
+            program_id: DESTINATION_PROGRAM_ID,
+            data: records_program_write_data(0, &[0x2B]),
+            accounts: cpi_instruction_accounts,
+        },
+    ];
+    let signing_account = signing_session_account_from_state(&mollusk, &signing_init_state);
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(DESTINATION_PROGRAM_ID, false), // 4: DESTINATION_PROGRAM_ID
+            AccountMeta::new(storage_pda, false),             // 5: storage_pda (remaining[0])
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()), // 0: payer/signer
+            (root_pda, root_account.clone().into()), // 1: root_pda
+            (signing_pda, signing_account.clone().into()), // 2: signing_pda
+            (system_program, system_account.clone()), // 3: system_program
+            (DESTINATION_PROGRAM_ID, dst_program_account.clone().into()), // 4: DESTINATION_PROGRAM_ID
+            (storage_pda, storage_pda_account.clone().into()), // 5: storage_pda (remaining[0])
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    let updated_storage_pda_account = result.get_account(&storage_pda).unwrap();
+
+    assert!(updated_storage_pda_account.data.last() == Some(&0x2B));
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Executed);
+}
+
+#[test]
+fn test_execute_after_timelock() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    mollusk.add_program(&DESTINATION_PROGRAM_ID, "tests/spl_record");
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let dst_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        mut root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // root requires 100 seconds between approval and execution
+    root_pda_init_state.timelock = 100;
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // storage
+    let (storage_pda, storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session
+    let (signing_pda, signing_pda_bump, signing_init_state_serialized, _signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved, // signing session phase / must be Approved to Execute
+            cpi_instruction_accounts,
+            cpi_instruction_data,
+        );
+
+    // session approved at unix timestamp 1_000
+    let mut signing_init_state =
+        SigningSession::deserialize(&signing_init_state_serialized).unwrap();
+    signing_init_state.approved_at = Some(1_000);
+    let signing_account = signing_session_account_from_state(&mollusk, &signing_init_state);
+    mollusk.sysvars.clock.unix_timestamp = 1_100;
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(DESTINATION_PROGRAM_ID, false), // 4: DESTINATION_PROGRAM_ID
+            AccountMeta::new(storage_pda, false),             // 5: storage_pda (remaining[0])
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()), // 0: payer/signer
+            (root_pda, root_account.clone().into()), // 1: root_pda
+            (signing_pda, signing_account.clone().into()), // 2: signing_pda
+            (system_program, system_account.clone()), // 3: system_program
+            (DESTINATION_PROGRAM_ID, dst_program_account.clone().into()), // 4: DESTINATION_PROGRAM_ID
+            (storage_pda, storage_pda_account.clone().into()), // 5: storage_pda (remaining[0])
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
     );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    let copy_of_initial_storage_data = &storage_pda_account.clone().into();
+    let updated_storage_pda_account = result.get_account(&storage_pda).unwrap();
+
+    assert!(updated_storage_pda_account != copy_of_initial_storage_data,);
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Executed);
+    assert!(has_voted(
+        parsed_signing_session_pda_data.approvals,
+        &operators_pubkey,
+        &signer
+    ));
+    assert!(has_voted(
+        parsed_signing_session_pda_data.approvals,
+        &operators_pubkey,
+        &operators_pubkey[1]
+    ));
     assert!(parsed_signing_session_pda_data.bump == signing_pda_bump)
 }
+
+#[test]
+fn test_execute_older_session() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    mollusk.add_program(&DESTINATION_PROGRAM_ID, "tests/spl_record");
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let dst_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // newer sessions were created after this one
+    let root_last_id = 4;
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        root_last_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // storage
+    let (storage_pda, storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved, // signing session phase / must be Approved to Execute
+            cpi_instruction_accounts,
+            cpi_instruction_data,
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(DESTINATION_PROGRAM_ID, false), // 4: DESTINATION_PROGRAM_ID
+            AccountMeta::new(storage_pda, false),             // 5: storage_pda (remaining[0])
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()), // 0: payer/signer
+            (root_pda, root_account.clone().into()), // 1: root_pda
+            (signing_pda, signing_account.clone().into()), // 2: signing_pda
+            (system_program, system_account.clone()), // 3: system_program
+            (DESTINATION_PROGRAM_ID, dst_program_account.clone().into()), // 4: DESTINATION_PROGRAM_ID
+            (storage_pda, storage_pda_account.clone().into()), // 5: storage_pda (remaining[0])
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    let copy_of_initial_storage_data = &storage_pda_account.clone().into();
+    let updated_storage_pda_account = result.get_account(&storage_pda).unwrap();
+
+    assert!(updated_storage_pda_account != copy_of_initial_storage_data,);
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Executed);
+    assert!(has_voted(
+        parsed_signing_session_pda_data.approvals,
+        &operators_pubkey,
+        &signer
+    ));
+    assert!(has_voted(
+        parsed_signing_session_pda_data.approvals,
+        &operators_pubkey,
+        &operators_pubkey[1]
+    ));
+    assert!(parsed_signing_session_pda_data.bump == signing_pda_bump)
+}
+
+#[test]
+fn test_execute_add_operator_config_change() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session / config proposal carries no cpi data
+    let new_operator = Pubkey::new_unique();
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::AddOperator(Operator {
+                key: new_operator,
+                weight: 1,
+                permissions: Permission::ALL,
+            })),
+            vec![],
+            vec![],
+        );
+
+    let logs = collect_logs(&mut mollusk);
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new(root_pda, false),                // 1: root_pda / writable for config
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(PROGRAM_ID, false),     // 4: unused by config proposals
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&root_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_root_pda_account = result.get_account(&root_pda).unwrap();
+    let parsed_root_pda_data = Root::deserialize(&updated_root_pda_account.data).unwrap();
+    let expected_root_size = root_pda_initial_state_serialized.len() + 34; // operator key, weight and permissions
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_root_pda_data.operators.len() == 4);
+    assert!(parsed_root_pda_data.operators.contains(&Operator {
+        key: new_operator,
+        weight: 1,
+        permissions: Permission::ALL,
+    }));
+    assert!(updated_root_pda_account.data.len() == expected_root_size);
+    assert!(
+        updated_root_pda_account.lamports
+            >= mollusk.sysvars.rent.minimum_balance(expected_root_size)
+    );
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Executed);
+    assert!(
+        emitted_events(&logs)
+            == vec![
+                Event::ConfigChanged {
+                    root: root_pda,
+                    session_id,
+                    change: ConfigChange::AddOperator(Operator {
+                        key: new_operator,
+                        weight: 1,
+                        permissions: Permission::ALL,
+                    }),
+                },
+                Event::SessionExecuted {
+                    root: root_pda,
+                    session_id,
+                    executor: signer,
+                },
+            ]
+    );
+}
+
+#[test]
+fn test_execute_remove_operator_config_change() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(4, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let leaving_operator = operators_pubkey[3];
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::RemoveOperator(leaving_operator)),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::success()],
+    );
+
+    let updated_root_pda_account = result.get_account(&root_pda).unwrap();
+    let parsed_root_pda_data = Root::deserialize(&updated_root_pda_account.data).unwrap();
+
+    // removed operator leaves a tombstone so remaining operators keep their positions
+    assert!(parsed_root_pda_data.operators.len() == 4);
+    assert!(parsed_root_pda_data.operators[3] == Operator::REMOVED);
+    assert!(
+        !parsed_root_pda_data
+            .operators
+            .iter()
+            .any(|operator| operator.key == leaving_operator)
+    );
+}
+
+#[test]
+fn test_execute_after_unrelated_operator_removal() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(4, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // two sessions were created, root last_id points at the later one
+    let removal_session_id = 1;
+    let pending_session_id = 2;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        pending_session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // session removing operator at the last position
+    let leaving_operator = operators_pubkey[3];
+    let (removal_pda, _removal_pda_bump, _removal_init_state_serialized, removal_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            removal_session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::RemoveOperator(leaving_operator)),
+            vec![],
+            vec![],
+        );
+
+    // session approved by operators who stay, waiting for execution meanwhile
+    let new_operator = Pubkey::new_unique();
+    let (pending_pda, _pending_pda_bump, _pending_init_state_serialized, pending_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            pending_session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::AddOperator(Operator {
+                key: new_operator,
+                weight: 1,
+                permissions: Permission::ALL,
+            })),
+            vec![],
+            vec![],
+        );
+
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ExecuteIxData {}).unwrap(),
+    ]
+    .concat();
+    let execute_instruction = |signing_pda| {
+        Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &data_execute,
+            vec![
+                AccountMeta::new(signer, true),
+                AccountMeta::new(root_pda, false),
+                AccountMeta::new(signing_pda, false),
+                AccountMeta::new_readonly(system_program, false),
+                AccountMeta::new_readonly(PROGRAM_ID, false),
+            ],
+        )
+    };
+
+    // remove operator
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &execute_instruction(removal_pda),
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (removal_pda, removal_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::success()],
+    );
+    let root_account = result.get_account(&root_pda).unwrap().clone();
+
+    // pending session still executes against root without the removed operator
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &execute_instruction(pending_pda),
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone()),
+            (pending_pda, pending_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::success()],
+    );
+
+    let updated_root_pda_account = result.get_account(&root_pda).unwrap();
+    let parsed_root_pda_data = Root::deserialize(&updated_root_pda_account.data).unwrap();
+
+    let updated_signing_session_pda_account = result.get_account(&pending_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    // new operator takes the tombstoned slot over in a new operators epoch
+    assert!(parsed_root_pda_data.operators.len() == 4);
+    assert!(parsed_root_pda_data.operators[3].key == new_operator);
+    assert!(parsed_root_pda_data.operators_epoch == 1);
+    assert!(parsed_root_pda_data.operator_epochs == vec![0, 0, 0, 1]);
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Executed);
+}
+
+#[test]
+fn test_execute_replace_operator_config_change() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let old_operator = operators_pubkey[2];
+    let new_operator = Pubkey::new_unique();
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::ReplaceOperator {
+                old: old_operator,
+                new: new_operator,
+            }),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::success()],
+    );
+
+    let updated_root_pda_account = result.get_account(&root_pda).unwrap();
+    let parsed_root_pda_data = Root::deserialize(&updated_root_pda_account.data).unwrap();
+
+    // new key takes the slot over in a new epoch so votes of the old key never count for it
+    assert!(parsed_root_pda_data.operators.len() == 3);
+    assert!(
+        parsed_root_pda_data.operators[2]
+            == Operator {
+                key: new_operator,
+                ..root_pda_init_state.operators[2]
+            }
+    );
+    assert!(parsed_root_pda_data.operators_epoch == 1);
+    assert!(parsed_root_pda_data.operator_epochs == vec![0, 0, 1]);
+}
+
+#[test]
+fn test_execute_replace_operator_beyond_operator_slots() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // every rotation goes through its own session
+    let rotations = MAX_OPERATORS as u16 + 6;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        rotations,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ExecuteIxData {}).unwrap(),
+    ]
+    .concat();
+
+    // keep rotating the last operator, each time to a fresh key
+    let mut root_account = solana_sdk::account::Account::from(root_account);
+    let mut rotated_operator = operators_pubkey[2];
+    for session_id in 1..=rotations {
+        let new_operator = Pubkey::new_unique();
+        let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+            prepare_signing_session_with_proposal(
+                &mollusk,
+                session_id,
+                root_pda,
+                votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+                SigningSessionPhase::Approved,
+                Proposal::Config(ConfigChange::ReplaceOperator {
+                    old: rotated_operator,
+                    new: new_operator,
+                }),
+                vec![],
+                vec![],
+            );
+
+        let instruction = Instruction::new_with_bytes(
+            PROGRAM_ID,
+            &data_execute,
+            vec![
+                AccountMeta::new(signer, true),
+                AccountMeta::new(root_pda, false),
+                AccountMeta::new(signing_pda, false),
+                AccountMeta::new_readonly(system_program, false),
+                AccountMeta::new_readonly(PROGRAM_ID, false),
+            ],
+        );
+        let result: mollusk_svm::result::InstructionResult = mollusk
+            .process_and_validate_instruction(
+                &instruction,
+                &[
+                    (signer, signer_account.clone().into()),
+                    (root_pda, root_account.clone()),
+                    (signing_pda, signing_account.clone().into()),
+                    (system_program, system_account.clone()),
+                    (PROGRAM_ID, mosaic_program_account.clone().into()),
+                ],
+                &[Check::success()],
+            );
+
+        root_account = result.get_account(&root_pda).unwrap().clone();
+        rotated_operator = new_operator;
+    }
+
+    let parsed_root_pda_data = Root::deserialize(&root_account.data).unwrap();
+
+    // rotations reuse the same slot instead of running out of them
+    assert!(parsed_root_pda_data.operators.len() == 3);
+    assert!(parsed_root_pda_data.operators[2].key == rotated_operator);
+    assert!(parsed_root_pda_data.operators_epoch == u32::from(rotations));
+}
+
+#[test]
+fn test_execute_change_threshold_config_change() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::ChangeThreshold(3)),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::success()],
+    );
+
+    let updated_root_pda_account = result.get_account(&root_pda).unwrap();
+    let parsed_root_pda_data = Root::deserialize(&updated_root_pda_account.data).unwrap();
+
+    assert!(parsed_root_pda_data.threshold == 3);
+    assert!(parsed_root_pda_data.operators == equal_weight_operators(&operators_pubkey));
+}
+
+#[test]
+fn test_execute_change_default_ttl_config_change() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::ChangeDefaultTtl(Some(Expiry::Slot(1_000)))),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::success()],
+    );
+
+    let updated_root_pda_account = result.get_account(&root_pda).unwrap();
+    let parsed_root_pda_data = Root::deserialize(&updated_root_pda_account.data).unwrap();
+
+    assert!(parsed_root_pda_data.default_ttl == Some(Expiry::Slot(1_000)));
+    assert!(parsed_root_pda_data.operators == equal_weight_operators(&operators_pubkey));
+}
+
+#[test]
+fn test_execute_change_operator_weight_config_change() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::ChangeOperatorWeight {
+                operator: operators_pubkey[2],
+                weight: 5,
+            }),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::success()],
+    );
+
+    let updated_root_pda_account = result.get_account(&root_pda).unwrap();
+    let parsed_root_pda_data = Root::deserialize(&updated_root_pda_account.data).unwrap();
+
+    assert!(parsed_root_pda_data.operators[2].weight == 5);
+    assert!(parsed_root_pda_data.total_weight() == 7);
+}
+
+#[test]
+fn test_execute_change_operator_permissions_config_change() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::ChangeOperatorPermissions {
+                operator: operators_pubkey[2],
+                permissions: Permission::Initiate as u8 | Permission::Execute as u8,
+            }),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::success()],
+    );
+
+    let updated_root_pda_account = result.get_account(&root_pda).unwrap();
+    let parsed_root_pda_data = Root::deserialize(&updated_root_pda_account.data).unwrap();
+
+    assert!(!parsed_root_pda_data.operators[2].has_permission(Permission::Vote));
+    assert!(parsed_root_pda_data.operators[2].has_permission(Permission::Execute));
+    assert!(parsed_root_pda_data.total_weight() == 2);
+}
+
+#[test]
+fn test_execute_change_execute_policy_config_change() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::ChangeExecutePolicy(ExecutePolicy::Anyone)),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::success()],
+    );
+
+    let updated_root_pda_account = result.get_account(&root_pda).unwrap();
+    let parsed_root_pda_data = Root::deserialize(&updated_root_pda_account.data).unwrap();
+
+    assert!(parsed_root_pda_data.execute_policy == ExecutePolicy::Anyone);
+}
+
+#[test]
+fn test_execute_add_allowed_program_config_change() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    let allowed_program = Pubkey::new_unique();
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::AddAllowedProgram(allowed_program)),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::success()],
+    );
+
+    let updated_root_pda_account = result.get_account(&root_pda).unwrap();
+    let parsed_root_pda_data = Root::deserialize(&updated_root_pda_account.data).unwrap();
+
+    assert!(parsed_root_pda_data.allowed_programs == vec![DESTINATION_PROGRAM_ID, allowed_program]);
+    assert!(parsed_root_pda_data.operators == equal_weight_operators(&operators_pubkey));
+}
+
+#[test]
+fn test_execute_remove_allowed_program_config_change() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::RemoveAllowedProgram(DESTINATION_PROGRAM_ID)),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::success()],
+    );
+
+    let updated_root_pda_account = result.get_account(&root_pda).unwrap();
+    let parsed_root_pda_data = Root::deserialize(&updated_root_pda_account.data).unwrap();
+
+    assert!(parsed_root_pda_data.allowed_programs.is_empty());
+    assert!(parsed_root_pda_data.operators == equal_weight_operators(&operators_pubkey));
+}
+
+/// executes record program write carrying `padding` extra accounts in its cpi
+fn execute_with_padding_accounts(padding: usize) {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    mollusk.add_program(&DESTINATION_PROGRAM_ID, "tests/spl_record");
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let dst_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // storage
+    let (storage_pda, storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts followed by accounts it ignores
+    let (mut cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+    let (padding_instruction_accounts, padding_accounts) = padding_instruction_accounts(padding);
+    cpi_instruction_accounts.extend(padding_instruction_accounts);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            cpi_instruction_accounts,
+            cpi_instruction_data,
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let mut account_metas = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(root_pda, false),
+        AccountMeta::new(signing_pda, false),
+        AccountMeta::new_readonly(system_program, false),
+        AccountMeta::new_readonly(DESTINATION_PROGRAM_ID, false),
+        AccountMeta::new(storage_pda, false),
+    ];
+    let mut accounts = vec![
+        (signer, signer_account.clone().into()),
+        (root_pda, root_account.clone().into()),
+        (signing_pda, signing_account.clone().into()),
+        (system_program, system_account.clone()),
+        (DESTINATION_PROGRAM_ID, dst_program_account.clone().into()),
+        (storage_pda, storage_pda_account.clone().into()),
+    ];
+    for (pubkey, account) in padding_accounts {
+        account_metas.push(AccountMeta::new_readonly(pubkey, false));
+        accounts.push((pubkey, account.into()));
+    }
+
+    let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data_execute, account_metas);
+
+    let result: mollusk_svm::result::InstructionResult =
+        mollusk.process_and_validate_instruction(&instruction, &accounts, &[Check::success()]);
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    let copy_of_initial_storage_data = &storage_pda_account.clone().into();
+    let updated_storage_pda_account = result.get_account(&storage_pda).unwrap();
+
+    assert!(updated_storage_pda_account != copy_of_initial_storage_data);
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Executed);
+}
+
+#[test]
+fn test_execute_instruction_with_21_accounts() {
+    // 2 record program accounts + 19 padding; first count past former 20 account ceiling
+    execute_with_padding_accounts(19);
+}
+
+#[test]
+fn test_execute_instruction_at_cpi_account_limit() {
+    // 2 record program accounts + padding up to runtime cpi account limit
+    execute_with_padding_accounts(MAX_CPI_ACCOUNTS - 2);
+}
+
+#[test]
+fn test_execute_vault_transfer() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root allowed to cpi into system program
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        system_program,
+    );
+
+    // vault holding SOL and recipient of the transfer
+    let (vault_pda, _vault_pda_bump) = find_vault_pda(&root_pda, 0);
+    let vault_account = AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program);
+    let recipient = Pubkey::new_unique();
+    let recipient_account = AccountSharedData::new(0, 0, &system_program);
+    let transfer_lamports = LAMPORTS_PER_SOL / 2;
+
+    // signing session signed by vault 0
+    let (signing_pda, _signing_pda_bump, signing_init_state_serialized, _signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            vec![],
+            vec![],
+        );
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        system_transfer_ix_accs(vault_pda, recipient, transfer_lamports);
+    let mut signing_init_state =
+        SigningSession::deserialize(&signing_init_state_serialized).unwrap();
+    signing_init_state.instructions = vec![SessionInstruction {
+        program_id: system_program,
+        data: cpi_instruction_data,
+        accounts: cpi_instruction_accounts,
+    }];
+    let signing_account = signing_session_account_from_state(&mollusk, &signing_init_state);
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(system_program, false), // 4: targeted program
+            AccountMeta::new(vault_pda, false),               // 5: vault_pda (remaining[0])
+            AccountMeta::new(recipient, false),               // 6: recipient (remaining[1])
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (vault_pda, vault_account.clone().into()),
+            (recipient, recipient_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&vault_pda)
+                .lamports(LAMPORTS_PER_SOL - transfer_lamports)
+                .owner(&system_program)
+                .build(),
+            Check::account(&recipient)
+                .lamports(transfer_lamports)
+                .build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Executed);
+}
+
+#[test]
+fn test_execute_transfer_proposal() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // vault holding SOL and recipient of the transfer
+    let (vault_pda, _vault_pda_bump) = find_vault_pda(&root_pda, 1);
+    let vault_account = AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program);
+    let recipient = Pubkey::new_unique();
+    let recipient_account = AccountSharedData::new(0, 0, &system_program);
+    let transfer_lamports = LAMPORTS_PER_SOL / 2;
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Transfer {
+                recipient,
+                amount: transfer_lamports,
+                vault_index: 1,
+            },
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(system_program, false), // 4: unused by transfer
+            AccountMeta::new(vault_pda, false),               // 5: vault_pda (remaining[0])
+            AccountMeta::new(recipient, false),               // 6: recipient (remaining[1])
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (vault_pda, vault_account.clone().into()),
+            (recipient, recipient_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&vault_pda)
+                .lamports(LAMPORTS_PER_SOL - transfer_lamports)
+                .build(),
+            Check::account(&recipient)
+                .lamports(transfer_lamports)
+                .build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Executed);
+}
+
+#[test]
+fn test_execute_token_transfer_proposal() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let token_program = Pubkey::new_from_array(TOKEN_PROGRAM_ID.to_bytes());
+    mollusk.add_program(&token_program, "tests/spl_token");
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let token_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // vault holding tokens and token account of the recipient
+    let (vault_pda, _vault_pda_bump) = find_vault_pda(&root_pda, 0);
+    let vault_account = AccountSharedData::new(0, 0, &system_program);
+    let mint = Pubkey::new_unique();
+    let mint_account = token_mint_account(&mollusk, &token_program, 10_000, 6);
+    let source = Pubkey::new_unique();
+    let source_account = token_account(&mollusk, &token_program, &mint, &vault_pda, 10_000);
+    let destination = Pubkey::new_unique();
+    let destination_account =
+        token_account(&mollusk, &token_program, &mint, &Pubkey::new_unique(), 0);
+    let transfer_amount = 1_000;
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::TokenTransfer(TokenTransfer {
+                token_program,
+                mint,
+                source,
+                destination,
+                amount: transfer_amount,
+                decimals: 6,
+                vault_index: Some(0),
+            }),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(system_program, false), // 4: unused by token transfer
+            AccountMeta::new(source, false),                  // 5: source (remaining[0])
+            AccountMeta::new_readonly(mint, false),           // 6: mint (remaining[1])
+            AccountMeta::new(destination, false),             // 7: destination (remaining[2])
+            AccountMeta::new_readonly(token_program, false),  // 8: token program (remaining[3])
+            AccountMeta::new(vault_pda, false),               // 9: vault_pda (remaining[4])
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (source, source_account.clone().into()),
+            (mint, mint_account.clone().into()),
+            (destination, destination_account.clone().into()),
+            (token_program, token_program_account.clone().into()),
+            (vault_pda, vault_account.clone().into()),
+        ],
+        &[Check::success()],
+    );
+
+    let updated_source_account = result.get_account(&source).unwrap();
+    let updated_destination_account = result.get_account(&destination).unwrap();
+    assert!(token_account_amount(&updated_source_account.data) == 10_000 - transfer_amount);
+    assert!(token_account_amount(&updated_destination_account.data) == transfer_amount);
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Executed);
+}
+
+#[test]
+fn test_execute_token_2022_transfer_proposal_signed_by_root() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let token_program = Pubkey::new_from_array(TOKEN_2022_PROGRAM_ID.to_bytes());
+    mollusk.add_program(&token_program, "tests/spl_token_2022");
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let token_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // token account held by root pda and token account of the recipient
+    let mint = Pubkey::new_unique();
+    let mint_account = token_mint_account(&mollusk, &token_program, 10_000, 9);
+    let source = Pubkey::new_unique();
+    let source_account = token_account(&mollusk, &token_program, &mint, &root_pda, 10_000);
+    let destination = Pubkey::new_unique();
+    let destination_account =
+        token_account(&mollusk, &token_program, &mint, &Pubkey::new_unique(), 500);
+    let transfer_amount = 2_500;
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::TokenTransfer(TokenTransfer {
+                token_program,
+                mint,
+                source,
+                destination,
+                amount: transfer_amount,
+                decimals: 9,
+                vault_index: None,
+            }),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(system_program, false), // 4: unused by token transfer
+            AccountMeta::new(source, false),                  // 5: source (remaining[0])
+            AccountMeta::new_readonly(mint, false),           // 6: mint (remaining[1])
+            AccountMeta::new(destination, false),             // 7: destination (remaining[2])
+            AccountMeta::new_readonly(token_program, false),  // 8: token program (remaining[3])
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (source, source_account.clone().into()),
+            (mint, mint_account.clone().into()),
+            (destination, destination_account.clone().into()),
+            (token_program, token_program_account.clone().into()),
+        ],
+        &[Check::success()],
+    );
+
+    let updated_source_account = result.get_account(&source).unwrap();
+    let updated_destination_account = result.get_account(&destination).unwrap();
+    assert!(token_account_amount(&updated_source_account.data) == 10_000 - transfer_amount);
+    assert!(token_account_amount(&updated_destination_account.data) == 500 + transfer_amount);
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Executed);
+}
+
+#[test]
+fn test_execute_loader_close_buffer_proposal() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let (loader_program, loader_account) =
+        mollusk_svm::program::keyed_account_for_bpf_loader_v3_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // buffer owned by root and recipient of its lamports
+    let buffer = Pubkey::new_unique();
+    let buffer_account = loader_buffer_account(root_pda, &[1, 2, 3, 4]);
+    let recipient = Pubkey::new_unique();
+    let recipient_account = AccountSharedData::new(0, 0, &system_program);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Loader {
+                change: LoaderChange::CloseBuffer { buffer, recipient },
+                vault_index: None,
+            },
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(system_program, false), // 4: unused by loader proposal
+            AccountMeta::new(buffer, false),                  // 5: buffer (remaining[0])
+            AccountMeta::new(recipient, false),               // 6: recipient (remaining[1])
+            AccountMeta::new_readonly(loader_program, false), // 7: loader (remaining[2])
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (buffer, buffer_account.clone().into()),
+            (recipient, recipient_account.clone().into()),
+            (loader_program, loader_account.clone()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&buffer).lamports(0).build(),
+            Check::account(&recipient)
+                .lamports(LAMPORTS_PER_SOL)
+                .build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Executed);
+}
+
+#[test]
+fn test_execute_loader_upgrade_proposal() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    // loader refuses upgrading program in the slot it was deployed in
+    mollusk.warp_to_slot(1);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let (loader_program, loader_account) =
+        mollusk_svm::program::keyed_account_for_bpf_loader_v3_program();
+    let (rent_sysvar, rent_sysvar_account) = mollusk.sysvars.keyed_account_for_rent_sysvar();
+    let (clock_sysvar, clock_sysvar_account) = mollusk.sysvars.keyed_account_for_clock_sysvar();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // program upgraded by root with elf of the buffer, buffer lamports go to spill
+    let elf = std::fs::read("tests/spl_record.so").unwrap();
+    let program = Pubkey::new_unique();
+    let program_data = Pubkey::new_unique();
+    let program_account = loader_program_account(program_data);
+    let program_data_account = loader_program_data_account(Some(root_pda), 0, &vec![0; elf.len()]);
+    let buffer = Pubkey::new_unique();
+    let buffer_account = loader_buffer_account(root_pda, &elf);
+    let spill = Pubkey::new_unique();
+    let spill_account = AccountSharedData::new(0, 0, &system_program);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Loader {
+                change: LoaderChange::Upgrade {
+                    program,
+                    program_data,
+                    buffer,
+                    spill,
+                },
+                vault_index: None,
+            },
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(system_program, false), // 4: unused by loader proposal
+            AccountMeta::new(program_data, false),            // 5: program data (remaining[0])
+            AccountMeta::new(program, false),                 // 6: program (remaining[1])
+            AccountMeta::new(buffer, false),                  // 7: buffer (remaining[2])
+            AccountMeta::new(spill, false),                   // 8: spill (remaining[3])
+            AccountMeta::new_readonly(rent_sysvar, false),    // 9: rent sysvar (remaining[4])
+            AccountMeta::new_readonly(clock_sysvar, false),   // 10: clock sysvar (remaining[5])
+            AccountMeta::new_readonly(loader_program, false), // 11: loader (remaining[6])
+        ],
+    );
+
+    let program_data_len = LOADER_PROGRAM_DATA_HEADER_LEN + elf.len();
+    let program_data_rent = mollusk.sysvars.rent.minimum_balance(program_data_len);
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (program_data, program_data_account.clone().into()),
+            (program, program_account.clone().into()),
+            (buffer, buffer_account.clone().into()),
+            (spill, spill_account.clone().into()),
+            (rent_sysvar, rent_sysvar_account.clone()),
+            (clock_sysvar, clock_sysvar_account.clone()),
+            (loader_program, loader_account.clone()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&buffer).lamports(0).build(),
+            Check::account(&program_data)
+                .lamports(program_data_rent)
+                .space(program_data_len)
+                .build(),
+            Check::account(&spill)
+                .lamports(2 * LAMPORTS_PER_SOL - program_data_rent)
+                .build(),
+        ],
+    );
+
+    // program data holds buffer elf under unchanged upgrade authority
+    let updated_program_data_account = result.get_account(&program_data).unwrap();
+    assert!(updated_program_data_account.data[LOADER_PROGRAM_DATA_HEADER_LEN..] == elf[..]);
+    assert!(updated_program_data_account.data[12] == 1);
+    assert!(updated_program_data_account.data[13..45] == root_pda.to_bytes());
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Executed);
+}
+
+#[test]
+fn test_execute_loader_set_authority_proposal() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let (loader_program, loader_account) =
+        mollusk_svm::program::keyed_account_for_bpf_loader_v3_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // program data upgraded by root handed over to new authority
+    let program_data = Pubkey::new_unique();
+    let program_data_account = loader_program_data_account(Some(root_pda), 0, &[1, 2, 3, 4]);
+    let new_authority = Pubkey::new_unique();
+    let new_authority_account = AccountSharedData::new(0, 0, &system_program);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Loader {
+                change: LoaderChange::SetAuthority {
+                    account: program_data,
+                    new_authority: Some(new_authority),
+                },
+                vault_index: None,
+            },
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(system_program, false), // 4: unused by loader proposal
+            AccountMeta::new(program_data, false),            // 5: program data (remaining[0])
+            AccountMeta::new_readonly(new_authority, false),  // 6: new authority (remaining[1])
+            AccountMeta::new_readonly(loader_program, false), // 7: loader (remaining[2])
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (program_data, program_data_account.clone().into()),
+            (new_authority, new_authority_account.clone().into()),
+            (loader_program, loader_account.clone()),
+        ],
+        &[Check::success()],
+    );
+
+    // upgrade authority recorded in program data header
+    let updated_program_data_account = result.get_account(&program_data).unwrap();
+    assert!(updated_program_data_account.data[12] == 1);
+    assert!(updated_program_data_account.data[13..45] == new_authority.to_bytes());
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Executed);
+}
+
+#[test]
+fn test_execute_by_designated_executor() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, _signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        mut root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+    // bot outside of operators designated as the only executor
+    let executor = Pubkey::new_unique();
+    let executor_account = AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program);
+    root_pda_init_state.execute_policy = ExecutePolicy::Executors(vec![executor]);
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::ChangeTimelock(60)),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(executor, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (executor, executor_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::success()],
+    );
+
+    let updated_root_pda_account = result.get_account(&root_pda).unwrap();
+    let parsed_root_pda_data = Root::deserialize(&updated_root_pda_account.data).unwrap();
+
+    assert!(parsed_root_pda_data.timelock == 60);
+}
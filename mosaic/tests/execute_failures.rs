@@ -9,13 +9,22 @@ use {
 use mosaic::{
     errors::MosaicError,
     instructions::{Instruction as ProgramIx, execute::ExecuteIxData},
-    seeds::SIGNING_SESSION_PDA,
-    state::signing_session::{SigningSession, SigningSessionPhase},
+    programs::TOKEN_PROGRAM_ID,
+    seeds::{SIGNING_SESSION_PDA, VAULT_PDA},
+    state::{
+        expiry::Expiry,
+        proposal::{ConfigChange, LoaderChange, Proposal, TokenTransfer},
+        root::{ExecutePolicy, Operator, Permission},
+        signing_session::{SessionInstruction, SigningSession, SigningSessionPhase},
+    },
 };
 
+use pinocchio::cpi::MAX_CPI_ACCOUNTS;
+
 use solana_sdk::{
     account::AccountSharedData,
     instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
     program_error::ProgramError,
     pubkey::Pubkey,
 };
@@ -68,8 +77,8 @@ fn test_execute_payer_is_not_signer_failure() {
             &mollusk,
             session_id,
             root_pda,
-            vec![payer, operators_pubkey[1]], // approvals
-            SigningSessionPhase::Approved,    // signing session phase / must be Approved to Execute
+            votes_of(&operators_pubkey, &[payer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved, // signing session phase / must be Approved to Execute
             cpi_instruction_accounts,
             cpi_instruction_data,
         );
@@ -90,7 +99,7 @@ fn test_execute_payer_is_not_signer_failure() {
         PROGRAM_ID,
         &data_execute,
         vec![
-            AccountMeta::new(payer.into(), false),
+            AccountMeta::new(payer, false),
             AccountMeta::new_readonly(root_pda, false),
             AccountMeta::new(signing_pda, false),
             AccountMeta::new(storage_pda, false),
@@ -101,7 +110,7 @@ fn test_execute_payer_is_not_signer_failure() {
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (payer.into(), signer_account.clone().into()),
+            (payer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
             (storage_pda, storage_pda_account.clone().into()),
@@ -162,8 +171,8 @@ fn test_re_execute_failure() {
             &mollusk,
             session_id,
             root_pda,
-            vec![signer, operators_pubkey[1]], // approvals
-            SigningSessionPhase::Executed,     // signing session phase
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Executed,                               // signing session phase
             cpi_instruction_accounts,
             cpi_instruction_data,
         );
@@ -184,7 +193,7 @@ fn test_re_execute_failure() {
         PROGRAM_ID,
         &data_execute,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new_readonly(root_pda, false),
             AccountMeta::new(signing_pda, false),
             AccountMeta::new(storage_pda, false),
@@ -195,7 +204,7 @@ fn test_re_execute_failure() {
     let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
             (storage_pda, storage_pda_account.clone().into()),
@@ -212,7 +221,7 @@ fn test_re_execute_failure() {
 
     let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
     let _parsed_signing_session_pda_data =
-        borsh::from_slice::<SigningSession>(&updated_signing_session_pda_account.data).unwrap();
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
 
     let copy_of_initial_storage_data = &storage_pda_account.clone().into();
     let updated_storage_pda_account = result.get_account(&storage_pda).unwrap();
@@ -268,8 +277,8 @@ fn test_execute_active_phase_failure() {
             &mollusk,
             session_id,
             root_pda,
-            vec![signer, operators_pubkey[1]], // approvals
-            SigningSessionPhase::Active,       // signing session phase
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Active,                                 // signing session phase
             cpi_instruction_accounts,
             cpi_instruction_data,
         );
@@ -290,7 +299,7 @@ fn test_execute_active_phase_failure() {
         PROGRAM_ID,
         &data_execute,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new_readonly(root_pda, false),
             AccountMeta::new(signing_pda, false),
             AccountMeta::new(storage_pda, false),
@@ -301,7 +310,7 @@ fn test_execute_active_phase_failure() {
     let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
             (storage_pda, storage_pda_account.clone().into()),
@@ -318,7 +327,7 @@ fn test_execute_active_phase_failure() {
 
     let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
     let _parsed_signing_session_pda_data =
-        borsh::from_slice::<SigningSession>(&updated_signing_session_pda_account.data).unwrap();
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
 
     let copy_of_initial_storage_data = &storage_pda_account.clone().into();
     let updated_storage_pda_account = result.get_account(&storage_pda).unwrap();
@@ -364,7 +373,7 @@ fn test_execute_signing_session_not_writable_failure() {
         &mollusk,
         session_id,
         root_pda,
-        vec![signer, operators_pubkey[1]],
+        votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]),
         SigningSessionPhase::Approved,
         cpi_instruction_accounts,
         cpi_instruction_data,
@@ -384,7 +393,7 @@ fn test_execute_signing_session_not_writable_failure() {
         PROGRAM_ID,
         &data_execute,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new_readonly(root_pda, false),
             AccountMeta::new_readonly(signing_pda, false),
             AccountMeta::new(storage_pda, false),
@@ -396,7 +405,7 @@ fn test_execute_signing_session_not_writable_failure() {
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.into()),
+            (signer, signer_account.into()),
             (root_pda, root_account.into()),
             (signing_pda, signing_account.into()),
             (storage_pda, storage_pda_account.into()),
@@ -444,7 +453,7 @@ fn test_execute_root_incorrect_owner_failure() {
         &mollusk,
         session_id,
         root_pda,
-        vec![signer, operators_pubkey[1]],
+        votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]),
         SigningSessionPhase::Approved,
         cpi_instruction_accounts,
         cpi_instruction_data,
@@ -464,7 +473,7 @@ fn test_execute_root_incorrect_owner_failure() {
         PROGRAM_ID,
         &data_execute,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new_readonly(root_pda, false),
             AccountMeta::new(signing_pda, false),
             AccountMeta::new(storage_pda, false),
@@ -476,7 +485,7 @@ fn test_execute_root_incorrect_owner_failure() {
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.into()),
+            (signer, signer_account.into()),
             (root_pda, root_account.into()),
             (signing_pda, signing_account.into()),
             (storage_pda, storage_pda_account.into()),
@@ -522,14 +531,22 @@ fn test_execute_signing_session_incorrect_owner_failure() {
 
     let signing_init_state = SigningSession {
         session_id,
-        root_pda: root_pda,
+        root_pda,
+        proposer: signer,
+        rent_payer: signer,
         phase: SigningSessionPhase::Approved,
-        approvals: vec![signer, operators_pubkey[1]],
-        instruction_data: vec![],
-        instruction_accounts: vec![],
+        proposal: Proposal::Cpi,
+        approvals: votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]),
+        rejections: 0,
+        operators_epoch: 0,
+        instructions: vec![],
+        expires_at: None,
+        approved_at: None,
+        vault_index: 0,
+        vault_bump: 0,
         bump: 0,
     };
-    let signing_data = borsh::to_vec(&signing_init_state).unwrap();
+    let (signing_data, _) = signing_init_state.serialize().unwrap();
     signing_account.set_data_from_slice(&signing_data);
 
     let (storage_pda, storage_pda_account) =
@@ -546,7 +563,7 @@ fn test_execute_signing_session_incorrect_owner_failure() {
         PROGRAM_ID,
         &data_execute,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new_readonly(root_pda, false),
             AccountMeta::new(signing_pda, false),
             AccountMeta::new(storage_pda, false),
@@ -558,7 +575,7 @@ fn test_execute_signing_session_incorrect_owner_failure() {
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.into()),
+            (signer, signer_account.into()),
             (root_pda, root_account.into()),
             (signing_pda, signing_account.into()),
             (storage_pda, storage_pda_account.into()),
@@ -622,7 +639,7 @@ fn test_execute_signing_session_not_initialized_failure() {
         PROGRAM_ID,
         &data_execute,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new_readonly(root_pda, false),
             AccountMeta::new(signing_pda, false),
             AccountMeta::new(storage_pda, false),
@@ -634,7 +651,7 @@ fn test_execute_signing_session_not_initialized_failure() {
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.into()),
+            (signer, signer_account.into()),
             (root_pda, root_account.into()),
             (signing_pda, signing_account.into()),
             (storage_pda, storage_pda_account.into()),
@@ -648,7 +665,7 @@ fn test_execute_signing_session_not_initialized_failure() {
 }
 
 #[test]
-fn test_execute_but_session_id_does_not_equal_root_last_id_failure() {
+fn test_execute_session_stored_id_mismatch_failure() {
     let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
     mollusk.add_program(&DESTINATION_PROGRAM_ID, "tests/spl_record");
 
@@ -663,8 +680,9 @@ fn test_execute_but_session_id_does_not_equal_root_last_id_failure() {
         .collect();
     let (signer, signer_account) = operators.operators[0].clone();
 
-    // used in root pda as last_id and in signing session as id
-    let session_id = 0;
+    // root last id is ahead of both sessions
+    let root_last_id = 5;
+    let session_id = 1;
 
     // root
     let (
@@ -677,34 +695,38 @@ fn test_execute_but_session_id_does_not_equal_root_last_id_failure() {
         &mollusk,
         operators,
         operators_pubkey.clone(),
-        session_id,
+        root_last_id,
         DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
     );
 
     // storage
-    let (storage_pda, _storage_pda_account) =
+    let (storage_pda, storage_pda_account) =
         prepare_storage_account(&mollusk, session_id, root_pda);
 
     // record program accounts and instruction data
     let (cpi_instruction_accounts, cpi_instruction_data) =
         records_program_ix_accs(storage_pda, root_pda);
 
-    // signing session
-    let wrong_session_id_does_not_equal_root = session_id + 9;
-    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+    // session data stored under pda of another session id
+    let (_signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
         prepare_signing_session(
             &mollusk,
-            wrong_session_id_does_not_equal_root,
+            session_id,
             root_pda,
-            vec![signer, operators_pubkey[1]], // approvals
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
             SigningSessionPhase::Approved, // signing session phase / must be Approved to Execute
-            cpi_instruction_accounts,
-            cpi_instruction_data,
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data.clone(),
         );
-
-    // storage
-    let (storage_pda, storage_pda_account) =
-        prepare_storage_account(&mollusk, session_id, root_pda);
+    let (other_signing_pda, _, _, _) = prepare_signing_session(
+        &mollusk,
+        session_id + 1,
+        root_pda,
+        0,
+        SigningSessionPhase::Approved,
+        cpi_instruction_accounts,
+        cpi_instruction_data,
+    );
 
     // execute
     let ix_data_execute = ExecuteIxData {};
@@ -718,24 +740,2513 @@ fn test_execute_but_session_id_does_not_equal_root_last_id_failure() {
         PROGRAM_ID,
         &data_execute,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new_readonly(root_pda, false),
-            AccountMeta::new(signing_pda, false),
-            AccountMeta::new(storage_pda, false),
+            AccountMeta::new(other_signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
             AccountMeta::new_readonly(DESTINATION_PROGRAM_ID, false),
+            AccountMeta::new(storage_pda, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (other_signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (DESTINATION_PROGRAM_ID, dst_program_account.clone().into()),
+            (storage_pda, storage_pda_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn test_execute_config_change_root_not_writable_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        __root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::AddOperator(Operator {
+                key: Pubkey::new_unique(),
+                weight: 1,
+                permissions: Permission::ALL,
+            })),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false), // root must be writable for config changes
+            AccountMeta::new(signing_pda, false),
             AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
         ],
     );
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::RootAccountMustBeWrittable as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_add_existing_operator_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        __root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::AddOperator(Operator {
+                key: operators_pubkey[2],
+                weight: 1,
+                permissions: Permission::ALL,
+            })),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::OperatorAlreadyExists as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_remove_unknown_operator_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        __root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::RemoveOperator(Pubkey::new_unique())),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
-            (storage_pda, storage_pda_account.clone().into()),
-            (DESTINATION_PROGRAM_ID, dst_program_account.clone().into()),
             (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
         ],
-        &[Check::err(ProgramError::InvalidSeeds)],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::OperatorNotFound as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_approvals_before_operator_removal_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        __root_pda_bump,
+        mut root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // operator was removed after votes were casted; its approval no longer counts
+    root_pda_init_state.operators[1] = Operator::REMOVED;
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::AddOperator(Operator {
+                key: Pubkey::new_unique(),
+                weight: 1,
+                permissions: Permission::ALL,
+            })),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::ApprovalsDidNotReachThreshold as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_approvals_before_operator_replacement_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        __root_pda_bump,
+        mut root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // slot was handed to a new operator after votes were casted; its previous holder's approval
+    // no longer counts
+    root_pda_init_state.operators[1].key = Pubkey::new_unique();
+    root_pda_init_state.operators_epoch = 1;
+    root_pda_init_state.operator_epochs[1] = 1;
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::AddOperator(Operator {
+                key: Pubkey::new_unique(),
+                weight: 1,
+                permissions: Permission::ALL,
+            })),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::ApprovalsDidNotReachThreshold as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_change_threshold_to_zero_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        __root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::ChangeThreshold(0)),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::ThresholdMustNotBeZero as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_change_threshold_above_operators_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        __root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::ChangeThreshold(4)),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::ThresholdMustNotExceedOperators as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_expired_session_failure() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    mollusk.add_program(&DESTINATION_PROGRAM_ID, "tests/spl_record");
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let dst_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // storage
+    let (storage_pda, storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, signing_init_state_serialized, _signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved, // signing session phase / must be Approved to Execute
+            cpi_instruction_accounts,
+            cpi_instruction_data,
+        );
+
+    // session expired at slot 10
+    let mut signing_init_state =
+        SigningSession::deserialize(&signing_init_state_serialized).unwrap();
+    signing_init_state.expires_at = Some(Expiry::Slot(10));
+    let signing_account = signing_session_account_from_state(&mollusk, &signing_init_state);
+    mollusk.warp_to_slot(10);
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(DESTINATION_PROGRAM_ID, false), // 4: DESTINATION_PROGRAM_ID
+            AccountMeta::new(storage_pda, false),             // 5: storage_pda (remaining[0])
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()), // 0: payer/signer
+            (root_pda, root_account.clone().into()), // 1: root_pda
+            (signing_pda, signing_account.clone().into()), // 2: signing_pda
+            (system_program, system_account.clone()), // 3: system_program
+            (DESTINATION_PROGRAM_ID, dst_program_account.clone().into()), // 4: DESTINATION_PROGRAM_ID
+            (storage_pda, storage_pda_account.clone().into()), // 5: storage_pda (remaining[0])
+        ],
+        &[
+            Check::err(ProgramError::Custom(
+                MosaicError::SigningSessionExpired as u32,
+            )),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let copy_of_initial_storage_data = &storage_pda_account.clone().into();
+    let updated_storage_pda_account = result.get_account(&storage_pda).unwrap();
+
+    assert!(updated_storage_pda_account == copy_of_initial_storage_data);
+}
+
+#[test]
+fn test_execute_before_timelock_elapsed_failure() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    mollusk.add_program(&DESTINATION_PROGRAM_ID, "tests/spl_record");
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let dst_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        mut root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // root requires 100 seconds between approval and execution
+    root_pda_init_state.timelock = 100;
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // storage
+    let (storage_pda, storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, signing_init_state_serialized, _signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved, // signing session phase / must be Approved to Execute
+            cpi_instruction_accounts,
+            cpi_instruction_data,
+        );
+
+    // session approved at unix timestamp 1_000
+    let mut signing_init_state =
+        SigningSession::deserialize(&signing_init_state_serialized).unwrap();
+    signing_init_state.approved_at = Some(1_000);
+    let signing_account = signing_session_account_from_state(&mollusk, &signing_init_state);
+    mollusk.sysvars.clock.unix_timestamp = 1_099;
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(DESTINATION_PROGRAM_ID, false), // 4: DESTINATION_PROGRAM_ID
+            AccountMeta::new(storage_pda, false),             // 5: storage_pda (remaining[0])
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()), // 0: payer/signer
+            (root_pda, root_account.clone().into()), // 1: root_pda
+            (signing_pda, signing_account.clone().into()), // 2: signing_pda
+            (system_program, system_account.clone()), // 3: system_program
+            (DESTINATION_PROGRAM_ID, dst_program_account.clone().into()), // 4: DESTINATION_PROGRAM_ID
+            (storage_pda, storage_pda_account.clone().into()), // 5: storage_pda (remaining[0])
+        ],
+        &[
+            Check::err(ProgramError::Custom(MosaicError::TimelockNotElapsed as u32)),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let copy_of_initial_storage_data = &storage_pda_account.clone().into();
+    let updated_storage_pda_account = result.get_account(&storage_pda).unwrap();
+
+    assert!(updated_storage_pda_account == copy_of_initial_storage_data);
+}
+
+#[test]
+fn test_execute_batched_instruction_failure_reverts_all() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    mollusk.add_program(&DESTINATION_PROGRAM_ID, "tests/spl_record");
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let dst_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // storage
+    let (storage_pda, storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, signing_init_state_serialized, _signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved, // signing session phase / must be Approved to Execute
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data,
+        );
+
+    // first write is valid, second one writes past the end of the record
+    let mut signing_init_state =
+        SigningSession::deserialize(&signing_init_state_serialized).unwrap();
+    signing_init_state.instructions = vec![
+        SessionInstruction {
+            program_id: DESTINATION_PROGRAM_ID,
+            data: records_program_write_data(0, &[0x2A]),
+            accounts: cpi_instruction_accounts.clone(),
+        },
+        SessionInstruction {
+            program_id: DESTINATION_PROGRAM_ID,
+            data: records_program_write_data(8, &[0x2B]),
+            accounts: cpi_instruction_accounts,
+        },
+    ];
+    let signing_account = signing_session_account_from_state(&mollusk, &signing_init_state);
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(DESTINATION_PROGRAM_ID, false), // 4: DESTINATION_PROGRAM_ID
+            AccountMeta::new(storage_pda, false),             // 5: storage_pda (remaining[0])
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()), // 0: payer/signer
+            (root_pda, root_account.clone().into()), // 1: root_pda
+            (signing_pda, signing_account.clone().into()), // 2: signing_pda
+            (system_program, system_account.clone()), // 3: system_program
+            (DESTINATION_PROGRAM_ID, dst_program_account.clone().into()), // 4: DESTINATION_PROGRAM_ID
+            (storage_pda, storage_pda_account.clone().into()), // 5: storage_pda (remaining[0])
+        ],
+    );
+
+    assert!(result.program_result.is_err());
+
+    let copy_of_initial_storage_data = &storage_pda_account.clone().into();
+    let updated_storage_pda_account = result.get_account(&storage_pda).unwrap();
+
+    assert!(updated_storage_pda_account == copy_of_initial_storage_data);
+}
+
+#[test]
+fn test_execute_instruction_program_mismatch_failure() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    mollusk.add_program(&DESTINATION_PROGRAM_ID, "tests/spl_record");
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let dst_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // storage
+    let (storage_pda, storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, signing_init_state_serialized, _signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved, // signing session phase / must be Approved to Execute
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data.clone(),
+        );
+
+    // second instruction targets a program outside of allowed ones
+    let mut signing_init_state =
+        SigningSession::deserialize(&signing_init_state_serialized).unwrap();
+    signing_init_state.instructions.push(SessionInstruction {
+        program_id: Pubkey::new_unique(),
+        data: cpi_instruction_data,
+        accounts: cpi_instruction_accounts,
+    });
+    let signing_account = signing_session_account_from_state(&mollusk, &signing_init_state);
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(DESTINATION_PROGRAM_ID, false), // 4: DESTINATION_PROGRAM_ID
+            AccountMeta::new(storage_pda, false),             // 5: storage_pda (remaining[0])
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()), // 0: payer/signer
+            (root_pda, root_account.clone().into()), // 1: root_pda
+            (signing_pda, signing_account.clone().into()), // 2: signing_pda
+            (system_program, system_account.clone()), // 3: system_program
+            (DESTINATION_PROGRAM_ID, dst_program_account.clone().into()), // 4: DESTINATION_PROGRAM_ID
+            (storage_pda, storage_pda_account.clone().into()), // 5: storage_pda (remaining[0])
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::ProgramNotAllowed as u32,
+        ))],
+    );
+
+    let copy_of_initial_storage_data = &storage_pda_account.clone().into();
+    let updated_storage_pda_account = result.get_account(&storage_pda).unwrap();
+
+    assert!(updated_storage_pda_account == copy_of_initial_storage_data);
+}
+
+#[test]
+fn test_execute_add_existing_allowed_program_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        __root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::AddAllowedProgram(DESTINATION_PROGRAM_ID)),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::AllowedProgramAlreadyExists as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_change_default_ttl_to_negative_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        __root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::ChangeDefaultTtl(Some(Expiry::UnixTimestamp(
+                -1,
+            )))),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::DefaultTtlMustBePositive as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_add_mosaic_allowed_program_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        __root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::AddAllowedProgram(PROGRAM_ID)),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::MosaicProgramNotAllowed as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_remove_unknown_allowed_program_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        __root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::RemoveAllowedProgram(Pubkey::new_unique())),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::AllowedProgramNotFound as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_instruction_above_cpi_account_limit_failure() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    mollusk.add_program(&DESTINATION_PROGRAM_ID, "tests/spl_record");
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let dst_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // storage
+    let (storage_pda, storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts padded one past runtime cpi account limit
+    let (mut cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+    let (padding_instruction_accounts, padding_accounts) =
+        padding_instruction_accounts(MAX_CPI_ACCOUNTS - 1);
+    cpi_instruction_accounts.extend(padding_instruction_accounts);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            cpi_instruction_accounts,
+            cpi_instruction_data,
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let mut account_metas = vec![
+        AccountMeta::new(signer, true),
+        AccountMeta::new_readonly(root_pda, false),
+        AccountMeta::new(signing_pda, false),
+        AccountMeta::new_readonly(system_program, false),
+        AccountMeta::new_readonly(DESTINATION_PROGRAM_ID, false),
+        AccountMeta::new(storage_pda, false),
+    ];
+    let mut accounts = vec![
+        (signer, signer_account.clone().into()),
+        (root_pda, root_account.clone().into()),
+        (signing_pda, signing_account.clone().into()),
+        (system_program, system_account.clone()),
+        (DESTINATION_PROGRAM_ID, dst_program_account.clone().into()),
+        (storage_pda, storage_pda_account.clone().into()),
+    ];
+    for (pubkey, account) in padding_accounts {
+        account_metas.push(AccountMeta::new_readonly(pubkey, false));
+        accounts.push((pubkey, account.into()));
+    }
+
+    let instruction = Instruction::new_with_bytes(PROGRAM_ID, &data_execute, account_metas);
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+
+    let copy_of_initial_storage_data = &storage_pda_account.clone().into();
+    let updated_storage_pda_account = result.get_account(&storage_pda).unwrap();
+
+    assert!(updated_storage_pda_account == copy_of_initial_storage_data);
+}
+
+#[test]
+fn test_execute_transfer_from_other_vault_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root allowed to cpi into system program
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        system_program,
+    );
+
+    // vault holding SOL and recipient of the transfer
+    let (vault_pda, _vault_pda_bump) = find_vault_pda(&root_pda, 1);
+    let vault_account = AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program);
+    let recipient = Pubkey::new_unique();
+    let recipient_account = AccountSharedData::new(0, 0, &system_program);
+    let transfer_lamports = LAMPORTS_PER_SOL / 2;
+
+    // signing session signed by vault 0 while transfer drains vault 1
+    let (signing_pda, _signing_pda_bump, signing_init_state_serialized, _signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            vec![],
+            vec![],
+        );
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        system_transfer_ix_accs(vault_pda, recipient, transfer_lamports);
+    let mut signing_init_state =
+        SigningSession::deserialize(&signing_init_state_serialized).unwrap();
+    signing_init_state.instructions = vec![SessionInstruction {
+        program_id: system_program,
+        data: cpi_instruction_data,
+        accounts: cpi_instruction_accounts,
+    }];
+    let signing_account = signing_session_account_from_state(&mollusk, &signing_init_state);
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(system_program, false), // 4: targeted program
+            AccountMeta::new(vault_pda, false),               // 5: vault_pda (remaining[0])
+            AccountMeta::new(recipient, false),               // 6: recipient (remaining[1])
+        ],
+    );
+
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (vault_pda, vault_account.clone().into()),
+            (recipient, recipient_account.clone().into()),
+        ],
+    );
+
+    assert!(result.program_result.is_err());
+
+    let updated_vault_account = result.get_account(&vault_pda).unwrap();
+    assert!(updated_vault_account.lamports == LAMPORTS_PER_SOL);
+}
+
+#[test]
+fn test_execute_transfer_proposal_vault_mismatch_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // vault other than the one of the proposal
+    let (vault_pda, _vault_pda_bump) = find_vault_pda(&root_pda, 0);
+    let vault_account = AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program);
+    let recipient = Pubkey::new_unique();
+    let recipient_account = AccountSharedData::new(0, 0, &system_program);
+    let transfer_lamports = LAMPORTS_PER_SOL / 2;
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Transfer {
+                recipient,
+                amount: transfer_lamports,
+                vault_index: 1,
+            },
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(system_program, false), // 4: unused by transfer
+            AccountMeta::new(vault_pda, false),               // 5: vault_pda (remaining[0])
+            AccountMeta::new(recipient, false),               // 6: recipient (remaining[1])
+        ],
+    );
+
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (vault_pda, vault_account.clone().into()),
+            (recipient, recipient_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::VaultAccountMismatch as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_transfer_proposal_non_canonical_vault_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // vault of the proposal derived with a bump below the canonical one
+    let (_, canonical_vault_bump) = find_vault_pda(&root_pda, 1);
+    let vault_pda = (0..canonical_vault_bump)
+        .rev()
+        .find_map(|bump| {
+            Pubkey::create_program_address(
+                &[VAULT_PDA, root_pda.as_ref(), &[1], &[bump]],
+                &PROGRAM_ID,
+            )
+            .ok()
+        })
+        .unwrap();
+    let vault_account = AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program);
+    let recipient = Pubkey::new_unique();
+    let recipient_account = AccountSharedData::new(0, 0, &system_program);
+    let transfer_lamports = LAMPORTS_PER_SOL / 2;
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Transfer {
+                recipient,
+                amount: transfer_lamports,
+                vault_index: 1,
+            },
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(system_program, false), // 4: unused by transfer
+            AccountMeta::new(vault_pda, false),               // 5: vault_pda (remaining[0])
+            AccountMeta::new(recipient, false),               // 6: recipient (remaining[1])
+        ],
+    );
+
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (vault_pda, vault_account.clone().into()),
+            (recipient, recipient_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::VaultAccountMismatch as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_transfer_proposal_recipient_mismatch_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // vault holding SOL and recipient other than the one of the proposal
+    let (vault_pda, _vault_pda_bump) = find_vault_pda(&root_pda, 1);
+    let vault_account = AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program);
+    let recipient = Pubkey::new_unique();
+    let recipient_account = AccountSharedData::new(0, 0, &system_program);
+    let transfer_lamports = LAMPORTS_PER_SOL / 2;
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Transfer {
+                recipient: Pubkey::new_unique(),
+                amount: transfer_lamports,
+                vault_index: 1,
+            },
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(system_program, false), // 4: unused by transfer
+            AccountMeta::new(vault_pda, false),               // 5: vault_pda (remaining[0])
+            AccountMeta::new(recipient, false),               // 6: recipient (remaining[1])
+        ],
+    );
+
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (vault_pda, vault_account.clone().into()),
+            (recipient, recipient_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::RecipientAccountMismatch as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_token_transfer_destination_mismatch_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let token_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // token accounts of the transfer
+    let mint = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let token_program = Pubkey::new_from_array(TOKEN_PROGRAM_ID.to_bytes());
+    let (vault_pda, _vault_pda_bump) = find_vault_pda(&root_pda, 0);
+    let passed_destination = Pubkey::new_unique(); // other than proposed destination
+    let passed_vault = vault_pda;
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::TokenTransfer(TokenTransfer {
+                token_program,
+                mint,
+                source,
+                destination,
+                amount: 1_000,
+                decimals: 6,
+                vault_index: Some(0),
+            }),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(system_program, false), // 4: unused by token transfer
+            AccountMeta::new(source, false),                  // 5: source (remaining[0])
+            AccountMeta::new_readonly(mint, false),           // 6: mint (remaining[1])
+            AccountMeta::new(passed_destination, false),      // 7: destination (remaining[2])
+            AccountMeta::new_readonly(token_program, false),  // 8: token program (remaining[3])
+            AccountMeta::new(passed_vault, false),            // 9: vault_pda (remaining[4])
+        ],
+    );
+
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (source, AccountSharedData::default().into()),
+            (mint, AccountSharedData::default().into()),
+            (passed_destination, AccountSharedData::default().into()),
+            (token_program, token_program_account.clone().into()),
+            (passed_vault, AccountSharedData::default().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::TokenAccountMismatch as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_token_transfer_vault_mismatch_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let token_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // token accounts of the transfer
+    let mint = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let token_program = Pubkey::new_from_array(TOKEN_PROGRAM_ID.to_bytes());
+    let passed_destination = destination;
+    let passed_vault = find_vault_pda(&root_pda, 1).0; // other than proposed vault
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::TokenTransfer(TokenTransfer {
+                token_program,
+                mint,
+                source,
+                destination,
+                amount: 1_000,
+                decimals: 6,
+                vault_index: Some(0),
+            }),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(system_program, false), // 4: unused by token transfer
+            AccountMeta::new(source, false),                  // 5: source (remaining[0])
+            AccountMeta::new_readonly(mint, false),           // 6: mint (remaining[1])
+            AccountMeta::new(passed_destination, false),      // 7: destination (remaining[2])
+            AccountMeta::new_readonly(token_program, false),  // 8: token program (remaining[3])
+            AccountMeta::new(passed_vault, false),            // 9: vault_pda (remaining[4])
+        ],
+    );
+
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (source, AccountSharedData::default().into()),
+            (mint, AccountSharedData::default().into()),
+            (passed_destination, AccountSharedData::default().into()),
+            (token_program, token_program_account.clone().into()),
+            (passed_vault, AccountSharedData::default().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::VaultAccountMismatch as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_loader_buffer_mismatch_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let (loader_program, loader_account) =
+        mollusk_svm::program::keyed_account_for_bpf_loader_v3_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // buffer of the proposal
+    let buffer = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let passed_buffer = Pubkey::new_unique(); // other than proposed buffer
+    let passed_loader = loader_program;
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Loader {
+                change: LoaderChange::CloseBuffer { buffer, recipient },
+                vault_index: None,
+            },
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(system_program, false), // 4: unused by loader proposal
+            AccountMeta::new(passed_buffer, false),           // 5: buffer (remaining[0])
+            AccountMeta::new(recipient, false),               // 6: recipient (remaining[1])
+            AccountMeta::new_readonly(passed_loader, false),  // 7: loader (remaining[2])
+        ],
+    );
+
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (passed_buffer, loader_buffer_account(root_pda, &[]).into()),
+            (recipient, AccountSharedData::default().into()),
+            (passed_loader, loader_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::LoaderAccountMismatch as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_loader_program_mismatch_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // buffer of the proposal
+    let buffer = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let passed_buffer = buffer;
+    let passed_loader = Pubkey::new_unique(); // other than upgradeable loader
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Loader {
+                change: LoaderChange::CloseBuffer { buffer, recipient },
+                vault_index: None,
+            },
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),                   // 0: payer/signer
+            AccountMeta::new_readonly(root_pda, false),       // 1: root_pda
+            AccountMeta::new(signing_pda, false),             // 2: signing_pda
+            AccountMeta::new_readonly(system_program, false), // 3: system_program
+            AccountMeta::new_readonly(system_program, false), // 4: unused by loader proposal
+            AccountMeta::new(passed_buffer, false),           // 5: buffer (remaining[0])
+            AccountMeta::new(recipient, false),               // 6: recipient (remaining[1])
+            AccountMeta::new_readonly(passed_loader, false),  // 7: loader (remaining[2])
+        ],
+    );
+
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (passed_buffer, loader_buffer_account(root_pda, &[]).into()),
+            (recipient, AccountSharedData::default().into()),
+            (passed_loader, AccountSharedData::default().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::LoaderAccountMismatch as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_executor_lacks_permission_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        __root_pda_bump,
+        mut root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+    // signer may propose and vote but not execute
+    root_pda_init_state.operators[0].permissions =
+        Permission::Initiate as u8 | Permission::Vote as u8;
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::ChangeTimelock(60)),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::OperatorLacksPermission as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_executor_is_not_operator_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, _signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        __root_pda_bump,
+        root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // wallet outside of operators triggering execution
+    let executor = Pubkey::new_unique();
+    let executor_account = AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::ChangeTimelock(60)),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(executor, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (executor, executor_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::SignerIsNotOperator as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_execute_executor_not_designated_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let mosaic_program_account = AccountSharedData::new(0, 0, &solana_sdk::bpf_loader::id());
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        __root_pda_bump,
+        mut root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+    // only designated bot may execute, operators included
+    root_pda_init_state.execute_policy = ExecutePolicy::Executors(vec![Pubkey::new_unique()]);
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // signing session
+    let (signing_pda, _signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_proposal(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Approved,
+            Proposal::Config(ConfigChange::ChangeTimelock(60)),
+            vec![],
+            vec![],
+        );
+
+    // execute
+    let ix_data_execute = ExecuteIxData {};
+    let data_execute = [
+        vec![ProgramIx::Execute as u8],
+        to_vec(&ix_data_execute).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_execute,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+            AccountMeta::new_readonly(PROGRAM_ID, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+            (PROGRAM_ID, mosaic_program_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::ExecutorNotAllowed as u32,
+        ))],
     );
 }
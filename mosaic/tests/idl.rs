@@ -0,0 +1,523 @@
+//! generates a shank style idl from program sources and checks it against the committed one;
+//! run with `MOSAIC_UPDATE_IDL=1` to rewrite `idl/mosaic.json` after interface changes.
+
+use std::{collections::HashMap, env, fs, path::Path};
+
+use serde_json::{Map, Value, json};
+use syn::{
+    Attribute, Expr, ExprLit, Fields, File, GenericArgument, Item, ItemEnum, ItemStruct, Lit, Meta,
+    Pat, PathArguments, Stmt, Type,
+};
+
+const IDL_PATH: &str = "./idl/mosaic.json";
+
+/// first custom error code; `MosaicError` discriminants count up from it
+const FIRST_ERROR_CODE: u64 = 6000;
+
+/// accounts stored by the program as fixed header followed by borsh encoded rest; both are
+/// flattened into the account, every other borsh type goes to `types`
+const ACCOUNT_LAYOUTS: [(&str, &str, &str); 2] = [
+    ("Root", "RootHeader", "RootConfig"),
+    ("SigningSession", "SigningSessionHeader", "SessionPayload"),
+];
+
+/// sources of account and defined types, in idl order
+const TYPE_SOURCES: [&str; 5] = [
+    "src/state/root.rs",
+    "src/state/signing_session.rs",
+    "src/state/proposal.rs",
+    "src/state/expiry.rs",
+    "src/events.rs",
+];
+
+fn parse(path: &str) -> File {
+    let source = fs::read_to_string(path).unwrap_or_else(|e| panic!("reading {path}: {e}"));
+    syn::parse_file(&source).unwrap_or_else(|e| panic!("parsing {path}: {e}"))
+}
+
+fn camel_case(name: &str) -> String {
+    let mut camel = String::new();
+    let mut upper = false;
+    for c in name.trim_start_matches('_').chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            camel.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    let mut chars = camel.chars();
+    chars
+        .next()
+        .map(|first| first.to_lowercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn docs(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(meta) => match &meta.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(doc), ..
+                }) => Some(doc.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+fn with_docs(mut value: Map<String, Value>, attrs: &[Attribute]) -> Value {
+    let docs = docs(attrs);
+    if !docs.is_empty() {
+        value.insert("docs".to_string(), json!(docs));
+    }
+    Value::Object(value)
+}
+
+fn derives_borsh(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .any(|attr| {
+            let mut borsh = false;
+            let _ = attr.parse_nested_meta(|meta| {
+                borsh |= meta.path.segments.last().unwrap().ident == "BorshSerialize";
+                Ok(())
+            });
+            borsh
+        })
+}
+
+fn idl_type(ty: &Type) -> Value {
+    match ty {
+        Type::Array(array) => {
+            let len = match &array.len {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Int(len), ..
+                }) => len.base10_parse::<u64>().unwrap(),
+                Expr::Path(path) if path.path.is_ident("MAX_SEED_LEN") => 32,
+                _ => panic!("unsupported array length"),
+            };
+            json!({ "array": [idl_type(&array.elem), len] })
+        }
+        Type::Path(path) => {
+            let segment = path.path.segments.last().unwrap();
+            let inner = || match &segment.arguments {
+                PathArguments::AngleBracketed(args) => match args.args.first() {
+                    Some(GenericArgument::Type(inner)) => idl_type(inner),
+                    _ => panic!("unsupported generic argument of {}", segment.ident),
+                },
+                _ => panic!("missing generic argument of {}", segment.ident),
+            };
+            match segment.ident.to_string().as_str() {
+                primitive @ ("bool" | "u8" | "u16" | "u32" | "u64" | "i64") => json!(primitive),
+                "String" => json!("string"),
+                "Address" => json!("publicKey"),
+                "Vec" => json!({ "vec": inner() }),
+                "Option" => json!({ "option": inner() }),
+                defined => json!({ "defined": defined }),
+            }
+        }
+        _ => panic!("unsupported type"),
+    }
+}
+
+fn idl_fields(fields: &Fields) -> Vec<Value> {
+    fields
+        .iter()
+        .map(|field| {
+            let name = field.ident.as_ref().expect("named field").to_string();
+            let mut value = Map::new();
+            value.insert("name".to_string(), json!(camel_case(&name)));
+            value.insert("type".to_string(), idl_type(&field.ty));
+            with_docs(value, &field.attrs)
+        })
+        .collect()
+}
+
+fn idl_struct(item: &ItemStruct) -> Value {
+    let mut value = Map::new();
+    value.insert("name".to_string(), json!(item.ident.to_string()));
+    value.insert(
+        "type".to_string(),
+        json!({ "kind": "struct", "fields": idl_fields(&item.fields) }),
+    );
+    with_docs(value, &item.attrs)
+}
+
+fn idl_enum(item: &ItemEnum) -> Value {
+    let variants = item
+        .variants
+        .iter()
+        .map(|variant| {
+            let mut value = Map::new();
+            value.insert("name".to_string(), json!(variant.ident.to_string()));
+            match &variant.fields {
+                Fields::Unit => {}
+                Fields::Named(_) => {
+                    value.insert("fields".to_string(), json!(idl_fields(&variant.fields)));
+                }
+                Fields::Unnamed(fields) => {
+                    let types: Vec<Value> =
+                        fields.unnamed.iter().map(|f| idl_type(&f.ty)).collect();
+                    value.insert("fields".to_string(), json!(types));
+                }
+            }
+            with_docs(value, &variant.attrs)
+        })
+        .collect::<Vec<_>>();
+
+    let mut value = Map::new();
+    value.insert("name".to_string(), json!(item.ident.to_string()));
+    value.insert(
+        "type".to_string(),
+        json!({ "kind": "enum", "variants": variants }),
+    );
+    with_docs(value, &item.attrs)
+}
+
+/// returns (accounts, types) of borsh serializable items, instruction data excluded
+fn account_and_defined_types() -> (Vec<Value>, Vec<Value>) {
+    let mut structs = HashMap::new();
+    let mut types = vec![];
+    for path in TYPE_SOURCES {
+        for item in parse(path).items {
+            let (name, value) = match &item {
+                Item::Struct(item) => {
+                    structs.insert(item.ident.to_string(), item.clone());
+                    if !derives_borsh(&item.attrs) {
+                        continue;
+                    }
+                    (item.ident.to_string(), idl_struct(item))
+                }
+                Item::Enum(item) if derives_borsh(&item.attrs) => {
+                    (item.ident.to_string(), idl_enum(item))
+                }
+                _ => continue,
+            };
+            if !ACCOUNT_LAYOUTS
+                .iter()
+                .any(|(_, _, rest)| *rest == name.as_str())
+            {
+                types.push(value);
+            }
+        }
+    }
+
+    let accounts = ACCOUNT_LAYOUTS
+        .iter()
+        .map(|(account, header, rest)| {
+            let fields: Vec<Value> = [header, rest]
+                .iter()
+                .flat_map(|part| idl_fields(&structs[**part].fields))
+                .collect();
+            let mut value = Map::new();
+            value.insert("name".to_string(), json!(account));
+            value.insert(
+                "type".to_string(),
+                json!({ "kind": "struct", "fields": fields }),
+            );
+            with_docs(value, &structs[*account].attrs)
+        })
+        .collect();
+    (accounts, types)
+}
+
+/// returns names of accounts destructured by `TryFrom<&[AccountView]>` of given accounts struct
+fn destructured_accounts(file: &File, accounts_struct: &str) -> Vec<String> {
+    let body = file
+        .items
+        .iter()
+        .find_map(|item| match item {
+            Item::Impl(item) if item.trait_.is_some() => match &*item.self_ty {
+                Type::Path(path) if path.path.segments.last().unwrap().ident == accounts_struct => {
+                    item.items.iter().find_map(|item| match item {
+                        syn::ImplItem::Fn(f) => Some(f.block.clone()),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("missing TryFrom of {accounts_struct}"));
+
+    body.stmts
+        .iter()
+        .find_map(|stmt| match stmt {
+            Stmt::Local(local) => match &local.pat {
+                Pat::Slice(slice) => Some(
+                    slice
+                        .elems
+                        .iter()
+                        .map(|elem| match elem {
+                            Pat::Ident(ident) => ident.ident.to_string(),
+                            _ => panic!("unsupported account pattern of {accounts_struct}"),
+                        })
+                        .collect(),
+                ),
+                _ => None,
+            },
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("missing account destructuring of {accounts_struct}"))
+}
+
+/// returns `(flags, description)` of `n. \`[FLAGS]\` description` lines of the accounts doc
+fn documented_accounts(attrs: &[Attribute]) -> Vec<(String, String)> {
+    docs(attrs)
+        .iter()
+        .skip_while(|line| *line != "### accounts:")
+        .skip(1)
+        .map_while(|line| {
+            let (_, rest) = line.split_once(". `[")?;
+            let (flags, description) = rest.split_once("]`")?;
+            Some((flags.to_string(), description.trim().to_string()))
+        })
+        .collect()
+}
+
+fn instruction_accounts(file: &File, accounts_struct: &ItemStruct) -> Vec<Value> {
+    let accounts_name = accounts_struct.ident.to_string();
+    let names = destructured_accounts(file, &accounts_name);
+    let documented = documented_accounts(&accounts_struct.attrs);
+
+    // remaining accounts are documented as `[..]` after the destructured ones
+    let has_remaining = documented.last().is_some_and(|(flags, _)| flags == "..");
+    assert_eq!(
+        documented.len() - usize::from(has_remaining),
+        names.len(),
+        "accounts doc of {accounts_name} doesn't match its destructured accounts"
+    );
+
+    documented
+        .iter()
+        .enumerate()
+        .map(|(i, (flags, description))| {
+            let name = names.get(i).map_or("remaining", String::as_str);
+            let mut value = Map::new();
+            value.insert("name".to_string(), json!(camel_case(name)));
+            value.insert("isMut".to_string(), json!(flags.contains("WRITE")));
+            value.insert("isSigner".to_string(), json!(flags.contains("SIGNER")));
+            if flags == ".." {
+                value.insert("isOptional".to_string(), json!(true));
+            }
+            if !description.is_empty() {
+                value.insert("docs".to_string(), json!([description]));
+            }
+            Value::Object(value)
+        })
+        .collect()
+}
+
+fn instructions() -> Vec<Value> {
+    let File { items, .. } = parse("src/instructions/mod.rs");
+    let opcodes = items
+        .iter()
+        .find_map(|item| match item {
+            Item::Enum(item) if item.ident == "Instruction" => Some(item.variants.clone()),
+            _ => None,
+        })
+        .expect("missing Instruction enum");
+    let modules: Vec<String> = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Mod(module) => Some(module.ident.to_string()),
+            _ => None,
+        })
+        .collect();
+
+    opcodes
+        .iter()
+        .enumerate()
+        .map(|(opcode, variant)| {
+            // each opcode is handled by a same named struct holding its accounts and data
+            let name = variant.ident.to_string();
+            let (file, handler) = modules
+                .iter()
+                .map(|module| parse(&format!("src/instructions/{module}.rs")))
+                .find_map(|file| {
+                    let handler = file.items.iter().find_map(|item| match item {
+                        Item::Struct(item) if item.ident == name => Some(item.clone()),
+                        _ => None,
+                    })?;
+                    Some((file, handler))
+                })
+                .unwrap_or_else(|| panic!("missing handler of {name}"));
+            let field_type = |field: &str| {
+                let handler_field = handler
+                    .fields
+                    .iter()
+                    .find(|f| f.ident.as_ref().is_some_and(|ident| ident == field))
+                    .unwrap_or_else(|| panic!("missing {field} of {name}"));
+                let Type::Path(path) = &handler_field.ty else {
+                    panic!("unsupported {field} type of {name}");
+                };
+                path.path.segments.last().unwrap().ident.to_string()
+            };
+            let find_struct = |struct_name: String| {
+                file.items
+                    .iter()
+                    .find_map(|item| match item {
+                        Item::Struct(item) if item.ident == struct_name => Some(item.clone()),
+                        _ => None,
+                    })
+                    .unwrap_or_else(|| panic!("missing {struct_name}"))
+            };
+            let accounts_struct = find_struct(field_type("accounts"));
+            let data_struct = find_struct(field_type("instruction_data"));
+
+            let mut value = Map::new();
+            value.insert("name".to_string(), json!(camel_case(&name)));
+            value.insert(
+                "accounts".to_string(),
+                json!(instruction_accounts(&file, &accounts_struct)),
+            );
+            value.insert("args".to_string(), json!(idl_fields(&data_struct.fields)));
+            value.insert(
+                "discriminant".to_string(),
+                json!({ "type": "u8", "value": opcode }),
+            );
+            let docs = docs(&accounts_struct.attrs);
+            let summary: Vec<&String> = docs
+                .iter()
+                .take_while(|line| *line != "### accounts:")
+                .filter(|line| !line.is_empty())
+                .collect();
+            value.insert("docs".to_string(), json!(summary));
+            Value::Object(value)
+        })
+        .collect()
+}
+
+fn errors() -> Vec<Value> {
+    let file = parse("src/errors.rs");
+    let variants = file
+        .items
+        .iter()
+        .find_map(|item| match item {
+            Item::Enum(item) if item.ident == "MosaicError" => Some(item.variants.clone()),
+            _ => None,
+        })
+        .expect("missing MosaicError");
+    let display = file
+        .items
+        .iter()
+        .find_map(|item| match item {
+            Item::Impl(item)
+                if item.trait_.as_ref().is_some_and(|(_, path, _)| {
+                    path.segments.last().unwrap().ident == "Display"
+                }) =>
+            {
+                Some(error_messages(item))
+            }
+            _ => None,
+        })
+        .expect("missing Display of MosaicError");
+
+    variants
+        .iter()
+        .enumerate()
+        .map(|(i, variant)| {
+            let name = variant.ident.to_string();
+            let msg = display
+                .iter()
+                .find_map(|(arm, msg)| (*arm == name).then_some(msg.clone()))
+                .unwrap_or_else(|| panic!("missing message of {name}"));
+            json!({ "code": FIRST_ERROR_CODE + i as u64, "name": name, "msg": msg })
+        })
+        .collect()
+}
+
+/// returns `(variant, message)` of every `MosaicError::Variant => write!(f, "message")` arm
+fn error_messages(item: &syn::ItemImpl) -> Vec<(String, String)> {
+    let syn::ImplItem::Fn(fmt) = &item.items[0] else {
+        panic!("unexpected Display impl");
+    };
+    let Some(Stmt::Expr(Expr::Match(matched), _)) = fmt.block.stmts.first() else {
+        panic!("unexpected Display impl");
+    };
+    matched
+        .arms
+        .iter()
+        .map(|arm| {
+            let Pat::Path(path) = &arm.pat else {
+                panic!("unexpected Display arm");
+            };
+            let variant = path.path.segments.last().unwrap().ident.to_string();
+            let mac = match &*arm.body {
+                Expr::Macro(mac) => mac.mac.clone(),
+                Expr::Block(block) => match block.block.stmts.first() {
+                    Some(Stmt::Macro(mac)) => mac.mac.clone(),
+                    Some(Stmt::Expr(Expr::Macro(mac), _)) => mac.mac.clone(),
+                    _ => panic!("unexpected Display arm of {variant}"),
+                },
+                _ => panic!("unexpected Display arm of {variant}"),
+            };
+            let args = mac
+                .parse_body_with(
+                    syn::punctuated::Punctuated::<Expr, syn::Token![,]>::parse_terminated,
+                )
+                .unwrap();
+            let Some(Expr::Lit(ExprLit {
+                lit: Lit::Str(msg), ..
+            })) = args.iter().nth(1)
+            else {
+                panic!("unexpected message of {variant}");
+            };
+            (variant, msg.value())
+        })
+        .collect()
+}
+
+fn program_address() -> String {
+    parse("src/lib.rs")
+        .items
+        .iter()
+        .find_map(|item| match item {
+            Item::Macro(item) if item.mac.path.segments.last().unwrap().ident == "declare_id" => {
+                Some(item.mac.parse_body::<syn::LitStr>().unwrap().value())
+            }
+            _ => None,
+        })
+        .expect("missing declare_id")
+}
+
+fn generate_idl() -> String {
+    let (accounts, types) = account_and_defined_types();
+    let idl = json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "name": env!("CARGO_PKG_NAME"),
+        "instructions": instructions(),
+        "accounts": accounts,
+        "types": types,
+        "errors": errors(),
+        "metadata": {
+            "origin": "shank",
+            "address": program_address(),
+        },
+    });
+    serde_json::to_string_pretty(&idl).unwrap() + "\n"
+}
+
+#[test]
+fn test_idl_is_up_to_date() {
+    let idl = generate_idl();
+
+    if env::var_os("MOSAIC_UPDATE_IDL").is_some() {
+        fs::create_dir_all(Path::new(IDL_PATH).parent().unwrap()).unwrap();
+        fs::write(IDL_PATH, idl).unwrap();
+        return;
+    }
+
+    let committed = fs::read_to_string(IDL_PATH).unwrap_or_default();
+    assert!(
+        committed == idl,
+        "{IDL_PATH} is stale; regenerate it with `MOSAIC_UPDATE_IDL=1 cargo test --test idl`"
+    );
+}
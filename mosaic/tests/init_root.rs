@@ -3,24 +3,25 @@ mod common;
 use {
     borsh::to_vec,
     common::*,
-    mollusk_svm::{result::Check, Mollusk},
+    mollusk_svm::{Mollusk, result::Check},
 };
 
 use mosaic::{
-    instructions::{init_root::InitializeRootIxData, Instruction as ProgramIx},
-    seeds::ROOT_PDA,
-    state::root::Root,
+    events::Event,
+    instructions::{Instruction as ProgramIx, init_root::InitializeRootIxData},
+    state::root::{ExecutePolicy, Root},
 };
 
 use solana_sdk::{
     account::AccountSharedData,
     instruction::{AccountMeta, Instruction},
-    pubkey::Pubkey
+    pubkey::Pubkey,
 };
 
 #[test]
 fn test_initialize_root() {
-    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let logs = collect_logs(&mut mollusk);
     let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
 
     let operators = Operators::new(3, system_program);
@@ -31,15 +32,18 @@ fn test_initialize_root() {
         .collect();
     let (signer, signer_account) = operators.operators[0].clone();
 
-    let (root_pda, root_pda_bump) =
-        solana_sdk::pubkey::Pubkey::find_program_address(&[ROOT_PDA], &PROGRAM_ID);
+    let (root_pda, root_pda_bump) = find_root_pda(&signer, ROOT_NAME);
     let root_account = AccountSharedData::new(0, 0, &system_program);
 
     let ix_data = InitializeRootIxData {
-        operators: operators_pubkey.clone(),
+        name: ROOT_NAME.to_string(),
+        operators: equal_weight_operators(&operators_pubkey),
         threshold: operators.threshold,
+        default_ttl: None,
+        timelock: 0,
+        execute_policy: ExecutePolicy::Operators,
         bump: root_pda_bump,
-        destination_program: DESTINATION_PROGRAM_ID,
+        allowed_programs: vec![DESTINATION_PROGRAM_ID],
     };
     let data = [
         vec![ProgramIx::InitializeOperators as u8],
@@ -51,7 +55,7 @@ fn test_initialize_root() {
         PROGRAM_ID,
         &data,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new(root_pda, false),
             AccountMeta::new_readonly(system_program, false),
         ],
@@ -59,7 +63,7 @@ fn test_initialize_root() {
     let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (system_program, system_account.clone()),
         ],
@@ -69,10 +73,22 @@ fn test_initialize_root() {
         ],
     );
     let updated_root_pda_account = result.get_account(&root_pda).unwrap();
-    let parsed_root_pda_data = borsh::from_slice::<Root>(&updated_root_pda_account.data).unwrap();
+    let parsed_root_pda_data = Root::deserialize(&updated_root_pda_account.data).unwrap();
 
+    assert!(parsed_root_pda_data.creator == signer);
+    assert!(parsed_root_pda_data.name == ROOT_NAME);
     assert!(parsed_root_pda_data.bump == root_pda_bump);
     assert!(parsed_root_pda_data.last_id == 0);
     assert!(parsed_root_pda_data.threshold == operators.threshold);
-    assert!(parsed_root_pda_data.operators == operators_pubkey);
+    assert!(parsed_root_pda_data.operators == equal_weight_operators(&operators_pubkey));
+    assert!(parsed_root_pda_data.execute_policy == ExecutePolicy::Operators);
+    assert!(
+        emitted_events(&logs)
+            == vec![Event::RootInitialized {
+                root: root_pda,
+                creator: signer,
+                operators: equal_weight_operators(&operators_pubkey),
+                threshold: operators.threshold,
+            }]
+    );
 }
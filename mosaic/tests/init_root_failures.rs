@@ -9,13 +9,17 @@ use {
 use mosaic::{
     errors::MosaicError,
     instructions::{Instruction as ProgramIx, init_root::InitializeRootIxData},
-    seeds::ROOT_PDA,
+    state::{
+        expiry::Expiry,
+        root::{ExecutePolicy, MAX_OPERATORS},
+    },
 };
 
 use solana_sdk::{
     account::AccountSharedData,
     instruction::{AccountMeta, Instruction},
     program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 #[test]
@@ -31,15 +35,18 @@ fn test_initialize_root_not_writable_failure() {
         .collect();
     let (signer, signer_account) = operators.operators[0].clone();
 
-    let (root_pda, root_pda_bump) =
-        solana_sdk::pubkey::Pubkey::find_program_address(&[ROOT_PDA], &PROGRAM_ID);
+    let (root_pda, root_pda_bump) = find_root_pda(&signer, ROOT_NAME);
     let root_account = AccountSharedData::new(0, 0, &system_program);
 
     let ix_data = InitializeRootIxData {
-        operators: operators_pubkey.clone(),
+        name: ROOT_NAME.to_string(),
+        operators: equal_weight_operators(&operators_pubkey),
         threshold: operators.threshold,
+        default_ttl: None,
+        timelock: 0,
+        execute_policy: ExecutePolicy::Operators,
         bump: root_pda_bump,
-        destination_program: DESTINATION_PROGRAM_ID,
+        allowed_programs: vec![DESTINATION_PROGRAM_ID],
     };
     let data = [
         vec![ProgramIx::InitializeOperators as u8],
@@ -51,7 +58,7 @@ fn test_initialize_root_not_writable_failure() {
         PROGRAM_ID,
         &data,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new_readonly(root_pda, false),
             AccountMeta::new_readonly(system_program, false),
         ],
@@ -59,7 +66,7 @@ fn test_initialize_root_not_writable_failure() {
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (system_program, system_account.clone()),
         ],
@@ -98,10 +105,14 @@ fn test_re_initialize_root_failure() {
     );
 
     let ix_data = InitializeRootIxData {
-        operators: operators_pubkey.clone(),
+        name: ROOT_NAME.to_string(),
+        operators: equal_weight_operators(&operators_pubkey),
         threshold: operators.threshold,
+        default_ttl: None,
+        timelock: 0,
+        execute_policy: ExecutePolicy::Operators,
         bump: root_pda_bump,
-        destination_program: DESTINATION_PROGRAM_ID,
+        allowed_programs: vec![DESTINATION_PROGRAM_ID],
     };
     let data = [
         vec![ProgramIx::InitializeOperators as u8],
@@ -113,7 +124,7 @@ fn test_re_initialize_root_failure() {
         PROGRAM_ID,
         &data,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new(root_pda, false),
             AccountMeta::new_readonly(system_program, false),
         ],
@@ -121,7 +132,7 @@ fn test_re_initialize_root_failure() {
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (system_program, system_account.clone()),
         ],
@@ -163,10 +174,14 @@ fn test_initialize_root_payer_is_not_signer_failure() {
     );
 
     let ix_data = InitializeRootIxData {
-        operators: operators_pubkey.clone(),
+        name: ROOT_NAME.to_string(),
+        operators: equal_weight_operators(&operators_pubkey),
         threshold: operators.threshold,
+        default_ttl: None,
+        timelock: 0,
+        execute_policy: ExecutePolicy::Operators,
         bump: root_pda_bump,
-        destination_program: DESTINATION_PROGRAM_ID,
+        allowed_programs: vec![DESTINATION_PROGRAM_ID],
     };
     let data = [
         vec![ProgramIx::InitializeOperators as u8],
@@ -178,7 +193,7 @@ fn test_initialize_root_payer_is_not_signer_failure() {
         PROGRAM_ID,
         &data,
         vec![
-            AccountMeta::new(payer.into(), false),
+            AccountMeta::new(payer, false),
             AccountMeta::new(root_pda, false),
             AccountMeta::new_readonly(system_program, false),
         ],
@@ -186,7 +201,7 @@ fn test_initialize_root_payer_is_not_signer_failure() {
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (payer.into(), signer_account.clone().into()),
+            (payer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (system_program, system_account.clone()),
         ],
@@ -198,3 +213,560 @@ fn test_initialize_root_payer_is_not_signer_failure() {
         ],
     );
 }
+
+#[test]
+fn test_initialize_root_name_too_long_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // name exceeding 32 bytes can't be used as pda seed
+    let name = "a".repeat(33);
+    let (root_pda, root_pda_bump) = find_root_pda(&signer, &name[..32]);
+    let root_account = AccountSharedData::new(0, 0, &system_program);
+
+    let ix_data = InitializeRootIxData {
+        name,
+        operators: equal_weight_operators(&operators_pubkey),
+        threshold: operators.threshold,
+        default_ttl: None,
+        timelock: 0,
+        execute_policy: ExecutePolicy::Operators,
+        bump: root_pda_bump,
+        allowed_programs: vec![DESTINATION_PROGRAM_ID],
+    };
+    let data = [
+        vec![ProgramIx::InitializeOperators as u8],
+        to_vec(&ix_data).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::RootNameTooLong as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_initialize_root_zero_threshold_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let (root_pda, root_pda_bump) = find_root_pda(&signer, ROOT_NAME);
+    let root_account = AccountSharedData::new(0, 0, &system_program);
+
+    let ix_data = InitializeRootIxData {
+        name: ROOT_NAME.to_string(),
+        operators: equal_weight_operators(&operators_pubkey),
+        threshold: 0,
+        default_ttl: None,
+        timelock: 0,
+        execute_policy: ExecutePolicy::Operators,
+        bump: root_pda_bump,
+        allowed_programs: vec![DESTINATION_PROGRAM_ID],
+    };
+    let data = [
+        vec![ProgramIx::InitializeOperators as u8],
+        to_vec(&ix_data).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::ThresholdMustNotBeZero as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_initialize_root_threshold_exceeds_operators_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let (root_pda, root_pda_bump) = find_root_pda(&signer, ROOT_NAME);
+    let root_account = AccountSharedData::new(0, 0, &system_program);
+
+    let ix_data = InitializeRootIxData {
+        name: ROOT_NAME.to_string(),
+        operators: equal_weight_operators(&operators_pubkey),
+        threshold: operators_pubkey.len() as u16 + 1,
+        default_ttl: None,
+        timelock: 0,
+        execute_policy: ExecutePolicy::Operators,
+        bump: root_pda_bump,
+        allowed_programs: vec![DESTINATION_PROGRAM_ID],
+    };
+    let data = [
+        vec![ProgramIx::InitializeOperators as u8],
+        to_vec(&ix_data).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::ThresholdMustNotExceedOperators as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_initialize_root_zero_operator_weight_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let (root_pda, root_pda_bump) = find_root_pda(&signer, ROOT_NAME);
+    let root_account = AccountSharedData::new(0, 0, &system_program);
+
+    // operator without voting power
+    let mut weighted_operators = equal_weight_operators(&operators_pubkey);
+    weighted_operators[2].weight = 0;
+
+    let ix_data = InitializeRootIxData {
+        name: ROOT_NAME.to_string(),
+        operators: weighted_operators,
+        threshold: operators.threshold,
+        default_ttl: None,
+        timelock: 0,
+        execute_policy: ExecutePolicy::Operators,
+        bump: root_pda_bump,
+        allowed_programs: vec![DESTINATION_PROGRAM_ID],
+    };
+    let data = [
+        vec![ProgramIx::InitializeOperators as u8],
+        to_vec(&ix_data).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::OperatorWeightMustNotBeZero as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_initialize_root_duplicate_operators_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let mut operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // same operator key registered twice
+    operators_pubkey.push(signer);
+
+    let (root_pda, root_pda_bump) = find_root_pda(&signer, ROOT_NAME);
+    let root_account = AccountSharedData::new(0, 0, &system_program);
+
+    let ix_data = InitializeRootIxData {
+        name: ROOT_NAME.to_string(),
+        operators: equal_weight_operators(&operators_pubkey),
+        threshold: operators.threshold,
+        default_ttl: None,
+        timelock: 0,
+        execute_policy: ExecutePolicy::Operators,
+        bump: root_pda_bump,
+        allowed_programs: vec![DESTINATION_PROGRAM_ID],
+    };
+    let data = [
+        vec![ProgramIx::InitializeOperators as u8],
+        to_vec(&ix_data).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::OperatorsMustBeUnique as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_initialize_root_duplicate_allowed_programs_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let (root_pda, root_pda_bump) = find_root_pda(&signer, ROOT_NAME);
+    let root_account = AccountSharedData::new(0, 0, &system_program);
+
+    let ix_data = InitializeRootIxData {
+        name: ROOT_NAME.to_string(),
+        operators: equal_weight_operators(&operators_pubkey),
+        threshold: operators.threshold,
+        default_ttl: None,
+        timelock: 0,
+        execute_policy: ExecutePolicy::Operators,
+        bump: root_pda_bump,
+        allowed_programs: vec![DESTINATION_PROGRAM_ID, DESTINATION_PROGRAM_ID], // same program allowed twice
+    };
+    let data = [
+        vec![ProgramIx::InitializeOperators as u8],
+        to_vec(&ix_data).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::AllowedProgramsMustBeUnique as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_initialize_root_zero_default_ttl_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let (root_pda, root_pda_bump) = find_root_pda(&signer, ROOT_NAME);
+    let root_account = AccountSharedData::new(0, 0, &system_program);
+
+    let ix_data = InitializeRootIxData {
+        name: ROOT_NAME.to_string(),
+        operators: equal_weight_operators(&operators_pubkey),
+        threshold: operators.threshold,
+        default_ttl: Some(Expiry::Slot(0)), // sessions would expire on creation
+        timelock: 0,
+        execute_policy: ExecutePolicy::Operators,
+        bump: root_pda_bump,
+        allowed_programs: vec![DESTINATION_PROGRAM_ID],
+    };
+    let data = [
+        vec![ProgramIx::InitializeOperators as u8],
+        to_vec(&ix_data).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::DefaultTtlMustBePositive as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_initialize_root_mosaic_allowed_program_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let (root_pda, root_pda_bump) = find_root_pda(&signer, ROOT_NAME);
+    let root_account = AccountSharedData::new(0, 0, &system_program);
+
+    let ix_data = InitializeRootIxData {
+        name: ROOT_NAME.to_string(),
+        operators: equal_weight_operators(&operators_pubkey),
+        threshold: operators.threshold,
+        default_ttl: None,
+        timelock: 0,
+        execute_policy: ExecutePolicy::Operators,
+        bump: root_pda_bump,
+        allowed_programs: vec![DESTINATION_PROGRAM_ID, PROGRAM_ID], // mosaic itself as cpi target
+    };
+    let data = [
+        vec![ProgramIx::InitializeOperators as u8],
+        to_vec(&ix_data).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::MosaicProgramNotAllowed as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_initialize_root_empty_executors_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let (root_pda, root_pda_bump) = find_root_pda(&signer, ROOT_NAME);
+    let root_account = AccountSharedData::new(0, 0, &system_program);
+
+    let ix_data = InitializeRootIxData {
+        name: ROOT_NAME.to_string(),
+        operators: equal_weight_operators(&operators_pubkey),
+        threshold: operators.threshold,
+        default_ttl: None,
+        timelock: 0,
+        execute_policy: ExecutePolicy::Executors(vec![]), // nobody could ever execute
+        bump: root_pda_bump,
+        allowed_programs: vec![DESTINATION_PROGRAM_ID],
+    };
+    let data = [
+        vec![ProgramIx::InitializeOperators as u8],
+        to_vec(&ix_data).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::ExecutorsMustNotBeEmpty as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_initialize_root_too_many_operators_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let (root_pda, root_pda_bump) = find_root_pda(&signer, ROOT_NAME);
+    let root_account = AccountSharedData::new(0, 0, &system_program);
+
+    // one operator more than session vote bitmaps can hold
+    let mut too_many_operators = equal_weight_operators(&operators_pubkey);
+    too_many_operators.extend(equal_weight_operators(
+        &(too_many_operators.len()..=MAX_OPERATORS)
+            .map(|_| Pubkey::new_unique())
+            .collect::<Vec<_>>(),
+    ));
+
+    let ix_data = InitializeRootIxData {
+        name: ROOT_NAME.to_string(),
+        operators: too_many_operators,
+        threshold: operators.threshold,
+        default_ttl: None,
+        timelock: 0,
+        execute_policy: ExecutePolicy::Operators,
+        bump: root_pda_bump,
+        allowed_programs: vec![DESTINATION_PROGRAM_ID],
+    };
+    let data = [
+        vec![ProgramIx::InitializeOperators as u8],
+        to_vec(&ix_data).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::TooManyOperators as u32,
+        ))],
+    );
+}
@@ -3,15 +3,19 @@ mod common;
 use {
     borsh::to_vec,
     common::*,
-    mollusk_svm::{result::Check, Mollusk},
+    mollusk_svm::{Mollusk, result::Check},
 };
 
 use mosaic::{
+    events::Event,
     instructions::{
-        init_signing_session::InitializeSigningSessionIxData, Instruction as ProgramIx,
+        Instruction as ProgramIx, init_signing_session::InitializeSigningSessionIxData,
     },
+    programs::{BPF_LOADER_UPGRADEABLE_ID, TOKEN_2022_PROGRAM_ID},
     seeds::SIGNING_SESSION_PDA,
     state::{
+        expiry::Expiry,
+        proposal::{LoaderChange, Proposal, TokenTransfer},
         root::Root,
         signing_session::{SigningSession, SigningSessionPhase},
     },
@@ -20,11 +24,14 @@ use mosaic::{
 use solana_sdk::{
     account::AccountSharedData,
     instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
 };
 
 #[test]
 fn test_initialize_signing_session() {
-    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let logs = collect_logs(&mut mollusk);
     let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
 
     let operators = Operators::new(3, system_program);
@@ -73,8 +80,13 @@ fn test_initialize_signing_session() {
     let signing_account = AccountSharedData::new(0, 0, &system_program);
 
     let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
-        instruction_data: cpi_instruction_data.clone(),
-        instruction_accounts: cpi_instruction_accounts.clone(),
+        proposal: Proposal::Cpi,
+        instructions: destination_instructions(
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data.clone(),
+        ),
+        vault_index: 2,
+        expires_at: None,
         bump: signing_pda_bump,
     };
     let data_initialize_signing_session = [
@@ -87,7 +99,7 @@ fn test_initialize_signing_session() {
         PROGRAM_ID,
         &data_initialize_signing_session,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new(root_pda, false),
             AccountMeta::new(signing_pda, false),
             AccountMeta::new_readonly(system_program, false),
@@ -96,7 +108,7 @@ fn test_initialize_signing_session() {
     let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
             (system_program, system_account.clone()),
@@ -109,17 +121,528 @@ fn test_initialize_signing_session() {
 
     let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
     let parsed_signing_session_pda_data =
-        borsh::from_slice::<SigningSession>(&updated_signing_session_pda_account.data).unwrap();
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
 
     let updated_root_pda_account = result.get_account(&root_pda).unwrap();
-    let parsed_root_pda_data = borsh::from_slice::<Root>(&updated_root_pda_account.data).unwrap();
+    let parsed_root_pda_data = Root::deserialize(&updated_root_pda_account.data).unwrap();
 
     assert!(parsed_root_pda_data.last_id == next_session_id);
     assert!(parsed_root_pda_data.last_id == parsed_signing_session_pda_data.session_id);
     assert!(parsed_signing_session_pda_data.root_pda == root_pda);
+    assert!(parsed_signing_session_pda_data.proposer == signer);
     assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Active);
-    assert!(parsed_signing_session_pda_data.approvals.is_empty());
-    assert!(parsed_signing_session_pda_data.instruction_data == cpi_instruction_data);
-    assert!(parsed_signing_session_pda_data.instruction_accounts == cpi_instruction_accounts);
-    assert!(parsed_signing_session_pda_data.bump == signing_pda_bump)
+    assert!(parsed_signing_session_pda_data.proposal == Proposal::Cpi);
+    assert!(parsed_signing_session_pda_data.approvals == 0);
+    assert!(parsed_signing_session_pda_data.rejections == 0);
+    assert!(parsed_signing_session_pda_data.expires_at.is_none());
+    assert!(
+        parsed_signing_session_pda_data.instructions
+            == destination_instructions(cpi_instruction_accounts, cpi_instruction_data)
+    );
+    assert!(parsed_signing_session_pda_data.vault_index == 2);
+    assert!(parsed_signing_session_pda_data.vault_bump == find_vault_pda(&root_pda, 2).1);
+    assert!(parsed_signing_session_pda_data.bump == signing_pda_bump);
+    assert!(
+        emitted_events(&logs)
+            == vec![Event::SessionCreated {
+                root: root_pda,
+                session_id: next_session_id,
+                proposer: signer,
+                proposal: Proposal::Cpi,
+                expires_at: None,
+            }]
+    );
+}
+
+#[test]
+fn test_initialize_signing_session_default_ttl() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let session_id = 0;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        mut root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    mollusk.warp_to_slot(50);
+    root_pda_init_state.default_ttl = Some(Expiry::Slot(100));
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // storage
+    let (storage_pda, _storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing
+    let next_session_id = root_pda_init_state.last_id + 1; // this is because the next session id must be the incremented current one from root pda
+    let (signing_pda, signing_pda_bump) = solana_sdk::pubkey::Pubkey::find_program_address(
+        &[
+            &root_pda.to_bytes(),
+            &next_session_id.to_be_bytes(),
+            SIGNING_SESSION_PDA,
+        ],
+        &PROGRAM_ID,
+    );
+    let signing_account = AccountSharedData::new(0, 0, &system_program);
+
+    let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
+        proposal: Proposal::Cpi,
+        instructions: destination_instructions(
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data.clone(),
+        ),
+        vault_index: 0,
+        expires_at: None,
+        bump: signing_pda_bump,
+    };
+    let data_initialize_signing_session = [
+        vec![ProgramIx::InitializeSigningSession as u8],
+        to_vec(&ix_data_initialize_signing_session).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_initialize_signing_session,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    // default ttl counts from the slot session was created at
+    assert!(parsed_signing_session_pda_data.expires_at == Some(Expiry::Slot(150)));
+}
+
+#[test]
+fn test_initialize_signing_session_explicit_expiry_overrides_default_ttl() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let session_id = 0;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        mut root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    mollusk.warp_to_slot(50);
+    root_pda_init_state.default_ttl = Some(Expiry::Slot(100));
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // storage
+    let (storage_pda, _storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing
+    let next_session_id = root_pda_init_state.last_id + 1; // this is because the next session id must be the incremented current one from root pda
+    let (signing_pda, signing_pda_bump) = solana_sdk::pubkey::Pubkey::find_program_address(
+        &[
+            &root_pda.to_bytes(),
+            &next_session_id.to_be_bytes(),
+            SIGNING_SESSION_PDA,
+        ],
+        &PROGRAM_ID,
+    );
+    let signing_account = AccountSharedData::new(0, 0, &system_program);
+
+    let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
+        proposal: Proposal::Cpi,
+        instructions: destination_instructions(
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data.clone(),
+        ),
+        vault_index: 0,
+        expires_at: Some(Expiry::UnixTimestamp(1_000)),
+        bump: signing_pda_bump,
+    };
+    let data_initialize_signing_session = [
+        vec![ProgramIx::InitializeSigningSession as u8],
+        to_vec(&ix_data_initialize_signing_session).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_initialize_signing_session,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.expires_at == Some(Expiry::UnixTimestamp(1_000)));
+}
+
+#[test]
+fn test_initialize_signing_session_transfer_proposal() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let session_id = 0;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing
+    let next_session_id = root_pda_init_state.last_id + 1; // this is because the next session id must be the incremented current one from root pda
+    let (signing_pda, signing_pda_bump) = solana_sdk::pubkey::Pubkey::find_program_address(
+        &[
+            &root_pda.to_bytes(),
+            &next_session_id.to_be_bytes(),
+            SIGNING_SESSION_PDA,
+        ],
+        &PROGRAM_ID,
+    );
+    let signing_account = AccountSharedData::new(0, 0, &system_program);
+
+    let proposal = Proposal::Transfer {
+        recipient: Pubkey::new_unique(),
+        amount: LAMPORTS_PER_SOL,
+        vault_index: 0,
+    };
+    let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
+        proposal: proposal.clone(),
+        instructions: vec![],
+        vault_index: 0,
+        expires_at: None,
+        bump: signing_pda_bump,
+    };
+    let data_initialize_signing_session = [
+        vec![ProgramIx::InitializeSigningSession as u8],
+        to_vec(&ix_data_initialize_signing_session).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_initialize_signing_session,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.proposal == proposal);
+    assert!(parsed_signing_session_pda_data.instructions.is_empty());
+    assert!(parsed_signing_session_pda_data.vault_bump == find_vault_pda(&root_pda, 0).1);
+}
+
+#[test]
+fn test_initialize_signing_session_token_2022_transfer_proposal() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let session_id = 0;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing
+    let next_session_id = root_pda_init_state.last_id + 1; // this is because the next session id must be the incremented current one from root pda
+    let (signing_pda, signing_pda_bump) = solana_sdk::pubkey::Pubkey::find_program_address(
+        &[
+            &root_pda.to_bytes(),
+            &next_session_id.to_be_bytes(),
+            SIGNING_SESSION_PDA,
+        ],
+        &PROGRAM_ID,
+    );
+    let signing_account = AccountSharedData::new(0, 0, &system_program);
+
+    let proposal = Proposal::TokenTransfer(TokenTransfer {
+        token_program: Pubkey::new_from_array(TOKEN_2022_PROGRAM_ID.to_bytes()),
+        mint: Pubkey::new_unique(),
+        source: Pubkey::new_unique(),
+        destination: Pubkey::new_unique(),
+        amount: 1_000,
+        decimals: 6,
+        vault_index: None,
+    });
+    let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
+        proposal: proposal.clone(),
+        instructions: vec![],
+        vault_index: 0,
+        expires_at: None,
+        bump: signing_pda_bump,
+    };
+    let data_initialize_signing_session = [
+        vec![ProgramIx::InitializeSigningSession as u8],
+        to_vec(&ix_data_initialize_signing_session).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_initialize_signing_session,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.proposal == proposal);
+    assert!(parsed_signing_session_pda_data.instructions.is_empty());
+}
+
+#[test]
+fn test_initialize_signing_session_loader_upgrade_proposal() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let session_id = 0;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing
+    let next_session_id = root_pda_init_state.last_id + 1; // this is because the next session id must be the incremented current one from root pda
+    let (signing_pda, signing_pda_bump) = solana_sdk::pubkey::Pubkey::find_program_address(
+        &[
+            &root_pda.to_bytes(),
+            &next_session_id.to_be_bytes(),
+            SIGNING_SESSION_PDA,
+        ],
+        &PROGRAM_ID,
+    );
+    let signing_account = AccountSharedData::new(0, 0, &system_program);
+
+    let program = Pubkey::new_unique();
+    let (program_data, _program_data_bump) = Pubkey::find_program_address(
+        &[program.as_ref()],
+        &Pubkey::new_from_array(BPF_LOADER_UPGRADEABLE_ID.to_bytes()),
+    );
+    let proposal = Proposal::Loader {
+        change: LoaderChange::Upgrade {
+            program,
+            program_data,
+            buffer: Pubkey::new_unique(),
+            spill: signer,
+        },
+        vault_index: Some(0),
+    };
+    let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
+        proposal: proposal.clone(),
+        instructions: vec![],
+        vault_index: 0,
+        expires_at: None,
+        bump: signing_pda_bump,
+    };
+    let data_initialize_signing_session = [
+        vec![ProgramIx::InitializeSigningSession as u8],
+        to_vec(&ix_data_initialize_signing_session).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_initialize_signing_session,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.proposal == proposal);
+    assert!(parsed_signing_session_pda_data.instructions.is_empty());
 }
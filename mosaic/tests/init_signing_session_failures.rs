@@ -11,16 +11,23 @@ use mosaic::{
     instructions::{
         Instruction as ProgramIx, init_signing_session::InitializeSigningSessionIxData,
     },
-    seeds::{ROOT_PDA, SIGNING_SESSION_PDA},
-    state::signing_session::SigningSessionPhase,
+    seeds::SIGNING_SESSION_PDA,
+    state::{
+        expiry::Expiry,
+        proposal::{Proposal, TokenTransfer},
+        root::Permission,
+        signing_session::{SessionInstruction, SigningSessionPhase},
+    },
 };
 
+use pinocchio::cpi::MAX_CPI_ACCOUNTS;
 use solana_program::example_mocks::{solana_keypair::Keypair, solana_signer::Signer};
 use solana_sdk::{
     account::AccountSharedData,
     instruction::{AccountMeta, Instruction},
     native_token::LAMPORTS_PER_SOL,
     program_error::ProgramError,
+    pubkey::Pubkey,
 };
 
 #[test]
@@ -52,8 +59,13 @@ fn test_initialize_signing_session_root_pda_is_not_owned_by_program_failure() {
     let signing_account = AccountSharedData::new(0, 0, &system_program);
 
     let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
-        instruction_data: cpi_instruction_data.clone(),
-        instruction_accounts: cpi_instruction_accounts.clone(),
+        proposal: Proposal::Cpi,
+        instructions: destination_instructions(
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data.clone(),
+        ),
+        vault_index: 0,
+        expires_at: None,
         bump: signing_pda_bump,
     };
     let data_initialize_signing_session = [
@@ -66,7 +78,7 @@ fn test_initialize_signing_session_root_pda_is_not_owned_by_program_failure() {
         PROGRAM_ID,
         &data_initialize_signing_session,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new(root_pda, false),
             AccountMeta::new(signing_pda, false),
             AccountMeta::new_readonly(system_program, false),
@@ -75,7 +87,7 @@ fn test_initialize_signing_session_root_pda_is_not_owned_by_program_failure() {
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
             (system_program, system_account.clone()),
@@ -136,8 +148,13 @@ fn test_initialize_signing_session_not_writable_signing_session_account_failure(
     let signing_account = AccountSharedData::new(0, 0, &system_program);
 
     let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
-        instruction_data: cpi_instruction_data.clone(),
-        instruction_accounts: cpi_instruction_accounts.clone(),
+        proposal: Proposal::Cpi,
+        instructions: destination_instructions(
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data.clone(),
+        ),
+        vault_index: 0,
+        expires_at: None,
         bump: signing_pda_bump,
     };
     let data_initialize_signing_session = [
@@ -150,7 +167,7 @@ fn test_initialize_signing_session_not_writable_signing_session_account_failure(
         PROGRAM_ID,
         &data_initialize_signing_session,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new(root_pda, false),
             AccountMeta::new_readonly(signing_pda, false),
             AccountMeta::new_readonly(system_program, false),
@@ -159,7 +176,7 @@ fn test_initialize_signing_session_not_writable_signing_session_account_failure(
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
             (system_program, system_account.clone()),
@@ -186,8 +203,7 @@ fn test_initialize_signing_session_root_pda_not_initialized_failure() {
     let session_id = 0;
 
     // root
-    let (root_pda, _root_pda_bump) =
-        solana_sdk::pubkey::Pubkey::find_program_address(&[ROOT_PDA], &PROGRAM_ID);
+    let (root_pda, _root_pda_bump) = find_root_pda(&ROOT_CREATOR, ROOT_NAME);
     let root_account = AccountSharedData::new(0, 0, &PROGRAM_ID);
 
     let (storage_pda, _storage_pda_account) =
@@ -205,8 +221,13 @@ fn test_initialize_signing_session_root_pda_not_initialized_failure() {
     let signing_account = AccountSharedData::new(0, 0, &system_program);
 
     let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
-        instruction_data: cpi_instruction_data.clone(),
-        instruction_accounts: cpi_instruction_accounts.clone(),
+        proposal: Proposal::Cpi,
+        instructions: destination_instructions(
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data.clone(),
+        ),
+        vault_index: 0,
+        expires_at: None,
         bump: signing_pda_bump,
     };
     let data_initialize_signing_session = [
@@ -219,7 +240,7 @@ fn test_initialize_signing_session_root_pda_not_initialized_failure() {
         PROGRAM_ID,
         &data_initialize_signing_session,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new(root_pda, false),
             AccountMeta::new(signing_pda, false),
             AccountMeta::new_readonly(system_program, false),
@@ -228,7 +249,7 @@ fn test_initialize_signing_session_root_pda_not_initialized_failure() {
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
             (system_program, system_account.clone()),
@@ -289,8 +310,13 @@ fn test_initialize_signing_session_root_not_writable_failure() {
     let signing_account = AccountSharedData::new(0, 0, &system_program);
 
     let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
-        instruction_data: cpi_instruction_data.clone(),
-        instruction_accounts: cpi_instruction_accounts.clone(),
+        proposal: Proposal::Cpi,
+        instructions: destination_instructions(
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data.clone(),
+        ),
+        vault_index: 0,
+        expires_at: None,
         bump: signing_pda_bump,
     };
     let data_initialize_signing_session = [
@@ -303,7 +329,7 @@ fn test_initialize_signing_session_root_not_writable_failure() {
         PROGRAM_ID,
         &data_initialize_signing_session,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new_readonly(root_pda, false),
             AccountMeta::new(signing_pda, false),
             AccountMeta::new_readonly(system_program, false),
@@ -312,7 +338,7 @@ fn test_initialize_signing_session_root_not_writable_failure() {
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
             (system_program, system_account.clone()),
@@ -374,8 +400,13 @@ fn test_initialize_signing_session_payer_is_not_signer_failure() {
     let signing_account = AccountSharedData::new(0, 0, &system_program);
 
     let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
-        instruction_data: cpi_instruction_data.clone(),
-        instruction_accounts: cpi_instruction_accounts.clone(),
+        proposal: Proposal::Cpi,
+        instructions: destination_instructions(
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data.clone(),
+        ),
+        vault_index: 0,
+        expires_at: None,
         bump: signing_pda_bump,
     };
     let data_initialize_signing_session = [
@@ -388,7 +419,7 @@ fn test_initialize_signing_session_payer_is_not_signer_failure() {
         PROGRAM_ID,
         &data_initialize_signing_session,
         vec![
-            AccountMeta::new(payer.into(), false),
+            AccountMeta::new(payer, false),
             AccountMeta::new(root_pda, false),
             AccountMeta::new(signing_pda, false),
             AccountMeta::new_readonly(system_program, false),
@@ -397,7 +428,7 @@ fn test_initialize_signing_session_payer_is_not_signer_failure() {
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (payer.into(), signer_account.clone().into()),
+            (payer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
             (system_program, system_account.clone()),
@@ -421,8 +452,7 @@ fn test_initialize_signing_session_signer_is_not_operator_failure() {
         .collect();
 
     let not_operator_signer = Keypair::new();
-    let not_operator_signer_account =
-        AccountSharedData::new(1 * LAMPORTS_PER_SOL, 0, &system_program);
+    let not_operator_signer_account = AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program);
 
     let session_id = 0;
 
@@ -461,8 +491,13 @@ fn test_initialize_signing_session_signer_is_not_operator_failure() {
     let signing_account = AccountSharedData::new(0, 0, &system_program);
 
     let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
-        instruction_data: cpi_instruction_data.clone(),
-        instruction_accounts: cpi_instruction_accounts.clone(),
+        proposal: Proposal::Cpi,
+        instructions: destination_instructions(
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data.clone(),
+        ),
+        vault_index: 0,
+        expires_at: None,
         bump: signing_pda_bump,
     };
     let data_initialize_signing_session = [
@@ -498,6 +533,98 @@ fn test_initialize_signing_session_signer_is_not_operator_failure() {
     );
 }
 
+#[test]
+fn test_initialize_signing_session_operator_lacks_initiate_permission_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let session_id = 0;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        mut root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+    // signer may only vote
+    root_pda_init_state.operators[0].permissions = Permission::Vote as u8;
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    let (storage_pda, _storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing
+    let next_session_id = root_pda_init_state.last_id + 1; // this is because the next session id must be the incremented current one from root pda
+    let (signing_pda, signing_pda_bump) = solana_sdk::pubkey::Pubkey::find_program_address(
+        &[
+            &root_pda.to_bytes(),
+            &next_session_id.to_be_bytes(),
+            SIGNING_SESSION_PDA,
+        ],
+        &PROGRAM_ID,
+    );
+    let signing_account = AccountSharedData::new(0, 0, &system_program);
+
+    let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
+        proposal: Proposal::Cpi,
+        instructions: destination_instructions(
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data.clone(),
+        ),
+        vault_index: 0,
+        expires_at: None,
+        bump: signing_pda_bump,
+    };
+    let data_initialize_signing_session = [
+        vec![ProgramIx::InitializeSigningSession as u8],
+        to_vec(&ix_data_initialize_signing_session).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_initialize_signing_session,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::OperatorLacksPermission as u32,
+        ))],
+    );
+}
+
 #[test]
 fn test_re_initialize_signing_session_failure() {
     let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
@@ -540,15 +667,20 @@ fn test_re_initialize_signing_session_failure() {
             &mollusk,
             session_id,
             root_pda,
-            vec![], // approvals
+            0, // approvals
             SigningSessionPhase::Active,
             cpi_instruction_accounts.clone(),
             cpi_instruction_data.clone(),
         );
 
     let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
-        instruction_data: cpi_instruction_data.clone(),
-        instruction_accounts: cpi_instruction_accounts.clone(),
+        proposal: Proposal::Cpi,
+        instructions: destination_instructions(
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data.clone(),
+        ),
+        vault_index: 0,
+        expires_at: None,
         bump: signing_pda_bump,
     };
     let data_initialize_signing_session = [
@@ -561,7 +693,7 @@ fn test_re_initialize_signing_session_failure() {
         PROGRAM_ID,
         &data_initialize_signing_session,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new(root_pda, false),
             AccountMeta::new(signing_pda, false),
             AccountMeta::new_readonly(system_program, false),
@@ -570,7 +702,7 @@ fn test_re_initialize_signing_session_failure() {
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
             (system_program, system_account.clone()),
@@ -583,3 +715,600 @@ fn test_re_initialize_signing_session_failure() {
         ],
     );
 }
+
+#[test]
+fn test_initialize_signing_session_already_expired_failure() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let session_id = 0;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    mollusk.warp_to_slot(20);
+
+    // storage
+    let (storage_pda, _storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing
+    let next_session_id = root_pda_init_state.last_id + 1; // this is because the next session id must be the incremented current one from root pda
+    let (signing_pda, signing_pda_bump) = solana_sdk::pubkey::Pubkey::find_program_address(
+        &[
+            &root_pda.to_bytes(),
+            &next_session_id.to_be_bytes(),
+            SIGNING_SESSION_PDA,
+        ],
+        &PROGRAM_ID,
+    );
+    let signing_account = AccountSharedData::new(0, 0, &system_program);
+
+    let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
+        proposal: Proposal::Cpi,
+        instructions: destination_instructions(
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data.clone(),
+        ),
+        vault_index: 0,
+        expires_at: Some(Expiry::Slot(10)),
+        bump: signing_pda_bump,
+    };
+    let data_initialize_signing_session = [
+        vec![ProgramIx::InitializeSigningSession as u8],
+        to_vec(&ix_data_initialize_signing_session).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_initialize_signing_session,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::SigningSessionExpired as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_initialize_signing_session_cpi_without_instructions_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let session_id = 0;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing
+    let next_session_id = root_pda_init_state.last_id + 1; // this is because the next session id must be the incremented current one from root pda
+    let (signing_pda, signing_pda_bump) = solana_sdk::pubkey::Pubkey::find_program_address(
+        &[
+            &root_pda.to_bytes(),
+            &next_session_id.to_be_bytes(),
+            SIGNING_SESSION_PDA,
+        ],
+        &PROGRAM_ID,
+    );
+    let signing_account = AccountSharedData::new(0, 0, &system_program);
+
+    let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
+        proposal: Proposal::Cpi,
+        instructions: vec![],
+        vault_index: 0,
+        expires_at: None,
+        bump: signing_pda_bump,
+    };
+    let data_initialize_signing_session = [
+        vec![ProgramIx::InitializeSigningSession as u8],
+        to_vec(&ix_data_initialize_signing_session).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_initialize_signing_session,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::SessionInstructionsMissing as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_initialize_signing_session_program_not_allowed_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let session_id = 0;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing
+    let next_session_id = root_pda_init_state.last_id + 1; // this is because the next session id must be the incremented current one from root pda
+    let (signing_pda, signing_pda_bump) = solana_sdk::pubkey::Pubkey::find_program_address(
+        &[
+            &root_pda.to_bytes(),
+            &next_session_id.to_be_bytes(),
+            SIGNING_SESSION_PDA,
+        ],
+        &PROGRAM_ID,
+    );
+    let signing_account = AccountSharedData::new(0, 0, &system_program);
+
+    let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
+        proposal: Proposal::Cpi,
+        instructions: vec![SessionInstruction {
+            program_id: Pubkey::new_unique(), // not among allowed programs
+            data: vec![],
+            accounts: vec![],
+        }],
+        vault_index: 0,
+        expires_at: None,
+        bump: signing_pda_bump,
+    };
+    let data_initialize_signing_session = [
+        vec![ProgramIx::InitializeSigningSession as u8],
+        to_vec(&ix_data_initialize_signing_session).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_initialize_signing_session,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::ProgramNotAllowed as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_initialize_signing_session_instruction_above_cpi_account_limit_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let session_id = 0;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing
+    let next_session_id = root_pda_init_state.last_id + 1; // this is because the next session id must be the incremented current one from root pda
+    let (signing_pda, signing_pda_bump) = solana_sdk::pubkey::Pubkey::find_program_address(
+        &[
+            &root_pda.to_bytes(),
+            &next_session_id.to_be_bytes(),
+            SIGNING_SESSION_PDA,
+        ],
+        &PROGRAM_ID,
+    );
+    let signing_account = AccountSharedData::new(0, 0, &system_program);
+
+    let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
+        proposal: Proposal::Cpi,
+        instructions: vec![SessionInstruction {
+            program_id: DESTINATION_PROGRAM_ID,
+            data: vec![],
+            accounts: padding_instruction_accounts(MAX_CPI_ACCOUNTS + 1).0, // one past cpi limit
+        }],
+        vault_index: 0,
+        expires_at: None,
+        bump: signing_pda_bump,
+    };
+    let data_initialize_signing_session = [
+        vec![ProgramIx::InitializeSigningSession as u8],
+        to_vec(&ix_data_initialize_signing_session).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_initialize_signing_session,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::SessionInstructionTooManyAccounts as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_initialize_signing_session_transfer_zero_amount_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let session_id = 0;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing
+    let next_session_id = root_pda_init_state.last_id + 1; // this is because the next session id must be the incremented current one from root pda
+    let (signing_pda, signing_pda_bump) = solana_sdk::pubkey::Pubkey::find_program_address(
+        &[
+            &root_pda.to_bytes(),
+            &next_session_id.to_be_bytes(),
+            SIGNING_SESSION_PDA,
+        ],
+        &PROGRAM_ID,
+    );
+    let signing_account = AccountSharedData::new(0, 0, &system_program);
+
+    let proposal = Proposal::Transfer {
+        recipient: Pubkey::new_unique(),
+        amount: 0,
+        vault_index: 0,
+    };
+    let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
+        proposal,
+        instructions: vec![],
+        vault_index: 0,
+        expires_at: None,
+        bump: signing_pda_bump,
+    };
+    let data_initialize_signing_session = [
+        vec![ProgramIx::InitializeSigningSession as u8],
+        to_vec(&ix_data_initialize_signing_session).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_initialize_signing_session,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::TransferAmountMustNotBeZero as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_initialize_signing_session_transfer_with_instructions_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let session_id = 0;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing
+    let next_session_id = root_pda_init_state.last_id + 1; // this is because the next session id must be the incremented current one from root pda
+    let (signing_pda, signing_pda_bump) = solana_sdk::pubkey::Pubkey::find_program_address(
+        &[
+            &root_pda.to_bytes(),
+            &next_session_id.to_be_bytes(),
+            SIGNING_SESSION_PDA,
+        ],
+        &PROGRAM_ID,
+    );
+    let signing_account = AccountSharedData::new(0, 0, &system_program);
+
+    let proposal = Proposal::Transfer {
+        recipient: Pubkey::new_unique(),
+        amount: LAMPORTS_PER_SOL,
+        vault_index: 0,
+    };
+    let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
+        proposal,
+        instructions: vec![SessionInstruction {
+            program_id: DESTINATION_PROGRAM_ID,
+            data: vec![],
+            accounts: vec![],
+        }],
+        vault_index: 0,
+        expires_at: None,
+        bump: signing_pda_bump,
+    };
+    let data_initialize_signing_session = [
+        vec![ProgramIx::InitializeSigningSession as u8],
+        to_vec(&ix_data_initialize_signing_session).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_initialize_signing_session,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::SessionInstructionsUnexpected as u32,
+        ))],
+    );
+}
+
+#[test]
+fn test_initialize_signing_session_token_transfer_unsupported_program_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    let session_id = 0;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing
+    let next_session_id = root_pda_init_state.last_id + 1; // this is because the next session id must be the incremented current one from root pda
+    let (signing_pda, signing_pda_bump) = solana_sdk::pubkey::Pubkey::find_program_address(
+        &[
+            &root_pda.to_bytes(),
+            &next_session_id.to_be_bytes(),
+            SIGNING_SESSION_PDA,
+        ],
+        &PROGRAM_ID,
+    );
+    let signing_account = AccountSharedData::new(0, 0, &system_program);
+
+    let proposal = Proposal::TokenTransfer(TokenTransfer {
+        token_program: Pubkey::new_unique(), // neither token nor token-2022 program,
+        mint: Pubkey::new_unique(),
+        source: Pubkey::new_unique(),
+        destination: Pubkey::new_unique(),
+        amount: 1_000,
+        decimals: 6,
+        vault_index: None,
+    });
+    let ix_data_initialize_signing_session = InitializeSigningSessionIxData {
+        proposal,
+        instructions: vec![],
+        vault_index: 0,
+        expires_at: None,
+        bump: signing_pda_bump,
+    };
+    let data_initialize_signing_session = [
+        vec![ProgramIx::InitializeSigningSession as u8],
+        to_vec(&ix_data_initialize_signing_session).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_initialize_signing_session,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+            AccountMeta::new_readonly(system_program, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+            (system_program, system_account.clone()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            MosaicError::TokenProgramNotSupported as u32,
+        ))],
+    );
+}
@@ -0,0 +1,235 @@
+mod common;
+
+use {
+    borsh::to_vec,
+    common::*,
+    mollusk_svm::{Mollusk, result::Check},
+};
+
+use mosaic::{
+    events::Event,
+    instructions::{Instruction as ProgramIx, reject::RejectIxData},
+    state::{
+        proposal::Proposal,
+        signing_session::{SigningSession, SigningSessionPhase},
+    },
+};
+
+use solana_sdk::instruction::{AccountMeta, Instruction};
+
+#[test]
+fn test_reject() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_votes(
+            &mollusk,
+            session_id,
+            root_pda,
+            operators_pubkey[1],
+            0, // approvals
+            0, // rejections
+            SigningSessionPhase::Active,
+            Proposal::Cpi,
+            vec![],
+            vec![],
+        );
+
+    let logs = collect_logs(&mut mollusk);
+
+    // reject
+    let ix_data_reject = RejectIxData {
+        bump: signing_pda_bump,
+    };
+    let data_reject = [
+        vec![ProgramIx::Reject as u8],
+        to_vec(&ix_data_reject).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_reject,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    // 3 operators with threshold 2 can still approve after a single rejection
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Active);
+    assert!(has_voted(
+        parsed_signing_session_pda_data.rejections,
+        &operators_pubkey,
+        &signer
+    ));
+    assert!(parsed_signing_session_pda_data.approvals == 0);
+    // rejection is recorded without finishing the session
+    assert!(
+        emitted_events(&logs)
+            == vec![Event::SessionRejectVote {
+                root: root_pda,
+                session_id,
+                signer,
+                rejection_weight: 1,
+            }]
+    );
+}
+
+#[test]
+fn test_reject_reaching_rejection_threshold() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_votes(
+            &mollusk,
+            session_id,
+            root_pda,
+            operators_pubkey[1],
+            0,                                                   // approvals
+            votes_of(&operators_pubkey, &[operators_pubkey[1]]), // rejections
+            SigningSessionPhase::Active,
+            Proposal::Cpi,
+            vec![],
+            vec![],
+        );
+
+    let logs = collect_logs(&mut mollusk);
+
+    // reject
+    let ix_data_reject = RejectIxData {
+        bump: signing_pda_bump,
+    };
+    let data_reject = [
+        vec![ProgramIx::Reject as u8],
+        to_vec(&ix_data_reject).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_reject,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    // only one operator left to approve, threshold of 2 is unreachable
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Rejected);
+    assert!(parsed_signing_session_pda_data.rejections.count_ones() == 2);
+    assert!(has_voted(
+        parsed_signing_session_pda_data.rejections,
+        &operators_pubkey,
+        &signer
+    ));
+    assert!(
+        emitted_events(&logs)
+            == vec![
+                Event::SessionRejectVote {
+                    root: root_pda,
+                    session_id,
+                    signer,
+                    rejection_weight: 2,
+                },
+                Event::SessionRejected {
+                    root: root_pda,
+                    session_id,
+                    signer,
+                    rejection_weight: 2,
+                },
+            ]
+    );
+}
@@ -7,8 +7,9 @@ use {
 };
 
 use mosaic::{
+    events::Event,
     instructions::{Instruction as ProgramIx, sign::SignIxData},
-    state::signing_session::{SigningSession, SigningSessionPhase},
+    state::signing_session::{SigningSession, SigningSessionPhase, vote_bit},
 };
 
 use solana_sdk::instruction::{AccountMeta, Instruction};
@@ -16,7 +17,8 @@ use solana_sdk::instruction::{AccountMeta, Instruction};
 #[test]
 fn test_sign() {
     let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
-    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
 
     let operators = Operators::new(3, system_program);
     let operators_pubkey: Vec<_> = operators
@@ -39,7 +41,7 @@ fn test_sign() {
     ) = prepare_root(
         &mollusk,
         operators,
-        operators_pubkey,
+        operators_pubkey.clone(),
         session_id,
         DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
     );
@@ -58,7 +60,7 @@ fn test_sign() {
             &mollusk,
             session_id,
             root_pda,
-            vec![], // approvals
+            0, // approvals
             SigningSessionPhase::Active,
             cpi_instruction_accounts,
             cpi_instruction_data,
@@ -74,19 +76,17 @@ fn test_sign() {
         PROGRAM_ID,
         &data_sign,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new_readonly(root_pda, false),
             AccountMeta::new(signing_pda, false),
-            AccountMeta::new_readonly(system_program, false),
         ],
     );
     let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
-            (system_program, system_account.clone()),
         ],
         &[
             Check::success(),
@@ -96,11 +96,613 @@ fn test_sign() {
 
     let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
     let parsed_signing_session_pda_data =
-        borsh::from_slice::<SigningSession>(&updated_signing_session_pda_account.data).unwrap();
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
 
     assert!(parsed_signing_session_pda_data.session_id == session_id);
     assert!(parsed_signing_session_pda_data.root_pda == root_pda);
     assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Active);
-    assert!(parsed_signing_session_pda_data.approvals.contains(&signer));
+    assert!(parsed_signing_session_pda_data.approved_at.is_none());
+    assert!(has_voted(
+        parsed_signing_session_pda_data.approvals,
+        &operators_pubkey,
+        &signer
+    ));
     assert!(parsed_signing_session_pda_data.bump == signing_pda_bump)
 }
+
+#[test]
+fn test_sign_records_approval_time() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // storage
+    let (storage_pda, _storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Active,
+            cpi_instruction_accounts,
+            cpi_instruction_data,
+        );
+
+    mollusk.sysvars.clock.unix_timestamp = 1_234;
+    let logs = collect_logs(&mut mollusk);
+
+    // sign
+    let ix_data_sign = SignIxData {
+        bump: signing_pda_bump,
+    };
+    let data_sign = [vec![ProgramIx::Sign as u8], to_vec(&ix_data_sign).unwrap()].concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_sign,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Approved);
+    assert!(parsed_signing_session_pda_data.approved_at == Some(1_234));
+    assert!(
+        emitted_events(&logs)
+            == vec![
+                Event::SessionSigned {
+                    root: root_pda,
+                    session_id,
+                    signer,
+                    approval_weight: 2,
+                },
+                Event::SessionApproved {
+                    root: root_pda,
+                    session_id,
+                    approved_at: 1_234,
+                },
+            ]
+    );
+}
+
+#[test]
+fn test_sign_older_session() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // newer sessions were created after this one
+    let root_last_id = 4;
+    let session_id = 2;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        root_last_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // storage
+    let (storage_pda, _storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            0, // approvals
+            SigningSessionPhase::Active,
+            cpi_instruction_accounts,
+            cpi_instruction_data,
+        );
+
+    // sign
+    let ix_data_sign = SignIxData {
+        bump: signing_pda_bump,
+    };
+    let data_sign = [vec![ProgramIx::Sign as u8], to_vec(&ix_data_sign).unwrap()].concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_sign,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.session_id == session_id);
+    assert!(parsed_signing_session_pda_data.root_pda == root_pda);
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Active);
+    assert!(has_voted(
+        parsed_signing_session_pda_data.approvals,
+        &operators_pubkey,
+        &signer
+    ));
+    assert!(parsed_signing_session_pda_data.bump == signing_pda_bump)
+}
+
+#[test]
+fn test_sign_approvals_above_lowered_threshold() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    // threshold lowered while session already collected approvals
+    let mut operators = Operators::new(3, system_program);
+    operators.threshold = 1;
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // storage
+    let (storage_pda, _storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(
+                &operators_pubkey,
+                &[operators_pubkey[1], operators_pubkey[2]],
+            ), // approvals
+            SigningSessionPhase::Active,
+            cpi_instruction_accounts,
+            cpi_instruction_data,
+        );
+
+    // sign
+    let ix_data_sign = SignIxData {
+        bump: signing_pda_bump,
+    };
+    let data_sign = [vec![ProgramIx::Sign as u8], to_vec(&ix_data_sign).unwrap()].concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_sign,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.session_id == session_id);
+    assert!(parsed_signing_session_pda_data.root_pda == root_pda);
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Approved);
+    assert!(has_voted(
+        parsed_signing_session_pda_data.approvals,
+        &operators_pubkey,
+        &signer
+    ));
+    assert!(parsed_signing_session_pda_data.bump == signing_pda_bump)
+}
+
+#[test]
+fn test_sign_weighted_operator_reaching_threshold() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    // first operator outweighs the other two together
+    let mut operators = Operators::new(3, system_program);
+    operators.threshold = 3;
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        mut root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+    root_pda_init_state.operators[0].weight = 3;
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // storage
+    let (storage_pda, _storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            0, // approvals
+            SigningSessionPhase::Active,
+            cpi_instruction_accounts,
+            cpi_instruction_data,
+        );
+
+    // sign
+    let ix_data_sign = SignIxData {
+        bump: signing_pda_bump,
+    };
+    let data_sign = [vec![ProgramIx::Sign as u8], to_vec(&ix_data_sign).unwrap()].concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_sign,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.session_id == session_id);
+    assert!(parsed_signing_session_pda_data.root_pda == root_pda);
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Approved);
+    assert!(has_voted(
+        parsed_signing_session_pda_data.approvals,
+        &operators_pubkey,
+        &signer
+    ));
+    assert!(parsed_signing_session_pda_data.bump == signing_pda_bump)
+}
+
+#[test]
+fn test_sign_light_operators_below_weight_threshold() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    // first operator outweighs the other two together
+    let mut operators = Operators::new(3, system_program);
+    operators.threshold = 3;
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[2].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        mut root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+    root_pda_init_state.operators[0].weight = 3;
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // storage
+    let (storage_pda, _storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[operators_pubkey[1]]), // approvals
+            SigningSessionPhase::Active,
+            cpi_instruction_accounts,
+            cpi_instruction_data,
+        );
+
+    // sign
+    let ix_data_sign = SignIxData {
+        bump: signing_pda_bump,
+    };
+    let data_sign = [vec![ProgramIx::Sign as u8], to_vec(&ix_data_sign).unwrap()].concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_sign,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.session_id == session_id);
+    assert!(parsed_signing_session_pda_data.root_pda == root_pda);
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Active);
+    assert!(has_voted(
+        parsed_signing_session_pda_data.approvals,
+        &operators_pubkey,
+        &signer
+    ));
+    assert!(parsed_signing_session_pda_data.bump == signing_pda_bump)
+}
+
+#[test]
+fn test_sign_by_operator_taking_over_slot() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let leaving_operator = operators_pubkey[2];
+    let (signer, signer_account) = Operators::new(1, system_program).operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        mut root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+    // signer replaced the last operator after the session got created
+    root_pda_init_state.operators[2].key = signer;
+    root_pda_init_state.operators_epoch = 1;
+    root_pda_init_state.operator_epochs = vec![0, 0, 1];
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // storage
+    let (storage_pda, _storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session approved by the replaced operator only
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            votes_of(&operators_pubkey, &[leaving_operator]), // approvals
+            SigningSessionPhase::Active,
+            cpi_instruction_accounts,
+            cpi_instruction_data,
+        );
+
+    // sign
+    let ix_data_sign = SignIxData {
+        bump: signing_pda_bump,
+    };
+    let data_sign = [vec![ProgramIx::Sign as u8], to_vec(&ix_data_sign).unwrap()].concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_sign,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    // vote of the replaced operator got dropped, so the slot holds signer's own approval
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Active);
+    assert!(parsed_signing_session_pda_data.approvals == vote_bit(2));
+    assert!(parsed_signing_session_pda_data.operators_epoch == 1);
+}
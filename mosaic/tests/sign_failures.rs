@@ -9,7 +9,11 @@ use {
 use mosaic::{
     errors::MosaicError,
     instructions::{Instruction as ProgramIx, sign::SignIxData},
-    state::signing_session::{SigningSession, SigningSessionPhase},
+    state::{
+        expiry::Expiry,
+        root::{Operator, Permission},
+        signing_session::{SigningSession, SigningSessionPhase},
+    },
 };
 
 use solana_program::example_mocks::{solana_keypair::Keypair, solana_signer::Signer};
@@ -24,7 +28,8 @@ use solana_sdk::{
 #[test]
 fn test_sign_payer_is_not_signer_failure() {
     let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
-    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
 
     let operators = Operators::new(3, system_program);
     let operators_pubkey: Vec<_> = operators
@@ -66,7 +71,7 @@ fn test_sign_payer_is_not_signer_failure() {
             &mollusk,
             session_id,
             root_pda,
-            vec![], // approvals
+            0, // approvals
             SigningSessionPhase::Active,
             cpi_instruction_accounts,
             cpi_instruction_data,
@@ -82,19 +87,17 @@ fn test_sign_payer_is_not_signer_failure() {
         PROGRAM_ID,
         &data_sign,
         vec![
-            AccountMeta::new(payer.into(), false),
+            AccountMeta::new(payer, false),
             AccountMeta::new_readonly(root_pda, false),
             AccountMeta::new(signing_pda, false),
-            AccountMeta::new_readonly(system_program, false),
         ],
     );
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (payer.into(), signer_account.clone().into()),
+            (payer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
-            (system_program, system_account.clone()),
         ],
         &[Check::err(ProgramError::Custom(
             MosaicError::PayerMustEqualSigner as u32,
@@ -105,7 +108,8 @@ fn test_sign_payer_is_not_signer_failure() {
 #[test]
 fn test_sign_last_wrap_session() {
     let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
-    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
 
     let operators = Operators::new(3, system_program);
     let operators_pubkey: Vec<_> = operators
@@ -147,7 +151,7 @@ fn test_sign_last_wrap_session() {
             &mollusk,
             session_id,
             root_pda,
-            vec![operators_pubkey[1]], // approvals
+            votes_of(&operators_pubkey, &[operators_pubkey[1]]), // approvals
             SigningSessionPhase::Active,
             cpi_instruction_accounts,
             cpi_instruction_data,
@@ -162,19 +166,17 @@ fn test_sign_last_wrap_session() {
         PROGRAM_ID,
         &data_sign,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new_readonly(root_pda, false),
             AccountMeta::new(signing_pda, false),
-            AccountMeta::new_readonly(system_program, false),
         ],
     );
     let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
-            (system_program, system_account.clone()),
         ],
         &[
             Check::success(),
@@ -183,18 +185,23 @@ fn test_sign_last_wrap_session() {
     );
 
     let updated_data = result.get_account(&signing_pda).unwrap();
-    let parsed_data = borsh::from_slice::<SigningSession>(&updated_data.data).unwrap();
+    let parsed_data = SigningSession::deserialize(&updated_data.data).unwrap();
 
     assert!(parsed_data.phase == SigningSessionPhase::Approved);
-    assert!(parsed_data.approvals.contains(&signer));
-    assert!(parsed_data.approvals.contains(&operators_pubkey[1]));
+    assert!(has_voted(parsed_data.approvals, &operators_pubkey, &signer));
+    assert!(has_voted(
+        parsed_data.approvals,
+        &operators_pubkey,
+        &operators_pubkey[1]
+    ));
     assert!(parsed_data.bump == signing_pda_bump)
 }
 
 #[test]
 fn test_sign_twice_same_signer_failure() {
     let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
-    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
 
     let operators = Operators::new(3, system_program);
     let operators_pubkey: Vec<_> = operators
@@ -217,7 +224,7 @@ fn test_sign_twice_same_signer_failure() {
     ) = prepare_root(
         &mollusk,
         operators,
-        operators_pubkey,
+        operators_pubkey.clone(),
         session_id,
         DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
     );
@@ -236,7 +243,7 @@ fn test_sign_twice_same_signer_failure() {
             &mollusk,
             session_id,
             root_pda,
-            vec![signer], // approvals
+            votes_of(&operators_pubkey, &[signer]), // approvals
             SigningSessionPhase::Active,
             cpi_instruction_accounts,
             cpi_instruction_data,
@@ -252,19 +259,17 @@ fn test_sign_twice_same_signer_failure() {
         PROGRAM_ID,
         &data_sign,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new_readonly(root_pda, false),
             AccountMeta::new(signing_pda, false),
-            AccountMeta::new_readonly(system_program, false),
         ],
     );
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
-            (system_program, system_account.clone()),
         ],
         &[
             Check::err(ProgramError::Custom(
@@ -278,7 +283,8 @@ fn test_sign_twice_same_signer_failure() {
 #[test]
 fn test_sign_signer_is_not_operator_failure() {
     let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
-    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
 
     let operators = Operators::new(3, system_program);
     let operators_pubkey: Vec<_> = operators
@@ -288,8 +294,7 @@ fn test_sign_signer_is_not_operator_failure() {
         .collect();
 
     let not_operator_signer = Keypair::new();
-    let not_operator_signer_account =
-        AccountSharedData::new(1 * LAMPORTS_PER_SOL, 0, &system_program);
+    let not_operator_signer_account = AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program);
 
     // used in root pda as last_id and in signing session as id
     let session_id = 1;
@@ -323,7 +328,7 @@ fn test_sign_signer_is_not_operator_failure() {
             &mollusk,
             session_id,
             root_pda,
-            vec![not_operator_signer.pubkey()], // approvals
+            0, // approvals
             SigningSessionPhase::Active,
             cpi_instruction_accounts,
             cpi_instruction_data,
@@ -342,7 +347,6 @@ fn test_sign_signer_is_not_operator_failure() {
             AccountMeta::new(not_operator_signer.pubkey(), true),
             AccountMeta::new_readonly(root_pda, false),
             AccountMeta::new(signing_pda, false),
-            AccountMeta::new_readonly(system_program, false),
         ],
     );
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
@@ -354,7 +358,6 @@ fn test_sign_signer_is_not_operator_failure() {
             ),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
-            (system_program, system_account.clone()),
         ],
         &[
             Check::err(ProgramError::Custom(
@@ -368,7 +371,8 @@ fn test_sign_signer_is_not_operator_failure() {
 #[test]
 fn test_sign_root_incorrect_owner_failure() {
     let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
-    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
 
     let operators = Operators::new(3, system_program);
     let (signer, signer_account) = operators.operators[0].clone();
@@ -390,7 +394,7 @@ fn test_sign_root_incorrect_owner_failure() {
             &mollusk,
             session_id,
             root_pda,
-            vec![],
+            0,
             SigningSessionPhase::Active,
             cpi_instruction_accounts,
             cpi_instruction_data,
@@ -405,20 +409,18 @@ fn test_sign_root_incorrect_owner_failure() {
         PROGRAM_ID,
         &data_sign,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new_readonly(root_pda, false),
             AccountMeta::new(signing_pda, false),
-            AccountMeta::new_readonly(system_program, false),
         ],
     );
 
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.into()),
             (signing_pda, signing_account.clone().into()),
-            (system_program, system_account.clone()),
         ],
         &[Check::err(ProgramError::Custom(
             MosaicError::RootAccountIncorrectOwner as u32,
@@ -429,7 +431,8 @@ fn test_sign_root_incorrect_owner_failure() {
 #[test]
 fn test_sign_signing_session_not_writable_failure() {
     let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
-    let (system_program, system_account) = mollusk_svm::program::keyed_account_for_system_program();
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
 
     let operators = Operators::new(3, system_program);
     let operators_pubkey: Vec<_> = operators
@@ -461,7 +464,7 @@ fn test_sign_signing_session_not_writable_failure() {
         &mollusk,
         session_id,
         root_pda,
-        vec![],
+        0,
         SigningSessionPhase::Active,
         cpi_instruction_accounts,
         cpi_instruction_data,
@@ -476,23 +479,372 @@ fn test_sign_signing_session_not_writable_failure() {
         PROGRAM_ID,
         &data_sign,
         vec![
-            AccountMeta::new(signer.into(), true),
+            AccountMeta::new(signer, true),
             AccountMeta::new_readonly(root_pda, false),
             AccountMeta::new_readonly(signing_pda, false), // ← NIE WRITABLE
-            AccountMeta::new_readonly(system_program, false),
         ],
     );
 
     let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
         &instruction,
         &[
-            (signer.into(), signer_account.clone().into()),
+            (signer, signer_account.clone().into()),
             (root_pda, root_account.clone().into()),
             (signing_pda, signing_account.clone().into()),
-            (system_program, system_account.clone()),
         ],
         &[Check::err(ProgramError::Custom(
             MosaicError::SigningSessionAccountMustBeWritable as u32,
         ))],
     );
 }
+
+#[test]
+fn test_sign_session_stored_id_mismatch_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // root last id is ahead of both sessions
+    let root_last_id = 5;
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        __root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        root_last_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // storage
+    let (storage_pda, _storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // session data stored under pda of another session id
+    let (_signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            0, // approvals
+            SigningSessionPhase::Active,
+            cpi_instruction_accounts.clone(),
+            cpi_instruction_data.clone(),
+        );
+    let (other_signing_pda, _, _, _) = prepare_signing_session(
+        &mollusk,
+        session_id + 1,
+        root_pda,
+        0,
+        SigningSessionPhase::Active,
+        cpi_instruction_accounts,
+        cpi_instruction_data,
+    );
+
+    // sign
+    let ix_data_sign = SignIxData {
+        bump: signing_pda_bump,
+    };
+    let data_sign = [vec![ProgramIx::Sign as u8], to_vec(&ix_data_sign).unwrap()].concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_sign,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(other_signing_pda, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (other_signing_pda, signing_account.clone().into()),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+}
+
+#[test]
+fn test_sign_expired_session_failure() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // storage
+    let (storage_pda, _storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session
+    let (signing_pda, signing_pda_bump, signing_init_state_serialized, _signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            0, // approvals
+            SigningSessionPhase::Active,
+            cpi_instruction_accounts,
+            cpi_instruction_data,
+        );
+
+    // session expired at unix timestamp 1_000
+    let mut signing_init_state =
+        SigningSession::deserialize(&signing_init_state_serialized).unwrap();
+    signing_init_state.expires_at = Some(Expiry::UnixTimestamp(1_000));
+    let signing_account = signing_session_account_from_state(&mollusk, &signing_init_state);
+    mollusk.sysvars.clock.unix_timestamp = 1_000;
+
+    // sign
+    let ix_data_sign = SignIxData {
+        bump: signing_pda_bump,
+    };
+    let data_sign = [vec![ProgramIx::Sign as u8], to_vec(&ix_data_sign).unwrap()].concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_sign,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+        ],
+        &[
+            Check::err(ProgramError::Custom(
+                MosaicError::SigningSessionExpired as u32,
+            )),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+}
+
+#[test]
+fn test_sign_removed_operator_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        mut root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+    // signer was removed from root operators; its slot is left as a tombstone
+    root_pda_init_state.operators[0] = Operator::REMOVED;
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // storage
+    let (storage_pda, _storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            0, // approvals
+            SigningSessionPhase::Active,
+            cpi_instruction_accounts,
+            cpi_instruction_data,
+        );
+
+    // sign
+    let ix_data_sign = SignIxData {
+        bump: signing_pda_bump,
+    };
+    let data_sign = [vec![ProgramIx::Sign as u8], to_vec(&ix_data_sign).unwrap()].concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_sign,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+        ],
+        &[
+            Check::err(ProgramError::Custom(
+                MosaicError::SignerIsNotOperator as u32,
+            )),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+}
+
+#[test]
+fn test_sign_operator_lacks_vote_permission_failure() {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        __root_pda_bump,
+        mut root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        _root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey,
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+    // bot key may propose and execute but never vote
+    root_pda_init_state.operators[0].permissions =
+        Permission::Initiate as u8 | Permission::Execute as u8;
+    let root_account = root_account_from_state(&mollusk, &root_pda_init_state);
+
+    // storage
+    let (storage_pda, _storage_pda_account) =
+        prepare_storage_account(&mollusk, session_id, root_pda);
+
+    // record program accounts and instruction data
+    let (cpi_instruction_accounts, cpi_instruction_data) =
+        records_program_ix_accs(storage_pda, root_pda);
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session(
+            &mollusk,
+            session_id,
+            root_pda,
+            0, // approvals
+            SigningSessionPhase::Active,
+            cpi_instruction_accounts,
+            cpi_instruction_data,
+        );
+
+    // sign
+    let ix_data_sign = SignIxData {
+        bump: signing_pda_bump,
+    };
+    let data_sign = [vec![ProgramIx::Sign as u8], to_vec(&ix_data_sign).unwrap()].concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_sign,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let _result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+        ],
+        &[
+            Check::err(ProgramError::Custom(
+                MosaicError::OperatorLacksPermission as u32,
+            )),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+}
@@ -0,0 +1,234 @@
+mod common;
+
+use {
+    borsh::to_vec,
+    common::*,
+    mollusk_svm::{Mollusk, result::Check},
+};
+
+use mosaic::{
+    events::Event,
+    instructions::{Instruction as ProgramIx, unsign::UnsignIxData},
+    state::{
+        proposal::Proposal,
+        signing_session::{SigningSession, SigningSessionPhase},
+    },
+};
+
+use solana_sdk::instruction::{AccountMeta, Instruction};
+
+#[test]
+fn test_unsign() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_votes(
+            &mollusk,
+            session_id,
+            root_pda,
+            operators_pubkey[1],
+            votes_of(&operators_pubkey, &[signer]), // approvals
+            0,                                      // rejections
+            SigningSessionPhase::Active,
+            Proposal::Cpi,
+            vec![],
+            vec![],
+        );
+
+    let logs = collect_logs(&mut mollusk);
+
+    // unsign
+    let ix_data_unsign = UnsignIxData {
+        bump: signing_pda_bump,
+    };
+    let data_unsign = [
+        vec![ProgramIx::Unsign as u8],
+        to_vec(&ix_data_unsign).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_unsign,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Active);
+    assert!(parsed_signing_session_pda_data.approvals == 0);
+    assert!(parsed_signing_session_pda_data.rejections == 0);
+    // session never got approved, nothing to reactivate
+    assert!(
+        emitted_events(&logs)
+            == vec![Event::SessionUnsigned {
+                root: root_pda,
+                session_id,
+                signer,
+                approval_weight: 0,
+            }]
+    );
+}
+
+#[test]
+fn test_unsign_approved_session() {
+    let mut mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = operators.operators[0].clone();
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, signing_pda_bump, signing_init_state_serialized, _signing_account) =
+        prepare_signing_session_with_votes(
+            &mollusk,
+            session_id,
+            root_pda,
+            operators_pubkey[1],
+            votes_of(&operators_pubkey, &[signer, operators_pubkey[1]]), // approvals
+            0,                                                           // rejections
+            SigningSessionPhase::Approved,
+            Proposal::Cpi,
+            vec![],
+            vec![],
+        );
+
+    let mut signing_init_state =
+        SigningSession::deserialize(&signing_init_state_serialized).unwrap();
+    signing_init_state.approved_at = Some(1_000);
+    let signing_account = signing_session_account_from_state(&mollusk, &signing_init_state);
+
+    let logs = collect_logs(&mut mollusk);
+
+    // unsign
+    let ix_data_unsign = UnsignIxData {
+        bump: signing_pda_bump,
+    };
+    let data_unsign = [
+        vec![ProgramIx::Unsign as u8],
+        to_vec(&ix_data_unsign).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_unsign,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    let result: mollusk_svm::result::InstructionResult = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.clone().into()),
+            (root_pda, root_account.clone().into()),
+            (signing_pda, signing_account.clone().into()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&signing_pda).owner(&PROGRAM_ID).build(),
+        ],
+    );
+
+    let updated_signing_session_pda_account = result.get_account(&signing_pda).unwrap();
+    let parsed_signing_session_pda_data =
+        SigningSession::deserialize(&updated_signing_session_pda_account.data).unwrap();
+
+    // single approval left is below threshold of 2, session needs approvals again
+    assert!(parsed_signing_session_pda_data.phase == SigningSessionPhase::Active);
+    assert!(
+        parsed_signing_session_pda_data.approvals
+            == votes_of(&operators_pubkey, &[operators_pubkey[1]])
+    );
+    assert!(parsed_signing_session_pda_data.approved_at.is_none());
+    assert!(
+        emitted_events(&logs)
+            == vec![
+                Event::SessionUnsigned {
+                    root: root_pda,
+                    session_id,
+                    signer,
+                    approval_weight: 1,
+                },
+                Event::SessionReactivated {
+                    root: root_pda,
+                    session_id,
+                    signer,
+                    approval_weight: 1,
+                },
+            ]
+    );
+}
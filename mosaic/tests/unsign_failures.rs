@@ -0,0 +1,171 @@
+mod common;
+
+use {
+    borsh::to_vec,
+    common::*,
+    mollusk_svm::{Mollusk, result::Check},
+};
+
+use mosaic::{
+    errors::MosaicError,
+    instructions::{Instruction as ProgramIx, unsign::UnsignIxData},
+    state::{
+        proposal::Proposal,
+        signing_session::{SigningSessionPhase, vote_bit},
+    },
+};
+
+use solana_sdk::{
+    account::AccountSharedData,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// runs unsign by operator at `signer` index (outsider when `None`) against a session
+/// in given phase, with votes given as operator indexes
+fn process_unsign(
+    signer: Option<usize>,
+    approvals: &[usize],
+    rejections: &[usize],
+    phase: SigningSessionPhase,
+    error: MosaicError,
+) {
+    let mollusk = Mollusk::new(&PROGRAM_ID, MOSAIC_BINARY_PATH);
+    let (system_program, _system_account) =
+        mollusk_svm::program::keyed_account_for_system_program();
+
+    let operators = Operators::new(3, system_program);
+    let operators_pubkey: Vec<_> = operators
+        .operators
+        .iter()
+        .map(|operator| operator.0)
+        .collect();
+    let (signer, signer_account) = match signer {
+        Some(index) => operators.operators[index].clone(),
+        None => (
+            Pubkey::new_unique(),
+            AccountSharedData::new(LAMPORTS_PER_SOL, 0, &system_program),
+        ),
+    };
+    let approvals = approvals.iter().fold(0, |votes, i| votes | vote_bit(*i));
+    let rejections = rejections.iter().fold(0, |votes, i| votes | vote_bit(*i));
+
+    // used in root pda as last_id and in signing session as id
+    let session_id = 1;
+
+    // root
+    let (
+        root_pda,
+        _root_pda_bump,
+        _root_pda_init_state,
+        _root_pda_initial_state_serialized,
+        root_account,
+    ) = prepare_root(
+        &mollusk,
+        operators,
+        operators_pubkey.clone(),
+        session_id,
+        DESTINATION_PROGRAM_ID.as_ref().try_into().unwrap(),
+    );
+
+    // signing session
+    let (signing_pda, signing_pda_bump, _signing_init_state_serialized, signing_account) =
+        prepare_signing_session_with_votes(
+            &mollusk,
+            session_id,
+            root_pda,
+            operators_pubkey[1],
+            approvals,
+            rejections,
+            phase,
+            Proposal::Cpi,
+            vec![],
+            vec![],
+        );
+
+    // unsign
+    let ix_data_unsign = UnsignIxData {
+        bump: signing_pda_bump,
+    };
+    let data_unsign = [
+        vec![ProgramIx::Unsign as u8],
+        to_vec(&ix_data_unsign).unwrap(),
+    ]
+    .concat();
+
+    let instruction = Instruction::new_with_bytes(
+        PROGRAM_ID,
+        &data_unsign,
+        vec![
+            AccountMeta::new(signer, true),
+            AccountMeta::new_readonly(root_pda, false),
+            AccountMeta::new(signing_pda, false),
+        ],
+    );
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (signer, signer_account.into()),
+            (root_pda, root_account.into()),
+            (signing_pda, signing_account.into()),
+        ],
+        &[Check::err(ProgramError::Custom(error as u32))],
+    );
+}
+
+#[test]
+fn test_unsign_without_approval_failure() {
+    process_unsign(
+        Some(0),
+        &[1],
+        &[],
+        SigningSessionPhase::Active,
+        MosaicError::SigningSessionSignerHasNotApproved,
+    );
+}
+
+#[test]
+fn test_unsign_after_reject_failure() {
+    process_unsign(
+        Some(0),
+        &[],
+        &[0],
+        SigningSessionPhase::Active,
+        MosaicError::SigningSessionSignerHasNotApproved,
+    );
+}
+
+#[test]
+fn test_unsign_signer_is_not_operator_failure() {
+    process_unsign(
+        None,
+        &[0],
+        &[],
+        SigningSessionPhase::Active,
+        MosaicError::SignerIsNotOperator,
+    );
+}
+
+#[test]
+fn test_unsign_executed_session_failure() {
+    process_unsign(
+        Some(0),
+        &[0, 1],
+        &[],
+        SigningSessionPhase::Executed,
+        MosaicError::SigningSessionPhaseIncorrect,
+    );
+}
+
+#[test]
+fn test_unsign_rejected_session_failure() {
+    process_unsign(
+        Some(0),
+        &[0],
+        &[1, 2],
+        SigningSessionPhase::Rejected,
+        MosaicError::SigningSessionPhaseIncorrect,
+    );
+}